@@ -0,0 +1,233 @@
+// chrono support (cargo feature "chrono")
+// the conversions from chrono fail if the value is out of range (1970-01-01 to 9999-12-31)
+// or not representable (e.g. a leap second or an offset with seconds)
+
+use std::convert::TryFrom;
+
+use ::chrono::{
+    DateTime as ChronoDateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime,
+    TimeZone as _, Timelike,
+};
+
+use crate::{
+    CalendarDate, DateTime, DayOfMonth, Hour, Instant, Minute, Month, Nanosecond, OffsetDateTime,
+    PreciseInstant, Second, Time, TimeZoneOffset, TryFromCalendarDateError, TryFromDateTimeError,
+    TryFromOffsetDateTimeError, TryFromTimeError, TryFromTimeZoneOffsetError, Year,
+};
+
+impl From<CalendarDate> for NaiveDate {
+    fn from(date: CalendarDate) -> Self {
+        NaiveDate::from_ymd_opt(
+            i32::from(date.year()),
+            u32::from(date.month()),
+            u32::from(date.day_of_month()),
+        )
+        .expect("CalendarDate is a valid NaiveDate")
+    }
+}
+
+impl TryFrom<NaiveDate> for CalendarDate {
+    type Error = TryFromCalendarDateError;
+
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        let year = Year::try_from(date.year()).map_err(|_| TryFromCalendarDateError::OutOfRange)?;
+        let month = Month::try_from(date.month()).expect("month is [1, 12]");
+        let day_of_month = DayOfMonth::try_from(date.day()).expect("day of month is [1, 31]");
+        Ok(CalendarDate::from_ymd(year, month, day_of_month).expect("NaiveDate is a valid date"))
+    }
+}
+
+impl From<Time> for NaiveTime {
+    fn from(time: Time) -> Self {
+        NaiveTime::from_hms_nano_opt(
+            u32::from(time.hour()),
+            u32::from(time.minute()),
+            u32::from(time.second()),
+            u32::from(time.nanosecond()),
+        )
+        .expect("Time is a valid NaiveTime")
+    }
+}
+
+// a leap second (the nanosecond is 1_000_000_000 or more) is out of range
+impl TryFrom<NaiveTime> for Time {
+    type Error = TryFromTimeError;
+
+    fn try_from(time: NaiveTime) -> Result<Self, Self::Error> {
+        let nanosecond =
+            Nanosecond::try_from(time.nanosecond()).map_err(|_| TryFromTimeError::OutOfRange)?;
+        Ok(Time::from_hms_nano(
+            Hour::try_from(time.hour()).expect("hour is [0, 23]"),
+            Minute::try_from(time.minute()).expect("minute is [0, 59]"),
+            Second::try_from(time.second()).expect("second is [0, 59]"),
+            nanosecond,
+        ))
+    }
+}
+
+impl From<DateTime> for NaiveDateTime {
+    fn from(date_time: DateTime) -> Self {
+        NaiveDateTime::new(
+            NaiveDate::from(date_time.date()),
+            NaiveTime::from(date_time.time()),
+        )
+    }
+}
+
+impl TryFrom<NaiveDateTime> for DateTime {
+    type Error = TryFromDateTimeError;
+
+    fn try_from(date_time: NaiveDateTime) -> Result<Self, Self::Error> {
+        let date = CalendarDate::try_from(date_time.date())
+            .map_err(|_| TryFromDateTimeError::OutOfRange)?;
+        let time =
+            Time::try_from(date_time.time()).map_err(|_| TryFromDateTimeError::OutOfRange)?;
+        Ok(DateTime::new(date, time))
+    }
+}
+
+impl From<TimeZoneOffset> for FixedOffset {
+    fn from(offset: TimeZoneOffset) -> Self {
+        FixedOffset::east_opt(i32::from(offset.offset_in_minutes()) * 60)
+            .expect("TimeZoneOffset is a valid FixedOffset")
+    }
+}
+
+// an offset with seconds (e.g. +09:18:59) is out of range
+impl TryFrom<FixedOffset> for TimeZoneOffset {
+    type Error = TryFromTimeZoneOffsetError;
+
+    fn try_from(offset: FixedOffset) -> Result<Self, Self::Error> {
+        let seconds = offset.local_minus_utc();
+        if seconds % 60 != 0 {
+            return Err(TryFromTimeZoneOffsetError::OutOfRange);
+        }
+        TimeZoneOffset::from_offset_in_minutes((seconds / 60) as i16)
+    }
+}
+
+impl From<OffsetDateTime> for ChronoDateTime<FixedOffset> {
+    fn from(offset_date_time: OffsetDateTime) -> Self {
+        FixedOffset::from(offset_date_time.offset())
+            .from_local_datetime(&NaiveDateTime::from(offset_date_time.date_time()))
+            .single()
+            .expect("FixedOffset has no gaps or overlaps")
+    }
+}
+
+impl TryFrom<ChronoDateTime<FixedOffset>> for OffsetDateTime {
+    type Error = TryFromOffsetDateTimeError;
+
+    fn try_from(date_time: ChronoDateTime<FixedOffset>) -> Result<Self, Self::Error> {
+        let offset = TimeZoneOffset::try_from(*date_time.offset())
+            .map_err(|_| TryFromOffsetDateTimeError::OutOfRange)?;
+        let instant = Instant::try_from(date_time.timestamp())
+            .map_err(|_| TryFromOffsetDateTimeError::OutOfRange)?;
+        let nanosecond = Nanosecond::try_from(date_time.timestamp_subsec_nanos())
+            .map_err(|_| TryFromOffsetDateTimeError::OutOfRange)?;
+        OffsetDateTime::from_precise_instant(PreciseInstant::new(instant, nanosecond), offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn calendar_date_test() -> anyhow::Result<()> {
+        let date = CalendarDate::from_str("2021-02-03")?;
+        let naive_date = NaiveDate::from_ymd_opt(2021, 2, 3).expect("valid");
+        assert_eq!(NaiveDate::from(date), naive_date);
+        assert_eq!(CalendarDate::try_from(naive_date)?, date);
+        let f = |y: i32, m: u32, d: u32| {
+            CalendarDate::try_from(NaiveDate::from_ymd_opt(y, m, d).expect("valid"))
+        };
+        assert_eq!(f(1970, 1, 1)?.to_string(), "1970-01-01");
+        assert_eq!(f(9999, 12, 31)?.to_string(), "9999-12-31");
+        assert_eq!(f(1969, 12, 31), Err(TryFromCalendarDateError::OutOfRange));
+        assert_eq!(f(10000, 1, 1), Err(TryFromCalendarDateError::OutOfRange));
+        Ok(())
+    }
+
+    #[test]
+    fn time_test() -> anyhow::Result<()> {
+        let time = Time::from_str("04:05:06.789")?;
+        let naive_time = NaiveTime::from_hms_nano_opt(4, 5, 6, 789_000_000).expect("valid");
+        assert_eq!(NaiveTime::from(time), naive_time);
+        assert_eq!(Time::try_from(naive_time)?, time);
+        let leap_second = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_000_000_000).expect("valid");
+        assert_eq!(
+            Time::try_from(leap_second),
+            Err(TryFromTimeError::OutOfRange)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn date_time_test() -> anyhow::Result<()> {
+        let date_time = DateTime::from_str("2021-02-03T04:05:06.789")?;
+        let naive_date_time = NaiveDateTime::from_str("2021-02-03T04:05:06.789")?;
+        assert_eq!(NaiveDateTime::from(date_time), naive_date_time);
+        assert_eq!(DateTime::try_from(naive_date_time)?, date_time);
+        assert_eq!(
+            DateTime::try_from(NaiveDateTime::from_str("1969-12-31T23:59:59")?),
+            Err(TryFromDateTimeError::OutOfRange)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn time_zone_offset_test() -> anyhow::Result<()> {
+        let offset = TimeZoneOffset::from_hm(-9, -30)?;
+        let fixed_offset = FixedOffset::west_opt(9 * 3_600 + 30 * 60).expect("valid");
+        assert_eq!(FixedOffset::from(offset), fixed_offset);
+        assert_eq!(TimeZoneOffset::try_from(fixed_offset)?, offset);
+        let with_seconds = FixedOffset::east_opt(9 * 3_600 + 18 * 60 + 59).expect("valid");
+        assert_eq!(
+            TimeZoneOffset::try_from(with_seconds),
+            Err(TryFromTimeZoneOffsetError::OutOfRange)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn offset_date_time_test() -> anyhow::Result<()> {
+        let offset_date_time = OffsetDateTime::from_str("2021-02-03T04:05:06.789+09:00")?;
+        let chrono_date_time = ChronoDateTime::parse_from_rfc3339("2021-02-03T04:05:06.789+09:00")?;
+        assert_eq!(
+            ChronoDateTime::<FixedOffset>::from(offset_date_time),
+            chrono_date_time
+        );
+        assert_eq!(
+            OffsetDateTime::try_from(chrono_date_time)?,
+            offset_date_time
+        );
+
+        let f = |s: &str| -> anyhow::Result<Result<OffsetDateTime, TryFromOffsetDateTimeError>> {
+            Ok(OffsetDateTime::try_from(
+                ChronoDateTime::parse_from_rfc3339(s)?,
+            ))
+        };
+        assert_eq!(
+            f("1970-01-01T00:00:00-00:01")?,
+            Ok(OffsetDateTime::from_str("1970-01-01T00:00:00-00:01")?)
+        );
+        // the instant is out of range
+        assert_eq!(
+            f("1970-01-01T00:00:00+00:01")?,
+            Err(TryFromOffsetDateTimeError::OutOfRange)
+        );
+        // the local date time is out of range
+        assert_eq!(
+            f("1969-12-31T23:59:59-00:01")?,
+            Err(TryFromOffsetDateTimeError::OutOfRange)
+        );
+        assert_eq!(
+            f("9999-12-31T23:59:59-00:01")?,
+            Err(TryFromOffsetDateTimeError::OutOfRange)
+        );
+        Ok(())
+    }
+}
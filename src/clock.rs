@@ -0,0 +1,104 @@
+use std::{convert::TryFrom, sync::Mutex, time::SystemTime};
+
+use thiserror::Error;
+
+use crate::{Instant, PreciseInstant, SignedSeconds};
+
+// the source of the current instant (e.g. Instant::now_in)
+pub trait Clock {
+    fn now(&self) -> Result<PreciseInstant, ClockError>;
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ClockError {
+    #[error("out of range")]
+    OutOfRange,
+}
+
+// std::time::SystemTime
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SystemClock;
+
+// a fixed clock that moves only when it is set or advanced (for tests)
+#[derive(Debug)]
+pub struct ManualClock {
+    now: Mutex<PreciseInstant>,
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Result<PreciseInstant, ClockError> {
+        PreciseInstant::try_from(SystemTime::now()).map_err(|_| ClockError::OutOfRange)
+    }
+}
+
+impl ManualClock {
+    pub fn new(now: PreciseInstant) -> Self {
+        Self {
+            now: Mutex::new(now),
+        }
+    }
+
+    pub fn advance(&self, seconds: SignedSeconds) -> Result<(), ClockError> {
+        self.advance_nanos(i128::from(i64::from(seconds)) * 1_000_000_000)
+    }
+
+    pub fn advance_nanos(&self, nanos: i128) -> Result<(), ClockError> {
+        let mut now = self.now.lock().expect("ManualClock is not poisoned");
+        *now = now.checked_add_nanos(nanos).ok_or(ClockError::OutOfRange)?;
+        Ok(())
+    }
+
+    pub fn set(&self, now: PreciseInstant) {
+        *self.now.lock().expect("ManualClock is not poisoned") = now;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Result<PreciseInstant, ClockError> {
+        Ok(*self.now.lock().expect("ManualClock is not poisoned"))
+    }
+}
+
+impl From<Instant> for ManualClock {
+    fn from(instant: Instant) -> Self {
+        Self::new(PreciseInstant::from(instant))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn system_clock_test() -> anyhow::Result<()> {
+        let now = SystemClock.now()?;
+        assert!(now > PreciseInstant::from_str("2021-01-01T00:00:00Z")?);
+        Ok(())
+    }
+
+    #[test]
+    fn manual_clock_test() -> anyhow::Result<()> {
+        let t = |s: &str| PreciseInstant::from_str(s);
+        let clock = ManualClock::new(t("2021-02-03T04:05:06Z")?);
+        assert_eq!(clock.now()?, t("2021-02-03T04:05:06Z")?);
+        assert_eq!(clock.now()?, t("2021-02-03T04:05:06Z")?);
+        clock.advance(SignedSeconds::from(60_i64))?;
+        assert_eq!(clock.now()?, t("2021-02-03T04:06:06Z")?);
+        clock.advance(SignedSeconds::from(-3_600_i64))?;
+        assert_eq!(clock.now()?, t("2021-02-03T03:06:06Z")?);
+        clock.advance_nanos(500_000_000)?;
+        assert_eq!(clock.now()?, t("2021-02-03T03:06:06.5Z")?);
+        clock.set(t("9999-12-31T23:59:59Z")?);
+        assert_eq!(
+            clock.advance(SignedSeconds::from(1_i64)),
+            Err(ClockError::OutOfRange)
+        );
+        assert_eq!(clock.now()?, t("9999-12-31T23:59:59Z")?);
+
+        let clock = ManualClock::from(Instant::from_str("2021-02-03T04:05:06Z")?);
+        assert_eq!(clock.now()?, t("2021-02-03T04:05:06Z")?);
+        Ok(())
+    }
+}
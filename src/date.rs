@@ -1,15 +1,23 @@
 mod calendar_date;
 mod day_of_month;
+mod day_of_week;
 mod day_of_year;
 mod month;
 mod ordinal_date;
+mod week_date;
+mod week_of_year;
 mod year;
 mod year_month;
+mod year_week;
 
 pub use self::calendar_date::*;
 pub use self::day_of_month::*;
+pub use self::day_of_week::*;
 pub use self::day_of_year::*;
 pub use self::month::*;
 pub use self::ordinal_date::*;
+pub use self::week_date::*;
+pub use self::week_of_year::*;
 pub use self::year::*;
 pub use self::year_month::*;
+pub use self::year_week::*;
@@ -1,9 +1,15 @@
-use crate::private::days_from_unix_epoch_from_date;
-use crate::Days;
+use crate::private::{
+    date_from_days_from_unix_epoch, day_of_week_from_days_from_unix_epoch,
+    days_from_unix_epoch_from_date,
+};
+use crate::range_iter::{range_iter, RangeIter, Step};
+use crate::{Clock, ClockError, Days, Months, OffsetDateTime, Period, SignedDays, TimeZoneOffset};
 
 use std::convert::TryFrom;
+use std::ops::RangeBounds;
 
 use super::day_of_month::{DayOfMonth, ParseDayOfMonthError};
+use super::day_of_week::DayOfWeek;
 use super::day_of_year::DayOfYear;
 use super::month::{Month, ParseMonthError};
 use super::ordinal_date::OrdinalDate;
@@ -40,6 +46,31 @@ pub enum ParseDateError {
 #[error("invalid date error")]
 pub struct InvalidDateError;
 
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum TryFromCalendarDateError {
+    #[error("out of range")]
+    OutOfRange,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum AddMonthsError {
+    #[error("invalid day of month")]
+    InvalidDayOfMonth,
+    #[error("out of range")]
+    OutOfRange,
+}
+
+// 2021-01-31 + 1 month (2021-02-31 is invalid)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EndOfMonthPolicy {
+    // 2021-02-28
+    Clamp,
+    // AddMonthsError::InvalidDayOfMonth
+    Fail,
+    // 2021-03-03 (2021-02-28 + 3 days)
+    Roll,
+}
+
 impl CalendarDate {
     pub fn first_date_of_month(year_month: YearMonth) -> Self {
         Self {
@@ -67,13 +98,21 @@ impl CalendarDate {
         Self::last_date_of_month(year_month)
     }
 
-    pub fn from_ymd(
+    // today at the offset
+    pub fn now_in<C: Clock + ?Sized>(
+        clock: &C,
+        offset: TimeZoneOffset,
+    ) -> Result<Self, ClockError> {
+        OffsetDateTime::now_in(clock, offset).map(|t| t.date_time().date())
+    }
+
+    pub const fn from_ymd(
         year: Year,
         month: Month,
         day_of_month: DayOfMonth,
     ) -> Result<Self, InvalidDateError> {
         let year_month = YearMonth::new(year, month);
-        if day_of_month > year_month.last_day_of_month() {
+        if day_of_month.to_u8() > year_month.last_day_of_month().to_u8() {
             return Err(InvalidDateError);
         }
         Ok(Self {
@@ -87,6 +126,12 @@ impl CalendarDate {
         self.day_of_month
     }
 
+    pub fn day_of_week(&self) -> DayOfWeek {
+        let days_from_unix_epoch = i64::from(self.days_from_unix_epoch());
+        let day_of_week = day_of_week_from_days_from_unix_epoch(days_from_unix_epoch);
+        DayOfWeek::try_from(day_of_week).expect("day of week is [1, 7]")
+    }
+
     pub fn month(&self) -> Month {
         self.month
     }
@@ -119,6 +164,102 @@ impl CalendarDate {
         }
     }
 
+    pub fn add_months(
+        &self,
+        months: Months,
+        policy: EndOfMonthPolicy,
+    ) -> Result<Self, AddMonthsError> {
+        self.add_signed_months(i64::from(u32::from(months)), policy)
+    }
+
+    pub fn sub_months(
+        &self,
+        months: Months,
+        policy: EndOfMonthPolicy,
+    ) -> Result<Self, AddMonthsError> {
+        self.add_signed_months(-i64::from(u32::from(months)), policy)
+    }
+
+    pub fn add_years(&self, years: u32, policy: EndOfMonthPolicy) -> Result<Self, AddMonthsError> {
+        self.add_signed_months(i64::from(years) * 12, policy)
+    }
+
+    pub fn sub_years(&self, years: u32, policy: EndOfMonthPolicy) -> Result<Self, AddMonthsError> {
+        self.add_signed_months(-i64::from(years) * 12, policy)
+    }
+
+    // calendar part: years and months (clamped to the last day of month), then days
+    // exact part: whole days only (None if e.g. PT1H)
+    pub fn checked_add_period(&self, period: Period) -> Option<Self> {
+        self.checked_add_signed_period(period, 1)
+    }
+
+    pub fn checked_sub_period(&self, period: Period) -> Option<Self> {
+        self.checked_add_signed_period(period, -1)
+    }
+
+    fn checked_add_signed_period(&self, period: Period, sign: i64) -> Option<Self> {
+        let months = i64::from(period.total_months()?);
+        let seconds = u64::from(period.seconds());
+        if seconds % 86_400 != 0 {
+            return None;
+        }
+        let days = i64::from(period.days()) + i64::try_from(seconds / 86_400).ok()?;
+        self.checked_add_months_and_days(sign * months, sign * days)
+    }
+
+    pub(crate) fn add_signed_months(
+        &self,
+        months: i64,
+        policy: EndOfMonthPolicy,
+    ) -> Result<Self, AddMonthsError> {
+        let year_month = self
+            .year_month()
+            .months_from_ce()
+            .checked_add(months)
+            .and_then(YearMonth::from_months_from_ce)
+            .ok_or(AddMonthsError::OutOfRange)?;
+        let last_day_of_month = year_month.last_day_of_month();
+        if self.day_of_month() <= last_day_of_month {
+            return Ok(
+                Self::from_ymd(year_month.year(), year_month.month(), self.day_of_month())
+                    .expect("day of month <= last day of month"),
+            );
+        }
+        match policy {
+            EndOfMonthPolicy::Clamp => Ok(Self::last_date_of_month(year_month)),
+            EndOfMonthPolicy::Fail => Err(AddMonthsError::InvalidDayOfMonth),
+            EndOfMonthPolicy::Roll => {
+                let overflow = u8::from(self.day_of_month()) - u8::from(last_day_of_month);
+                Self::last_date_of_month(year_month)
+                    .checked_add_days(i64::from(overflow))
+                    .ok_or(AddMonthsError::OutOfRange)
+            }
+        }
+    }
+
+    pub(crate) fn checked_add_days(&self, days: i64) -> Option<Self> {
+        let days_from_unix_epoch = i64::from(self.days_from_unix_epoch()).checked_add(days)?;
+        Days::try_from(days_from_unix_epoch)
+            .ok()
+            .map(Self::from_days_from_unix_epoch)
+    }
+
+    pub(crate) fn checked_add_months_and_days(&self, months: i64, days: i64) -> Option<Self> {
+        self.add_signed_months(months, EndOfMonthPolicy::Clamp)
+            .ok()?
+            .checked_add_days(days)
+    }
+
+    // UTC における Date と見なして 1970-01-01 からの経過日数から Date を返す
+    pub(crate) fn from_days_from_unix_epoch(days_from_unix_epoch: Days) -> Self {
+        let (y, m, d) = date_from_days_from_unix_epoch(i64::from(days_from_unix_epoch));
+        let year = Year::try_from(y).expect("year is [1970, 9999]");
+        let month = Month::try_from(m).expect("month is [1, 12]");
+        let day_of_month = DayOfMonth::try_from(d).expect("day of month is [1, 31]");
+        Self::from_ymd(year, month, day_of_month).expect("days from unix epoch is broken")
+    }
+
     // UTC における Date と見なして 1970-01-01 からの経過日数を返す
     pub(crate) fn days_from_unix_epoch(&self) -> Days {
         let days_from_unix_epoch = days_from_unix_epoch_from_date((
@@ -130,6 +271,32 @@ impl CalendarDate {
             u32::try_from(days_from_unix_epoch).expect("days from unix epoch is [0, 2_932_896]");
         Days::try_from(days_from_unix_epoch_as_u32).expect("Days supports [0, 2_932_896]")
     }
+
+    pub fn range<R: RangeBounds<Self>>(range: R) -> RangeIter<Self> {
+        range_iter(range)
+    }
+}
+
+impl Step for CalendarDate {
+    fn first() -> Self {
+        Self::first_date_of_year(Year::first())
+    }
+
+    fn last() -> Self {
+        Self::last_date_of_year(Year::last())
+    }
+
+    fn position(&self) -> u32 {
+        u32::from(self.days_from_unix_epoch())
+    }
+
+    fn pred(&self) -> Option<Self> {
+        Self::pred(self)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        Self::succ(self)
+    }
 }
 
 impl std::fmt::Display for CalendarDate {
@@ -145,6 +312,9 @@ impl std::str::FromStr for CalendarDate {
         if s.len() != 10 {
             return Err(Self::Err::InvalidLength);
         }
+        if !s.is_ascii() {
+            return Err(Self::Err::InvalidFormat);
+        }
         let year_month = match YearMonth::from_str(&s[0..7]) {
             Ok(ym) => ym,
             Err(e) => match e {
@@ -172,13 +342,53 @@ impl std::str::FromStr for CalendarDate {
     }
 }
 
+impl std::ops::Add<Days> for CalendarDate {
+    type Output = CalendarDate;
+
+    fn add(self, rhs: Days) -> Self::Output {
+        self + SignedDays::from(rhs)
+    }
+}
+
+impl std::ops::Add<SignedDays> for CalendarDate {
+    type Output = CalendarDate;
+
+    fn add(self, rhs: SignedDays) -> Self::Output {
+        self.checked_add_days(i64::from(rhs))
+            .unwrap_or_else(|| panic!("overflow"))
+    }
+}
+
+impl std::ops::Sub<Days> for CalendarDate {
+    type Output = CalendarDate;
+
+    fn sub(self, rhs: Days) -> Self::Output {
+        self + -SignedDays::from(rhs)
+    }
+}
+
+impl std::ops::Sub<SignedDays> for CalendarDate {
+    type Output = CalendarDate;
+
+    fn sub(self, rhs: SignedDays) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl std::ops::Sub<CalendarDate> for CalendarDate {
+    type Output = SignedDays;
+
+    fn sub(self, rhs: CalendarDate) -> Self::Output {
+        let days = i64::from(self.days_from_unix_epoch()) - i64::from(rhs.days_from_unix_epoch());
+        SignedDays::try_from(days).expect("signed days is [-2_932_896, 2_932_896]")
+    }
+}
+
 impl From<CalendarDate> for OrdinalDate {
     fn from(date: CalendarDate) -> Self {
         let year = date.year();
         let mut days = 0_u16;
-        // TODO: impl Iterator for Range<Month>
-        for m in u8::from(Month::january())..u8::from(date.month()) {
-            let m = Month::try_from(m).unwrap();
+        for m in Month::range(..date.month()) {
             let year_month = YearMonth::new(year, m);
             days += u16::try_from(u32::from(year_month.days()))
                 .expect("sum of year_month.days() in year <= 366");
@@ -195,9 +405,7 @@ impl From<OrdinalDate> for CalendarDate {
         let year = ordinal_date.year();
         let day_of_year = u16::from(ordinal_date.day_of_year());
         let mut days = 0_u16;
-        // TODO: impl Iterator for Range<Month>
-        for m in u8::from(Month::january())..=u8::from(Month::december()) {
-            let m = Month::try_from(m).unwrap();
+        for m in Month::range(..) {
             let year_month = YearMonth::new(year, m);
             let days_of_month = u16::try_from(u32::from(year_month.days()))
                 .expect("sum of year_month.days() in year <= 366");
@@ -219,8 +427,24 @@ impl From<OrdinalDate> for CalendarDate {
 mod tests {
     use std::str::FromStr;
 
+    use crate::{Instant, ManualClock};
+
     use super::*;
 
+    #[test]
+    fn now_in_test() -> anyhow::Result<()> {
+        let clock = ManualClock::from(Instant::from_str("2021-02-03T19:05:06Z")?);
+        assert_eq!(
+            CalendarDate::now_in(&clock, TimeZoneOffset::utc())?.to_string(),
+            "2021-02-03"
+        );
+        assert_eq!(
+            CalendarDate::now_in(&clock, TimeZoneOffset::from_h(9)?)?.to_string(),
+            "2021-02-04"
+        );
+        Ok(())
+    }
+
     #[test]
     fn first_date_of_month_test() -> anyhow::Result<()> {
         let year_month = YearMonth::from_str("2021-01")?;
@@ -292,10 +516,11 @@ mod tests {
         type E = ParseDateError;
         let f = |s: &str| CalendarDate::from_str(s);
 
-        assert!(matches!(f("2021-01-02"), Ok(_)));
+        assert!(f("2021-01-02").is_ok());
         assert!(matches!(f("20021-01-02"), Err(E::InvalidLength)));
         assert!(matches!(f("2021+01-02"), Err(E::InvalidFormat)));
         assert!(matches!(f("2021-01+02"), Err(E::InvalidFormat)));
+        assert!(matches!(f("2021-0é02"), Err(E::InvalidFormat)));
         assert!(matches!(f("+001-01-02"), Err(E::ParseYear(_))));
         assert!(matches!(f("2021-13-02"), Err(E::ParseMonth(_))));
         assert!(matches!(f("2021-01-32"), Err(E::ParseDayOfMonth(_))));
@@ -314,6 +539,18 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn day_of_week_test() -> anyhow::Result<()> {
+        let f =
+            |s: &str| -> anyhow::Result<DayOfWeek> { Ok(CalendarDate::from_str(s)?.day_of_week()) };
+        assert_eq!(f("1970-01-01")?, DayOfWeek::thursday());
+        assert_eq!(f("2021-02-01")?, DayOfWeek::monday());
+        assert_eq!(f("2021-02-03")?, DayOfWeek::wednesday());
+        assert_eq!(f("2021-02-07")?, DayOfWeek::sunday());
+        assert_eq!(f("9999-12-31")?, DayOfWeek::friday());
+        Ok(())
+    }
+
     #[test]
     fn month_test() -> anyhow::Result<()> {
         let d = CalendarDate::from_str("2021-01-02")?;
@@ -392,6 +629,251 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn from_days_from_unix_epoch_test() -> anyhow::Result<()> {
+        assert_eq!(
+            CalendarDate::from_days_from_unix_epoch(Days::from(0_u8)),
+            CalendarDate::from_str("1970-01-01")?
+        );
+        assert_eq!(
+            CalendarDate::from_days_from_unix_epoch(Days::from(1_u8)),
+            CalendarDate::from_str("1970-01-02")?
+        );
+        assert_eq!(
+            CalendarDate::from_days_from_unix_epoch(Days::try_from(2_932_896_u32)?),
+            CalendarDate::from_str("9999-12-31")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn add_months_test() -> anyhow::Result<()> {
+        type E = AddMonthsError;
+        type P = EndOfMonthPolicy;
+        let d = |s: &str| CalendarDate::from_str(s);
+        let m = |m: u32| Months::from(m);
+        assert_eq!(
+            d("2021-01-15")?.add_months(m(1), P::Fail)?,
+            d("2021-02-15")?
+        );
+        assert_eq!(
+            d("2021-01-15")?.add_months(m(12), P::Fail)?,
+            d("2022-01-15")?
+        );
+        assert_eq!(
+            d("2021-01-31")?.add_months(m(1), P::Clamp)?,
+            d("2021-02-28")?
+        );
+        assert_eq!(
+            d("2020-01-31")?.add_months(m(1), P::Clamp)?,
+            d("2020-02-29")?
+        );
+        assert_eq!(
+            d("2021-01-31")?.add_months(m(1), P::Fail),
+            Err(E::InvalidDayOfMonth)
+        );
+        assert_eq!(
+            d("2021-01-31")?.add_months(m(1), P::Roll)?,
+            d("2021-03-03")?
+        );
+        assert_eq!(
+            d("2020-01-31")?.add_months(m(1), P::Roll)?,
+            d("2020-03-02")?
+        );
+        assert_eq!(
+            d("2021-01-30")?.add_months(m(3), P::Roll)?,
+            d("2021-04-30")?
+        );
+        assert_eq!(
+            d("2021-03-31")?.add_months(m(1), P::Roll)?,
+            d("2021-05-01")?
+        );
+        assert_eq!(
+            d("9999-12-01")?.add_months(m(1), P::Clamp),
+            Err(E::OutOfRange)
+        );
+        assert_eq!(
+            d("9999-11-30")?.add_months(m(1), P::Roll)?,
+            d("9999-12-30")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sub_months_test() -> anyhow::Result<()> {
+        type E = AddMonthsError;
+        type P = EndOfMonthPolicy;
+        let d = |s: &str| CalendarDate::from_str(s);
+        let m = |m: u32| Months::from(m);
+        assert_eq!(
+            d("2021-03-31")?.sub_months(m(1), P::Clamp)?,
+            d("2021-02-28")?
+        );
+        assert_eq!(
+            d("2021-03-31")?.sub_months(m(1), P::Fail),
+            Err(E::InvalidDayOfMonth)
+        );
+        assert_eq!(
+            d("2021-03-31")?.sub_months(m(1), P::Roll)?,
+            d("2021-03-03")?
+        );
+        assert_eq!(
+            d("2021-03-15")?.sub_months(m(14), P::Fail)?,
+            d("2020-01-15")?
+        );
+        assert_eq!(
+            d("1970-01-31")?.sub_months(m(1), P::Clamp),
+            Err(E::OutOfRange)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn add_years_test() -> anyhow::Result<()> {
+        type E = AddMonthsError;
+        type P = EndOfMonthPolicy;
+        let d = |s: &str| CalendarDate::from_str(s);
+        assert_eq!(d("2021-02-03")?.add_years(1, P::Fail)?, d("2022-02-03")?);
+        assert_eq!(d("2020-02-29")?.add_years(1, P::Clamp)?, d("2021-02-28")?);
+        assert_eq!(
+            d("2020-02-29")?.add_years(1, P::Fail),
+            Err(E::InvalidDayOfMonth)
+        );
+        assert_eq!(d("2020-02-29")?.add_years(1, P::Roll)?, d("2021-03-01")?);
+        assert_eq!(d("2020-02-29")?.add_years(4, P::Fail)?, d("2024-02-29")?);
+        assert_eq!(d("9999-01-01")?.add_years(1, P::Fail), Err(E::OutOfRange));
+        Ok(())
+    }
+
+    #[test]
+    fn sub_years_test() -> anyhow::Result<()> {
+        type E = AddMonthsError;
+        type P = EndOfMonthPolicy;
+        let d = |s: &str| CalendarDate::from_str(s);
+        assert_eq!(d("2021-02-03")?.sub_years(1, P::Fail)?, d("2020-02-03")?);
+        assert_eq!(d("2020-02-29")?.sub_years(1, P::Clamp)?, d("2019-02-28")?);
+        assert_eq!(
+            d("2020-02-29")?.sub_years(1, P::Fail),
+            Err(E::InvalidDayOfMonth)
+        );
+        assert_eq!(d("1970-12-31")?.sub_years(1, P::Fail), Err(E::OutOfRange));
+        Ok(())
+    }
+
+    #[test]
+    fn checked_add_period_test() -> anyhow::Result<()> {
+        let d = |s: &str| CalendarDate::from_str(s);
+        let p = |s: &str| Period::from_str(s);
+        assert_eq!(
+            d("2021-02-03")?.checked_add_period(p("P1Y2M10D")?),
+            Some(d("2022-04-13")?)
+        );
+        assert_eq!(
+            d("2021-01-31")?.checked_add_period(p("P1M")?),
+            Some(d("2021-02-28")?)
+        );
+        assert_eq!(
+            d("2020-02-29")?.checked_add_period(p("P1Y")?),
+            Some(d("2021-02-28")?)
+        );
+        assert_eq!(
+            d("2021-01-31")?.checked_add_period(p("P1M1D")?),
+            Some(d("2021-03-01")?)
+        );
+        assert_eq!(
+            d("2021-02-03")?.checked_add_period(p("PT48H")?),
+            Some(d("2021-02-05")?)
+        );
+        assert_eq!(d("2021-02-03")?.checked_add_period(p("PT47H")?), None);
+        assert_eq!(d("2021-02-03")?.checked_add_period(p("PT1H")?), None);
+        assert_eq!(d("9999-12-31")?.checked_add_period(p("P1D")?), None);
+        assert_eq!(d("9999-12-01")?.checked_add_period(p("P1M")?), None);
+        Ok(())
+    }
+
+    #[test]
+    fn checked_sub_period_test() -> anyhow::Result<()> {
+        let d = |s: &str| CalendarDate::from_str(s);
+        let p = |s: &str| Period::from_str(s);
+        assert_eq!(
+            d("2022-04-13")?.checked_sub_period(p("P1Y2M10D")?),
+            Some(d("2021-02-03")?)
+        );
+        assert_eq!(
+            d("2021-03-31")?.checked_sub_period(p("P1M")?),
+            Some(d("2021-02-28")?)
+        );
+        assert_eq!(
+            d("2021-02-03")?.checked_sub_period(p("PT24H")?),
+            Some(d("2021-02-02")?)
+        );
+        assert_eq!(d("2021-02-03")?.checked_sub_period(p("P1DT1S")?), None);
+        assert_eq!(d("1970-01-01")?.checked_sub_period(p("P1D")?), None);
+        assert_eq!(d("1970-01-31")?.checked_sub_period(p("P1M")?), None);
+        Ok(())
+    }
+
+    #[test]
+    fn add_days_test() -> anyhow::Result<()> {
+        let d = |s: &str| CalendarDate::from_str(s);
+        assert_eq!(d("2021-02-03")? + Days::from(0_u8), d("2021-02-03")?);
+        assert_eq!(d("2021-02-03")? + Days::from(26_u8), d("2021-03-01")?);
+        assert_eq!(d("2021-12-31")? + Days::from(1_u8), d("2022-01-01")?);
+        assert_eq!(
+            d("1970-01-01")? + Days::try_from(2_932_896_u32)?,
+            d("9999-12-31")?
+        );
+        // should_panic
+        // d("9999-12-31")? + Days::from(1_u8)
+        Ok(())
+    }
+
+    #[test]
+    fn add_signed_days_test() -> anyhow::Result<()> {
+        let d = |s: &str| CalendarDate::from_str(s);
+        assert_eq!(d("2021-02-03")? + SignedDays::from(1_i8), d("2021-02-04")?);
+        assert_eq!(d("2021-02-03")? + SignedDays::from(-3_i8), d("2021-01-31")?);
+        Ok(())
+    }
+
+    #[test]
+    fn sub_days_test() -> anyhow::Result<()> {
+        let d = |s: &str| CalendarDate::from_str(s);
+        assert_eq!(d("2021-03-01")? - Days::from(1_u8), d("2021-02-28")?);
+        assert_eq!(d("2022-01-01")? - Days::from(1_u8), d("2021-12-31")?);
+        // should_panic
+        // d("1970-01-01")? - Days::from(1_u8)
+        Ok(())
+    }
+
+    #[test]
+    fn sub_signed_days_test() -> anyhow::Result<()> {
+        let d = |s: &str| CalendarDate::from_str(s);
+        assert_eq!(d("2021-02-03")? - SignedDays::from(3_i8), d("2021-01-31")?);
+        assert_eq!(d("2021-02-03")? - SignedDays::from(-1_i8), d("2021-02-04")?);
+        Ok(())
+    }
+
+    #[test]
+    fn sub_calendar_date_test() -> anyhow::Result<()> {
+        let d = |s: &str| CalendarDate::from_str(s);
+        assert_eq!(d("2021-02-03")? - d("2021-02-03")?, SignedDays::from(0_i8));
+        assert_eq!(d("2021-03-01")? - d("2021-02-03")?, SignedDays::from(26_i8));
+        assert_eq!(
+            d("2021-02-03")? - d("2021-03-01")?,
+            SignedDays::from(-26_i8)
+        );
+        assert_eq!(
+            d("9999-12-31")? - d("1970-01-01")?,
+            SignedDays::try_from(2_932_896_i32)?
+        );
+        assert_eq!(
+            d("1970-01-01")? - d("9999-12-31")?,
+            SignedDays::try_from(-2_932_896_i32)?
+        );
+        Ok(())
+    }
+
     #[test]
     fn date_conversion_test() -> anyhow::Result<()> {
         assert_eq!(
@@ -412,4 +894,35 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn range_test() -> anyhow::Result<()> {
+        let (a, b) = (
+            CalendarDate::from_str("2020-02-28")?,
+            CalendarDate::from_str("2020-03-01")?,
+        );
+        let f = |v: Vec<CalendarDate>| v.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+        assert_eq!(
+            f(CalendarDate::range(a..=b).collect()),
+            vec!["2020-02-28", "2020-02-29", "2020-03-01"]
+        );
+        assert_eq!(
+            f(CalendarDate::range(a..b).collect()),
+            vec!["2020-02-28", "2020-02-29"]
+        );
+        assert_eq!(
+            f(CalendarDate::range(a..=b).rev().collect()),
+            vec!["2020-03-01", "2020-02-29", "2020-02-28"]
+        );
+        assert_eq!(CalendarDate::range(a..=b).len(), 3);
+        assert_eq!(CalendarDate::range(b..a).len(), 0);
+        assert_eq!(CalendarDate::range(a..a).len(), 0);
+        assert_eq!(CalendarDate::range(..).len(), 2932897);
+        assert_eq!(CalendarDate::range(..).next(), Some(CalendarDate::first()));
+        assert_eq!(
+            CalendarDate::range(..).next_back(),
+            Some(CalendarDate::last())
+        );
+        Ok(())
+    }
 }
@@ -1,6 +1,9 @@
 use std::convert::TryFrom;
+use std::ops::RangeBounds;
 use thiserror::Error;
 
+use crate::range_iter::{range_iter, RangeIter, Step};
+
 use crate::Days;
 
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -11,6 +14,17 @@ impl DayOfMonth {
         Days::from(1_u8)
     }
 
+    pub(crate) const fn from_u8(value: u8) -> Option<Self> {
+        if value < 1 || value > 31 {
+            return None;
+        }
+        Some(Self(value))
+    }
+
+    pub(crate) const fn to_u8(self) -> u8 {
+        self.0
+    }
+
     pub fn pred(&self) -> Option<Self> {
         if self.0 > 1 {
             Some(Self(self.0 - 1))
@@ -26,6 +40,32 @@ impl DayOfMonth {
             None
         }
     }
+
+    pub fn range<R: RangeBounds<Self>>(range: R) -> RangeIter<Self> {
+        range_iter(range)
+    }
+}
+
+impl Step for DayOfMonth {
+    fn first() -> Self {
+        Self(1)
+    }
+
+    fn last() -> Self {
+        Self(31)
+    }
+
+    fn position(&self) -> u32 {
+        u32::from(self.0)
+    }
+
+    fn pred(&self) -> Option<Self> {
+        Self::pred(self)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        Self::succ(self)
+    }
 }
 
 #[derive(Debug, Eq, Error, PartialEq)]
@@ -157,10 +197,7 @@ impl std::convert::TryFrom<u8> for DayOfMonth {
     type Error = TryFromDayOfMonthError;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
-        if !(1..=31).contains(&value) {
-            return Err(Self::Error::OutOfRange);
-        }
-        Ok(Self(value))
+        Self::from_u8(value).ok_or(Self::Error::OutOfRange)
     }
 }
 
@@ -359,4 +396,26 @@ mod tests {
         assert_eq!(DayOfMonth::try_from(1)?.days(), Days::from(1_u16));
         Ok(())
     }
+
+    #[test]
+    fn range_test() -> anyhow::Result<()> {
+        let (a, b) = ("03".parse::<DayOfMonth>()?, "05".parse::<DayOfMonth>()?);
+        let f = |v: Vec<DayOfMonth>| v.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+        assert_eq!(
+            f(DayOfMonth::range(a..=b).collect()),
+            vec!["03", "04", "05"]
+        );
+        assert_eq!(f(DayOfMonth::range(a..b).collect()), vec!["03", "04"]);
+        assert_eq!(
+            f(DayOfMonth::range(a..=b).rev().collect()),
+            vec!["05", "04", "03"]
+        );
+        assert_eq!(DayOfMonth::range(a..=b).len(), 3);
+        assert_eq!(DayOfMonth::range(b..a).len(), 0);
+        assert_eq!(DayOfMonth::range(a..a).len(), 0);
+        assert_eq!(DayOfMonth::range(..).len(), 31);
+        assert_eq!(DayOfMonth::range(..).next(), Some(DayOfMonth::first()));
+        assert_eq!(DayOfMonth::range(..).next_back(), Some(DayOfMonth::last()));
+        Ok(())
+    }
 }
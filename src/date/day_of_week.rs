@@ -0,0 +1,386 @@
+use std::convert::TryFrom;
+use thiserror::Error;
+
+// [1, 7] (1: Monday, 7: Sunday)
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct DayOfWeek(u8);
+
+impl DayOfWeek {
+    pub fn monday() -> Self {
+        Self(1)
+    }
+
+    pub fn tuesday() -> Self {
+        Self(2)
+    }
+
+    pub fn wednesday() -> Self {
+        Self(3)
+    }
+
+    pub fn thursday() -> Self {
+        Self(4)
+    }
+
+    pub fn friday() -> Self {
+        Self(5)
+    }
+
+    pub fn saturday() -> Self {
+        Self(6)
+    }
+
+    pub fn sunday() -> Self {
+        Self(7)
+    }
+
+    pub fn pred(&self) -> Option<Self> {
+        if self.0 > 1 {
+            Some(Self(self.0 - 1))
+        } else {
+            None
+        }
+    }
+
+    pub fn succ(&self) -> Option<Self> {
+        if self.0 < 7 {
+            Some(Self(self.0 + 1))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParseDayOfWeekError {
+    #[error("invalid digit")]
+    InvalidDigit,
+    #[error("invalid length")]
+    InvalidLength,
+    #[error("out of range")]
+    OutOfRange,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum TryFromDayOfWeekError {
+    #[error("out of range")]
+    OutOfRange,
+}
+
+impl std::fmt::Display for DayOfWeek {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for DayOfWeek {
+    type Err = ParseDayOfWeekError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 1 {
+            return Err(Self::Err::InvalidLength);
+        }
+        let mut dow = 0_u8;
+        for c in s.chars() {
+            let d = match c {
+                '0'..='9' => c as u8 - b'0',
+                _ => return Err(Self::Err::InvalidDigit),
+            };
+            dow = dow * 10 + d;
+        }
+        Self::try_from(dow).map_err(|_| Self::Err::OutOfRange)
+    }
+}
+
+impl From<DayOfWeek> for i8 {
+    fn from(day_of_week: DayOfWeek) -> Self {
+        i8::try_from(day_of_week.0).expect("day_of_week is [1,7]")
+    }
+}
+
+impl From<DayOfWeek> for i16 {
+    fn from(day_of_week: DayOfWeek) -> Self {
+        i16::from(day_of_week.0)
+    }
+}
+
+impl From<DayOfWeek> for i32 {
+    fn from(day_of_week: DayOfWeek) -> Self {
+        i32::from(day_of_week.0)
+    }
+}
+
+impl From<DayOfWeek> for i64 {
+    fn from(day_of_week: DayOfWeek) -> Self {
+        i64::from(day_of_week.0)
+    }
+}
+
+impl From<DayOfWeek> for u8 {
+    fn from(day_of_week: DayOfWeek) -> Self {
+        day_of_week.0
+    }
+}
+
+impl From<DayOfWeek> for u16 {
+    fn from(day_of_week: DayOfWeek) -> Self {
+        u16::from(day_of_week.0)
+    }
+}
+
+impl From<DayOfWeek> for u32 {
+    fn from(day_of_week: DayOfWeek) -> Self {
+        u32::from(day_of_week.0)
+    }
+}
+
+impl From<DayOfWeek> for u64 {
+    fn from(day_of_week: DayOfWeek) -> Self {
+        u64::from(day_of_week.0)
+    }
+}
+
+impl std::convert::TryFrom<i8> for DayOfWeek {
+    type Error = TryFromDayOfWeekError;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        let value_as_u8 = u8::try_from(value).map_err(|_| Self::Error::OutOfRange)?;
+        Self::try_from(value_as_u8)
+    }
+}
+
+impl std::convert::TryFrom<i16> for DayOfWeek {
+    type Error = TryFromDayOfWeekError;
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        let value_as_u8 = u8::try_from(value).map_err(|_| Self::Error::OutOfRange)?;
+        Self::try_from(value_as_u8)
+    }
+}
+
+impl std::convert::TryFrom<i32> for DayOfWeek {
+    type Error = TryFromDayOfWeekError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        let value_as_u8 = u8::try_from(value).map_err(|_| Self::Error::OutOfRange)?;
+        Self::try_from(value_as_u8)
+    }
+}
+
+impl std::convert::TryFrom<i64> for DayOfWeek {
+    type Error = TryFromDayOfWeekError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        let value_as_u8 = u8::try_from(value).map_err(|_| Self::Error::OutOfRange)?;
+        Self::try_from(value_as_u8)
+    }
+}
+
+impl std::convert::TryFrom<u8> for DayOfWeek {
+    type Error = TryFromDayOfWeekError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if !(1..=7).contains(&value) {
+            return Err(Self::Error::OutOfRange);
+        }
+        Ok(Self(value))
+    }
+}
+
+impl std::convert::TryFrom<u16> for DayOfWeek {
+    type Error = TryFromDayOfWeekError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        let value_as_u8 = u8::try_from(value).map_err(|_| Self::Error::OutOfRange)?;
+        Self::try_from(value_as_u8)
+    }
+}
+
+impl std::convert::TryFrom<u32> for DayOfWeek {
+    type Error = TryFromDayOfWeekError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        let value_as_u8 = u8::try_from(value).map_err(|_| Self::Error::OutOfRange)?;
+        Self::try_from(value_as_u8)
+    }
+}
+
+impl std::convert::TryFrom<u64> for DayOfWeek {
+    type Error = TryFromDayOfWeekError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        let value_as_u8 = u8::try_from(value).map_err(|_| Self::Error::OutOfRange)?;
+        Self::try_from(value_as_u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn str_conversion_test() {
+        type E = ParseDayOfWeekError;
+        let f = |s: &str| s.parse::<DayOfWeek>();
+        assert_eq!(f("1").map(|d| d.to_string()), Ok("1".to_string()));
+        assert_eq!(f("7").map(|d| d.to_string()), Ok("7".to_string()));
+        assert_eq!(f(""), Err(E::InvalidLength));
+        assert_eq!(f("01"), Err(E::InvalidLength));
+        assert_eq!(f("a"), Err(E::InvalidDigit));
+        assert_eq!(f("+"), Err(E::InvalidDigit));
+        assert_eq!(f("0"), Err(E::OutOfRange));
+        assert_eq!(f("8"), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn i8_conversion_test() {
+        type E = TryFromDayOfWeekError;
+        let f = |d: i8| DayOfWeek::try_from(d);
+        assert_eq!(f(0_i8), Err(E::OutOfRange));
+        assert_eq!(f(1_i8).map(i8::from), Ok(1_i8));
+        assert_eq!(f(7_i8).map(i8::from), Ok(7_i8));
+        assert_eq!(f(8_i8), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn i16_conversion_test() {
+        type E = TryFromDayOfWeekError;
+        let f = |d: i16| DayOfWeek::try_from(d);
+        assert_eq!(f(0_i16), Err(E::OutOfRange));
+        assert_eq!(f(1_i16).map(i16::from), Ok(1_i16));
+        assert_eq!(f(7_i16).map(i16::from), Ok(7_i16));
+        assert_eq!(f(8_i16), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn i32_conversion_test() {
+        type E = TryFromDayOfWeekError;
+        let f = |d: i32| DayOfWeek::try_from(d);
+        assert_eq!(f(0_i32), Err(E::OutOfRange));
+        assert_eq!(f(1_i32).map(i32::from), Ok(1_i32));
+        assert_eq!(f(7_i32).map(i32::from), Ok(7_i32));
+        assert_eq!(f(8_i32), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn i64_conversion_test() {
+        type E = TryFromDayOfWeekError;
+        let f = |d: i64| DayOfWeek::try_from(d);
+        assert_eq!(f(0_i64), Err(E::OutOfRange));
+        assert_eq!(f(1_i64).map(i64::from), Ok(1_i64));
+        assert_eq!(f(7_i64).map(i64::from), Ok(7_i64));
+        assert_eq!(f(8_i64), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn u8_conversion_test() {
+        type E = TryFromDayOfWeekError;
+        let f = |d: u8| DayOfWeek::try_from(d);
+        assert_eq!(f(0_u8), Err(E::OutOfRange));
+        assert_eq!(f(1_u8).map(u8::from), Ok(1_u8));
+        assert_eq!(f(7_u8).map(u8::from), Ok(7_u8));
+        assert_eq!(f(8_u8), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn u16_conversion_test() {
+        type E = TryFromDayOfWeekError;
+        let f = |d: u16| DayOfWeek::try_from(d);
+        assert_eq!(f(0_u16), Err(E::OutOfRange));
+        assert_eq!(f(1_u16).map(u16::from), Ok(1_u16));
+        assert_eq!(f(7_u16).map(u16::from), Ok(7_u16));
+        assert_eq!(f(8_u16), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn u32_conversion_test() {
+        type E = TryFromDayOfWeekError;
+        let f = |d: u32| DayOfWeek::try_from(d);
+        assert_eq!(f(0_u32), Err(E::OutOfRange));
+        assert_eq!(f(1_u32).map(u32::from), Ok(1_u32));
+        assert_eq!(f(7_u32).map(u32::from), Ok(7_u32));
+        assert_eq!(f(8_u32), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn u64_conversion_test() {
+        type E = TryFromDayOfWeekError;
+        let f = |d: u64| DayOfWeek::try_from(d);
+        assert_eq!(f(0_u64), Err(E::OutOfRange));
+        assert_eq!(f(1_u64).map(u64::from), Ok(1_u64));
+        assert_eq!(f(7_u64).map(u64::from), Ok(7_u64));
+        assert_eq!(f(8_u64), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn pred_test() -> anyhow::Result<()> {
+        assert_eq!(
+            DayOfWeek::try_from(7)?.pred(),
+            Some(DayOfWeek::try_from(6)?)
+        );
+        assert_eq!(
+            DayOfWeek::try_from(6)?.pred(),
+            Some(DayOfWeek::try_from(5)?)
+        );
+        assert_eq!(
+            DayOfWeek::try_from(5)?.pred(),
+            Some(DayOfWeek::try_from(4)?)
+        );
+        assert_eq!(
+            DayOfWeek::try_from(4)?.pred(),
+            Some(DayOfWeek::try_from(3)?)
+        );
+        assert_eq!(
+            DayOfWeek::try_from(3)?.pred(),
+            Some(DayOfWeek::try_from(2)?)
+        );
+        assert_eq!(
+            DayOfWeek::try_from(2)?.pred(),
+            Some(DayOfWeek::try_from(1)?)
+        );
+        assert_eq!(DayOfWeek::try_from(1)?.pred(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn succ_test() -> anyhow::Result<()> {
+        assert_eq!(
+            DayOfWeek::try_from(1)?.succ(),
+            Some(DayOfWeek::try_from(2)?)
+        );
+        assert_eq!(
+            DayOfWeek::try_from(2)?.succ(),
+            Some(DayOfWeek::try_from(3)?)
+        );
+        assert_eq!(
+            DayOfWeek::try_from(3)?.succ(),
+            Some(DayOfWeek::try_from(4)?)
+        );
+        assert_eq!(
+            DayOfWeek::try_from(4)?.succ(),
+            Some(DayOfWeek::try_from(5)?)
+        );
+        assert_eq!(
+            DayOfWeek::try_from(5)?.succ(),
+            Some(DayOfWeek::try_from(6)?)
+        );
+        assert_eq!(
+            DayOfWeek::try_from(6)?.succ(),
+            Some(DayOfWeek::try_from(7)?)
+        );
+        assert_eq!(DayOfWeek::try_from(7)?.succ(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn name_test() -> anyhow::Result<()> {
+        assert_eq!(DayOfWeek::try_from(1)?, DayOfWeek::monday());
+        assert_eq!(DayOfWeek::try_from(2)?, DayOfWeek::tuesday());
+        assert_eq!(DayOfWeek::try_from(3)?, DayOfWeek::wednesday());
+        assert_eq!(DayOfWeek::try_from(4)?, DayOfWeek::thursday());
+        assert_eq!(DayOfWeek::try_from(5)?, DayOfWeek::friday());
+        assert_eq!(DayOfWeek::try_from(6)?, DayOfWeek::saturday());
+        assert_eq!(DayOfWeek::try_from(7)?, DayOfWeek::sunday());
+        Ok(())
+    }
+}
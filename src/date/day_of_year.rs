@@ -1,6 +1,9 @@
 use std::convert::TryFrom;
+use std::ops::RangeBounds;
 use thiserror::Error;
 
+use crate::range_iter::{range_iter, RangeIter, Step};
+
 use crate::Days;
 
 // [1, 366]
@@ -43,6 +46,32 @@ impl DayOfYear {
             None
         }
     }
+
+    pub fn range<R: RangeBounds<Self>>(range: R) -> RangeIter<Self> {
+        range_iter(range)
+    }
+}
+
+impl Step for DayOfYear {
+    fn first() -> Self {
+        Self::min()
+    }
+
+    fn last() -> Self {
+        Self::max()
+    }
+
+    fn position(&self) -> u32 {
+        u32::from(self.0)
+    }
+
+    fn pred(&self) -> Option<Self> {
+        Self::pred(self)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        Self::succ(self)
+    }
 }
 
 #[derive(Debug, Eq, Error, PartialEq)]
@@ -388,4 +417,26 @@ mod tests {
         assert_eq!(DayOfYear::try_from(1)?.days(), Days::from(1_u16));
         Ok(())
     }
+
+    #[test]
+    fn range_test() -> anyhow::Result<()> {
+        let (a, b) = ("003".parse::<DayOfYear>()?, "005".parse::<DayOfYear>()?);
+        let f = |v: Vec<DayOfYear>| v.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+        assert_eq!(
+            f(DayOfYear::range(a..=b).collect()),
+            vec!["003", "004", "005"]
+        );
+        assert_eq!(f(DayOfYear::range(a..b).collect()), vec!["003", "004"]);
+        assert_eq!(
+            f(DayOfYear::range(a..=b).rev().collect()),
+            vec!["005", "004", "003"]
+        );
+        assert_eq!(DayOfYear::range(a..=b).len(), 3);
+        assert_eq!(DayOfYear::range(b..a).len(), 0);
+        assert_eq!(DayOfYear::range(a..a).len(), 0);
+        assert_eq!(DayOfYear::range(..).len(), 366);
+        assert_eq!(DayOfYear::range(..).next(), Some(DayOfYear::first()));
+        assert_eq!(DayOfYear::range(..).next_back(), Some(DayOfYear::last()));
+        Ok(())
+    }
 }
@@ -1,58 +1,72 @@
 use std::convert::TryFrom;
+use std::ops::RangeBounds;
 use thiserror::Error;
 
+use crate::range_iter::{range_iter, RangeIter, Step};
+
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Month(u8);
 
 impl Month {
-    pub fn january() -> Self {
+    pub const fn january() -> Self {
         Self(1)
     }
 
-    pub fn february() -> Self {
+    pub const fn february() -> Self {
         Self(2)
     }
 
-    pub fn march() -> Self {
+    pub const fn march() -> Self {
         Self(3)
     }
 
-    pub fn april() -> Self {
+    pub const fn april() -> Self {
         Self(4)
     }
 
-    pub fn may() -> Self {
+    pub const fn may() -> Self {
         Self(5)
     }
 
-    pub fn june() -> Self {
+    pub const fn june() -> Self {
         Self(6)
     }
 
-    pub fn july() -> Self {
+    pub const fn july() -> Self {
         Self(7)
     }
 
-    pub fn august() -> Self {
+    pub const fn august() -> Self {
         Self(8)
     }
 
-    pub fn september() -> Self {
+    pub const fn september() -> Self {
         Self(9)
     }
 
-    pub fn october() -> Self {
+    pub const fn october() -> Self {
         Self(10)
     }
 
-    pub fn november() -> Self {
+    pub const fn november() -> Self {
         Self(11)
     }
 
-    pub fn december() -> Self {
+    pub const fn december() -> Self {
         Self(12)
     }
 
+    pub(crate) const fn from_u8(value: u8) -> Option<Self> {
+        if value < 1 || value > 12 {
+            return None;
+        }
+        Some(Self(value))
+    }
+
+    pub(crate) const fn to_u8(self) -> u8 {
+        self.0
+    }
+
     pub fn pred(&self) -> Option<Self> {
         if self.0 > 1 {
             Some(Self(self.0 - 1))
@@ -68,6 +82,32 @@ impl Month {
             None
         }
     }
+
+    pub fn range<R: RangeBounds<Self>>(range: R) -> RangeIter<Self> {
+        range_iter(range)
+    }
+}
+
+impl Step for Month {
+    fn first() -> Self {
+        Self::january()
+    }
+
+    fn last() -> Self {
+        Self::december()
+    }
+
+    fn position(&self) -> u32 {
+        u32::from(self.0)
+    }
+
+    fn pred(&self) -> Option<Self> {
+        Self::pred(self)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        Self::succ(self)
+    }
 }
 
 #[derive(Debug, Eq, Error, PartialEq)]
@@ -199,10 +239,7 @@ impl std::convert::TryFrom<u8> for Month {
     type Error = TryFromMonthError;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
-        if !(1..=12).contains(&value) {
-            return Err(Self::Error::OutOfRange);
-        }
-        Ok(Self(value))
+        Self::from_u8(value).ok_or(Self::Error::OutOfRange)
     }
 }
 
@@ -382,4 +419,23 @@ mod tests {
         assert_eq!(Month::try_from(12)?, Month::december());
         Ok(())
     }
+
+    #[test]
+    fn range_test() -> anyhow::Result<()> {
+        let (a, b) = ("03".parse::<Month>()?, "05".parse::<Month>()?);
+        let f = |v: Vec<Month>| v.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+        assert_eq!(f(Month::range(a..=b).collect()), vec!["03", "04", "05"]);
+        assert_eq!(f(Month::range(a..b).collect()), vec!["03", "04"]);
+        assert_eq!(
+            f(Month::range(a..=b).rev().collect()),
+            vec!["05", "04", "03"]
+        );
+        assert_eq!(Month::range(a..=b).len(), 3);
+        assert_eq!(Month::range(b..a).len(), 0);
+        assert_eq!(Month::range(a..a).len(), 0);
+        assert_eq!(Month::range(..).len(), 12);
+        assert_eq!(Month::range(..).next(), Some(Month::first()));
+        assert_eq!(Month::range(..).next_back(), Some(Month::last()));
+        Ok(())
+    }
 }
@@ -1,7 +1,10 @@
-use crate::{DayOfYear, ParseDayOfYearError, ParseYearError, Year};
+use crate::{CalendarDate, DayOfYear, ParseDayOfYearError, ParseYearError, Year};
 
+use std::ops::RangeBounds;
 use thiserror::Error;
 
+use crate::range_iter::{range_iter, RangeIter, Step};
+
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct OrdinalDate {
     year: Year,
@@ -75,6 +78,32 @@ impl OrdinalDate {
                 .and_then(|next_day_of_month| OrdinalDate::new(self.year(), next_day_of_month).ok())
         }
     }
+
+    pub fn range<R: RangeBounds<Self>>(range: R) -> RangeIter<Self> {
+        range_iter(range)
+    }
+}
+
+impl Step for OrdinalDate {
+    fn first() -> Self {
+        Self::first_date_of_year(Year::first())
+    }
+
+    fn last() -> Self {
+        Self::last_date_of_year(Year::last())
+    }
+
+    fn position(&self) -> u32 {
+        CalendarDate::from(*self).position()
+    }
+
+    fn pred(&self) -> Option<Self> {
+        Self::pred(self)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        Self::succ(self)
+    }
 }
 
 impl std::fmt::Display for OrdinalDate {
@@ -90,6 +119,9 @@ impl std::str::FromStr for OrdinalDate {
         if s.len() != 8 {
             return Err(Self::Err::InvalidLength);
         }
+        if !s.is_ascii() {
+            return Err(Self::Err::InvalidFormat);
+        }
         let year = Year::from_str(&s[0..4]).map_err(Self::Err::ParseYear)?;
         if s.as_bytes().get(4) != Some(&b'-') {
             return Err(Self::Err::InvalidFormat);
@@ -148,9 +180,10 @@ mod tests {
         type E = ParseOrdinalDateError;
         let f = |s: &str| OrdinalDate::from_str(s);
 
-        assert!(matches!(f("2021-001"), Ok(_)));
+        assert!(f("2021-001").is_ok());
         assert!(matches!(f("20021-001"), Err(E::InvalidLength)));
         assert!(matches!(f("2021+001"), Err(E::InvalidFormat)));
+        assert!(matches!(f("202é001"), Err(E::InvalidFormat)));
         assert!(matches!(f("+001-001"), Err(E::ParseYear(_))));
         assert!(matches!(f("2021-+01"), Err(E::ParseDayOfYear(_))));
         assert!(matches!(f("2021-366"), Err(E::InvalidDayOfYear)));
@@ -204,4 +237,35 @@ mod tests {
         assert_eq!(OrdinalDate::from_str("9999-365")?.succ(), None);
         Ok(())
     }
+
+    #[test]
+    fn range_test() -> anyhow::Result<()> {
+        let (a, b) = (
+            OrdinalDate::from_str("2020-365")?,
+            OrdinalDate::from_str("2021-001")?,
+        );
+        let f = |v: Vec<OrdinalDate>| v.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+        assert_eq!(
+            f(OrdinalDate::range(a..=b).collect()),
+            vec!["2020-365", "2020-366", "2021-001"]
+        );
+        assert_eq!(
+            f(OrdinalDate::range(a..b).collect()),
+            vec!["2020-365", "2020-366"]
+        );
+        assert_eq!(
+            f(OrdinalDate::range(a..=b).rev().collect()),
+            vec!["2021-001", "2020-366", "2020-365"]
+        );
+        assert_eq!(OrdinalDate::range(a..=b).len(), 3);
+        assert_eq!(OrdinalDate::range(b..a).len(), 0);
+        assert_eq!(OrdinalDate::range(a..a).len(), 0);
+        assert_eq!(OrdinalDate::range(..).len(), 2932897);
+        assert_eq!(OrdinalDate::range(..).next(), Some(OrdinalDate::first()));
+        assert_eq!(
+            OrdinalDate::range(..).next_back(),
+            Some(OrdinalDate::last())
+        );
+        Ok(())
+    }
 }
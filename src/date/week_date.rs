@@ -0,0 +1,302 @@
+use std::convert::TryFrom;
+use thiserror::Error;
+
+use crate::private::{date_from_week_date, week_date_from_date};
+
+use super::calendar_date::CalendarDate;
+use super::day_of_month::DayOfMonth;
+use super::day_of_week::{DayOfWeek, ParseDayOfWeekError};
+use super::month::Month;
+use super::week_of_year::{ParseWeekOfYearError, WeekOfYear};
+use super::year::{ParseYearError, Year};
+use super::year_week::{ParseYearWeekError, YearWeek};
+
+// ISO 8601 week date (e.g. 2021-W05-3)
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct WeekDate {
+    year: Year,
+    week_of_year: WeekOfYear,
+    day_of_week: DayOfWeek,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParseWeekDateError {
+    #[error("invalid format")]
+    InvalidFormat,
+    #[error("invalid length")]
+    InvalidLength,
+    #[error("invalid week of year")]
+    InvalidWeekOfYear,
+    #[error("out of range")]
+    OutOfRange,
+    #[error("parse day of week")]
+    ParseDayOfWeek(ParseDayOfWeekError),
+    #[error("parse week of year")]
+    ParseWeekOfYear(ParseWeekOfYearError),
+    #[error("parse year")]
+    ParseYear(ParseYearError),
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+#[error("invalid week date error")]
+pub struct InvalidWeekDateError;
+
+impl WeekDate {
+    // 1970-W01-1 ... 1970-W01-3 (1969-12-29 ... 1969-12-31) is out of range
+    pub fn from_ywd(
+        year: Year,
+        week_of_year: WeekOfYear,
+        day_of_week: DayOfWeek,
+    ) -> Result<Self, InvalidWeekDateError> {
+        YearWeek::new(year, week_of_year).map_err(|_| InvalidWeekDateError)?;
+        let (y, _, _) = date_from_week_date((
+            i64::from(year),
+            i64::from(week_of_year),
+            i64::from(day_of_week),
+        ));
+        Year::try_from(y).map_err(|_| InvalidWeekDateError)?;
+        Ok(Self {
+            year,
+            week_of_year,
+            day_of_week,
+        })
+    }
+
+    pub fn day_of_week(&self) -> DayOfWeek {
+        self.day_of_week
+    }
+
+    pub fn week_of_year(&self) -> WeekOfYear {
+        self.week_of_year
+    }
+
+    pub fn year(&self) -> Year {
+        self.year
+    }
+
+    pub fn year_week(&self) -> YearWeek {
+        YearWeek::new(self.year, self.week_of_year).expect("WeekDate is broken")
+    }
+
+    pub fn pred(&self) -> Option<Self> {
+        CalendarDate::from(*self).pred().map(Self::from)
+    }
+
+    pub fn succ(&self) -> Option<Self> {
+        CalendarDate::from(*self).succ().map(Self::from)
+    }
+}
+
+impl std::fmt::Display for WeekDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-W{}-{}",
+            self.year, self.week_of_year, self.day_of_week
+        )
+    }
+}
+
+impl std::str::FromStr for WeekDate {
+    type Err = ParseWeekDateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 10 {
+            return Err(Self::Err::InvalidLength);
+        }
+        // the fields are sliced by byte
+        if !s.is_ascii() {
+            return Err(Self::Err::InvalidFormat);
+        }
+        let year_week = match YearWeek::from_str(&s[0..8]) {
+            Ok(yw) => yw,
+            Err(e) => match e {
+                ParseYearWeekError::InvalidLength => unreachable!(),
+                ParseYearWeekError::InvalidFormat => return Err(Self::Err::InvalidFormat),
+                ParseYearWeekError::InvalidWeekOfYear => return Err(Self::Err::InvalidWeekOfYear),
+                ParseYearWeekError::ParseWeekOfYear(e) => {
+                    return Err(Self::Err::ParseWeekOfYear(e))
+                }
+                ParseYearWeekError::ParseYear(e) => return Err(Self::Err::ParseYear(e)),
+            },
+        };
+        if s.as_bytes().get(8) != Some(&b'-') {
+            return Err(Self::Err::InvalidFormat);
+        }
+        let day_of_week = DayOfWeek::from_str(&s[9..10]).map_err(Self::Err::ParseDayOfWeek)?;
+        Self::from_ywd(year_week.year(), year_week.week_of_year(), day_of_week)
+            .map_err(|_| Self::Err::OutOfRange)
+    }
+}
+
+impl From<CalendarDate> for WeekDate {
+    fn from(date: CalendarDate) -> Self {
+        let (y, w, d) = week_date_from_date((
+            i64::from(date.year()),
+            i64::from(date.month()),
+            i64::from(date.day_of_month()),
+        ));
+        // the week-based year of 1970-01-01 (Thursday) is 1970
+        // the week-based year of 9999-12-31 (Friday) is 9999
+        let year = Year::try_from(y).expect("week-based year is [1970, 9999]");
+        let week_of_year = WeekOfYear::try_from(w).expect("week of year is [1, 53]");
+        let day_of_week = DayOfWeek::try_from(d).expect("day of week is [1, 7]");
+        Self {
+            year,
+            week_of_year,
+            day_of_week,
+        }
+    }
+}
+
+impl From<WeekDate> for CalendarDate {
+    fn from(week_date: WeekDate) -> Self {
+        let (y, m, d) = date_from_week_date((
+            i64::from(week_date.year()),
+            i64::from(week_date.week_of_year()),
+            i64::from(week_date.day_of_week()),
+        ));
+        let year = Year::try_from(y).expect("WeekDate is broken");
+        let month = Month::try_from(m).expect("month is [1, 12]");
+        let day_of_month = DayOfMonth::try_from(d).expect("day of month is [1, 31]");
+        CalendarDate::from_ymd(year, month, day_of_month).expect("From<WeekDate> for CalendarDate")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn from_ywd_test() -> anyhow::Result<()> {
+        assert_eq!(
+            WeekDate::from_ywd(
+                Year::from_str("2021")?,
+                WeekOfYear::from_str("05")?,
+                DayOfWeek::from_str("3")?
+            )?,
+            WeekDate::from_str("2021-W05-3")?
+        );
+        assert!(matches!(
+            WeekDate::from_ywd(
+                Year::from_str("2021")?,
+                WeekOfYear::from_str("53")?,
+                DayOfWeek::from_str("1")?
+            ),
+            Err(InvalidWeekDateError)
+        ));
+        assert!(matches!(
+            WeekDate::from_ywd(
+                Year::from_str("1970")?,
+                WeekOfYear::from_str("01")?,
+                DayOfWeek::from_str("3")?
+            ),
+            Err(InvalidWeekDateError)
+        ));
+        assert!(WeekDate::from_ywd(
+            Year::from_str("1970")?,
+            WeekOfYear::from_str("01")?,
+            DayOfWeek::from_str("4")?
+        )
+        .is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        type E = ParseWeekDateError;
+        let f = |s: &str| WeekDate::from_str(s);
+
+        assert!(f("2021-W05-3").is_ok());
+        assert!(matches!(f("20021-W05-3"), Err(E::InvalidLength)));
+        assert!(matches!(f("2021+W05-3"), Err(E::InvalidFormat)));
+        assert!(matches!(f("2021-W05+3"), Err(E::InvalidFormat)));
+        assert!(matches!(f("2021-W0é5"), Err(E::InvalidFormat)));
+        assert!(matches!(f("2021-W05é"), Err(E::InvalidFormat)));
+        assert!(matches!(f("+001-W05-3"), Err(E::ParseYear(_))));
+        assert!(matches!(f("2021-W54-3"), Err(E::ParseWeekOfYear(_))));
+        assert!(matches!(f("2021-W53-3"), Err(E::InvalidWeekOfYear)));
+        assert!(matches!(f("2021-W05-8"), Err(E::ParseDayOfWeek(_))));
+        assert!(matches!(f("1970-W01-1"), Err(E::OutOfRange)));
+
+        assert_eq!(f("2021-W05-3")?.to_string(), "2021-W05-3");
+        Ok(())
+    }
+
+    #[test]
+    fn day_of_week_test() -> anyhow::Result<()> {
+        let d = WeekDate::from_str("2021-W05-3")?;
+        assert_eq!(d.day_of_week(), DayOfWeek::wednesday());
+        Ok(())
+    }
+
+    #[test]
+    fn week_of_year_test() -> anyhow::Result<()> {
+        let d = WeekDate::from_str("2021-W05-3")?;
+        assert_eq!(d.week_of_year(), WeekOfYear::from_str("05")?);
+        Ok(())
+    }
+
+    #[test]
+    fn year_test() -> anyhow::Result<()> {
+        let d = WeekDate::from_str("2021-W05-3")?;
+        assert_eq!(d.year(), Year::from_str("2021")?);
+        Ok(())
+    }
+
+    #[test]
+    fn year_week_test() -> anyhow::Result<()> {
+        let d = WeekDate::from_str("2021-W05-3")?;
+        assert_eq!(d.year_week(), YearWeek::from_str("2021-W05")?);
+        Ok(())
+    }
+
+    #[test]
+    fn pred_test() -> anyhow::Result<()> {
+        assert_eq!(
+            WeekDate::from_str("2021-W01-1")?.pred(),
+            Some(WeekDate::from_str("2020-W53-7")?)
+        );
+        assert_eq!(
+            WeekDate::from_str("2021-W05-3")?.pred(),
+            Some(WeekDate::from_str("2021-W05-2")?)
+        );
+        assert_eq!(WeekDate::from_str("1970-W01-4")?.pred(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn succ_test() -> anyhow::Result<()> {
+        assert_eq!(
+            WeekDate::from_str("2020-W53-7")?.succ(),
+            Some(WeekDate::from_str("2021-W01-1")?)
+        );
+        assert_eq!(
+            WeekDate::from_str("2021-W05-3")?.succ(),
+            Some(WeekDate::from_str("2021-W05-4")?)
+        );
+        assert_eq!(WeekDate::from_str("9999-W52-5")?.succ(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn date_conversion_test() -> anyhow::Result<()> {
+        let f = |s1: &str, s2: &str| -> anyhow::Result<()> {
+            let date = CalendarDate::from_str(s1)?;
+            let week_date = WeekDate::from_str(s2)?;
+            assert_eq!(WeekDate::from(date), week_date);
+            assert_eq!(CalendarDate::from(week_date), date);
+            Ok(())
+        };
+        f("1970-01-01", "1970-W01-4")?;
+        f("2008-12-29", "2009-W01-1")?;
+        f("2010-01-03", "2009-W53-7")?;
+        f("2021-01-01", "2020-W53-5")?;
+        f("2021-02-03", "2021-W05-3")?;
+        f("2024-12-30", "2025-W01-1")?;
+        f("9999-12-31", "9999-W52-5")?;
+        Ok(())
+    }
+}
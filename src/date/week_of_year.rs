@@ -0,0 +1,352 @@
+use std::convert::TryFrom;
+use thiserror::Error;
+
+use crate::Days;
+
+// [1, 53]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct WeekOfYear(u8);
+
+impl WeekOfYear {
+    pub fn max() -> WeekOfYear {
+        Self::max_in_long_year()
+    }
+
+    pub fn max_in_long_year() -> WeekOfYear {
+        WeekOfYear(53)
+    }
+
+    pub fn max_in_short_year() -> WeekOfYear {
+        WeekOfYear(52)
+    }
+
+    pub fn min() -> WeekOfYear {
+        WeekOfYear(1)
+    }
+
+    pub fn days(&self) -> Days {
+        Days::from(7_u8)
+    }
+
+    pub fn pred(&self) -> Option<Self> {
+        if self.0 > 1 {
+            Some(Self(self.0 - 1))
+        } else {
+            None
+        }
+    }
+
+    pub fn succ(&self) -> Option<Self> {
+        if self.0 < 53 {
+            Some(Self(self.0 + 1))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParseWeekOfYearError {
+    #[error("invalid digit")]
+    InvalidDigit,
+    #[error("invalid length")]
+    InvalidLength,
+    #[error("out of range")]
+    OutOfRange,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum TryFromWeekOfYearError {
+    #[error("out of range")]
+    OutOfRange,
+}
+
+impl std::fmt::Display for WeekOfYear {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}", self.0)
+    }
+}
+
+impl std::str::FromStr for WeekOfYear {
+    type Err = ParseWeekOfYearError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 2 {
+            return Err(Self::Err::InvalidLength);
+        }
+        let mut woy = 0_u8;
+        for c in s.chars() {
+            let d = match c {
+                '0'..='9' => c as u8 - b'0',
+                _ => return Err(Self::Err::InvalidDigit),
+            };
+            woy = woy * 10 + d;
+        }
+        Self::try_from(woy).map_err(|_| Self::Err::OutOfRange)
+    }
+}
+
+impl From<WeekOfYear> for i8 {
+    fn from(week_of_year: WeekOfYear) -> Self {
+        i8::try_from(week_of_year.0).expect("week_of_year is [1,53]")
+    }
+}
+
+impl From<WeekOfYear> for i16 {
+    fn from(week_of_year: WeekOfYear) -> Self {
+        i16::from(week_of_year.0)
+    }
+}
+
+impl From<WeekOfYear> for i32 {
+    fn from(week_of_year: WeekOfYear) -> Self {
+        i32::from(week_of_year.0)
+    }
+}
+
+impl From<WeekOfYear> for i64 {
+    fn from(week_of_year: WeekOfYear) -> Self {
+        i64::from(week_of_year.0)
+    }
+}
+
+impl From<WeekOfYear> for u8 {
+    fn from(week_of_year: WeekOfYear) -> Self {
+        week_of_year.0
+    }
+}
+
+impl From<WeekOfYear> for u16 {
+    fn from(week_of_year: WeekOfYear) -> Self {
+        u16::from(week_of_year.0)
+    }
+}
+
+impl From<WeekOfYear> for u32 {
+    fn from(week_of_year: WeekOfYear) -> Self {
+        u32::from(week_of_year.0)
+    }
+}
+
+impl From<WeekOfYear> for u64 {
+    fn from(week_of_year: WeekOfYear) -> Self {
+        u64::from(week_of_year.0)
+    }
+}
+
+impl std::convert::TryFrom<i8> for WeekOfYear {
+    type Error = TryFromWeekOfYearError;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        let value_as_u8 = u8::try_from(value).map_err(|_| Self::Error::OutOfRange)?;
+        Self::try_from(value_as_u8)
+    }
+}
+
+impl std::convert::TryFrom<i16> for WeekOfYear {
+    type Error = TryFromWeekOfYearError;
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        let value_as_u8 = u8::try_from(value).map_err(|_| Self::Error::OutOfRange)?;
+        Self::try_from(value_as_u8)
+    }
+}
+
+impl std::convert::TryFrom<i32> for WeekOfYear {
+    type Error = TryFromWeekOfYearError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        let value_as_u8 = u8::try_from(value).map_err(|_| Self::Error::OutOfRange)?;
+        Self::try_from(value_as_u8)
+    }
+}
+
+impl std::convert::TryFrom<i64> for WeekOfYear {
+    type Error = TryFromWeekOfYearError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        let value_as_u8 = u8::try_from(value).map_err(|_| Self::Error::OutOfRange)?;
+        Self::try_from(value_as_u8)
+    }
+}
+
+impl std::convert::TryFrom<u8> for WeekOfYear {
+    type Error = TryFromWeekOfYearError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if !(1..=53).contains(&value) {
+            return Err(Self::Error::OutOfRange);
+        }
+        Ok(Self(value))
+    }
+}
+
+impl std::convert::TryFrom<u16> for WeekOfYear {
+    type Error = TryFromWeekOfYearError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        let value_as_u8 = u8::try_from(value).map_err(|_| Self::Error::OutOfRange)?;
+        Self::try_from(value_as_u8)
+    }
+}
+
+impl std::convert::TryFrom<u32> for WeekOfYear {
+    type Error = TryFromWeekOfYearError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        let value_as_u8 = u8::try_from(value).map_err(|_| Self::Error::OutOfRange)?;
+        Self::try_from(value_as_u8)
+    }
+}
+
+impl std::convert::TryFrom<u64> for WeekOfYear {
+    type Error = TryFromWeekOfYearError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        let value_as_u8 = u8::try_from(value).map_err(|_| Self::Error::OutOfRange)?;
+        Self::try_from(value_as_u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn str_conversion_test() {
+        type E = ParseWeekOfYearError;
+        let f = |s: &str| s.parse::<WeekOfYear>();
+        assert_eq!(f("01").map(|d| d.to_string()), Ok("01".to_string()));
+        assert_eq!(f("53").map(|d| d.to_string()), Ok("53".to_string()));
+        assert_eq!(f(""), Err(E::InvalidLength));
+        assert_eq!(f("1"), Err(E::InvalidLength));
+        assert_eq!(f("100"), Err(E::InvalidLength));
+        assert_eq!(f("0a"), Err(E::InvalidDigit));
+        assert_eq!(f("+1"), Err(E::InvalidDigit));
+        assert_eq!(f("00"), Err(E::OutOfRange));
+        assert_eq!(f("54"), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn i8_conversion_test() {
+        type E = TryFromWeekOfYearError;
+        let f = |d: i8| WeekOfYear::try_from(d);
+        assert_eq!(f(0_i8), Err(E::OutOfRange));
+        assert_eq!(f(1_i8).map(i8::from), Ok(1_i8));
+        assert_eq!(f(53_i8).map(i8::from), Ok(53_i8));
+        assert_eq!(f(54_i8), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn i16_conversion_test() {
+        type E = TryFromWeekOfYearError;
+        let f = |d: i16| WeekOfYear::try_from(d);
+        assert_eq!(f(0_i16), Err(E::OutOfRange));
+        assert_eq!(f(1_i16).map(i16::from), Ok(1_i16));
+        assert_eq!(f(53_i16).map(i16::from), Ok(53_i16));
+        assert_eq!(f(54_i16), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn i32_conversion_test() {
+        type E = TryFromWeekOfYearError;
+        let f = |d: i32| WeekOfYear::try_from(d);
+        assert_eq!(f(0_i32), Err(E::OutOfRange));
+        assert_eq!(f(1_i32).map(i32::from), Ok(1_i32));
+        assert_eq!(f(53_i32).map(i32::from), Ok(53_i32));
+        assert_eq!(f(54_i32), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn i64_conversion_test() {
+        type E = TryFromWeekOfYearError;
+        let f = |d: i64| WeekOfYear::try_from(d);
+        assert_eq!(f(0_i64), Err(E::OutOfRange));
+        assert_eq!(f(1_i64).map(i64::from), Ok(1_i64));
+        assert_eq!(f(53_i64).map(i64::from), Ok(53_i64));
+        assert_eq!(f(54_i64), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn u8_conversion_test() {
+        type E = TryFromWeekOfYearError;
+        let f = |d: u8| WeekOfYear::try_from(d);
+        assert_eq!(f(0_u8), Err(E::OutOfRange));
+        assert_eq!(f(1_u8).map(u8::from), Ok(1_u8));
+        assert_eq!(f(53_u8).map(u8::from), Ok(53_u8));
+        assert_eq!(f(54_u8), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn u16_conversion_test() {
+        type E = TryFromWeekOfYearError;
+        let f = |d: u16| WeekOfYear::try_from(d);
+        assert_eq!(f(0_u16), Err(E::OutOfRange));
+        assert_eq!(f(1_u16).map(u16::from), Ok(1_u16));
+        assert_eq!(f(53_u16).map(u16::from), Ok(53_u16));
+        assert_eq!(f(54_u16), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn u32_conversion_test() {
+        type E = TryFromWeekOfYearError;
+        let f = |d: u32| WeekOfYear::try_from(d);
+        assert_eq!(f(0_u32), Err(E::OutOfRange));
+        assert_eq!(f(1_u32).map(u32::from), Ok(1_u32));
+        assert_eq!(f(53_u32).map(u32::from), Ok(53_u32));
+        assert_eq!(f(54_u32), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn u64_conversion_test() {
+        type E = TryFromWeekOfYearError;
+        let f = |d: u64| WeekOfYear::try_from(d);
+        assert_eq!(f(0_u64), Err(E::OutOfRange));
+        assert_eq!(f(1_u64).map(u64::from), Ok(1_u64));
+        assert_eq!(f(53_u64).map(u64::from), Ok(53_u64));
+        assert_eq!(f(54_u64), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn max_min_test() -> anyhow::Result<()> {
+        assert_eq!(WeekOfYear::max(), WeekOfYear::try_from(53)?);
+        assert_eq!(WeekOfYear::max_in_long_year(), WeekOfYear::try_from(53)?);
+        assert_eq!(WeekOfYear::max_in_short_year(), WeekOfYear::try_from(52)?);
+        assert_eq!(WeekOfYear::min(), WeekOfYear::try_from(1)?);
+        Ok(())
+    }
+
+    #[test]
+    fn pred_test() -> anyhow::Result<()> {
+        assert_eq!(
+            WeekOfYear::try_from(53)?.pred(),
+            Some(WeekOfYear::try_from(52)?)
+        );
+        assert_eq!(
+            WeekOfYear::try_from(2)?.pred(),
+            Some(WeekOfYear::try_from(1)?)
+        );
+        assert_eq!(WeekOfYear::try_from(1)?.pred(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn succ_test() -> anyhow::Result<()> {
+        assert_eq!(
+            WeekOfYear::try_from(1)?.succ(),
+            Some(WeekOfYear::try_from(2)?)
+        );
+        assert_eq!(
+            WeekOfYear::try_from(52)?.succ(),
+            Some(WeekOfYear::try_from(53)?)
+        );
+        assert_eq!(WeekOfYear::try_from(53)?.succ(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn days_test() -> anyhow::Result<()> {
+        assert_eq!(WeekOfYear::try_from(1)?.days(), Days::from(7_u16));
+        Ok(())
+    }
+}
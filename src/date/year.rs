@@ -1,6 +1,9 @@
 use std::convert::TryFrom;
+use std::ops::RangeBounds;
 use thiserror::Error;
 
+use crate::range_iter::{range_iter, RangeIter, Step};
+
 use crate::{DayOfYear, Days};
 
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -42,6 +45,32 @@ impl Year {
             None
         }
     }
+
+    pub fn range<R: RangeBounds<Self>>(range: R) -> RangeIter<Self> {
+        range_iter(range)
+    }
+}
+
+impl Step for Year {
+    fn first() -> Self {
+        Self(1970)
+    }
+
+    fn last() -> Self {
+        Self(9999)
+    }
+
+    fn position(&self) -> u32 {
+        u32::from(self.0)
+    }
+
+    fn pred(&self) -> Option<Self> {
+        Self::pred(self)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        Self::succ(self)
+    }
 }
 
 #[derive(Debug, Eq, Error, PartialEq)]
@@ -61,7 +90,16 @@ pub enum TryFromYearError {
 }
 
 impl Year {
-    pub fn is_leap_year(&self) -> bool {
+    pub(crate) const fn from_u16(value: u16) -> Option<Self> {
+        if value < 1970 || value > 9999 {
+            return None;
+        }
+        Some(Self(value))
+    }
+
+    // `is_multiple_of` is not const before Rust 1.87
+    #[allow(clippy::manual_is_multiple_of)]
+    pub const fn is_leap_year(&self) -> bool {
         (self.0 % 400 == 0) || ((self.0 % 100 != 0) && (self.0 % 4 == 0))
     }
 }
@@ -158,10 +196,7 @@ impl std::convert::TryFrom<u16> for Year {
     type Error = TryFromYearError;
 
     fn try_from(value: u16) -> Result<Self, Self::Error> {
-        if !(1970..=9999).contains(&value) {
-            return Err(Self::Error::OutOfRange);
-        }
-        Ok(Self(value))
+        Self::from_u16(value).ok_or(Self::Error::OutOfRange)
     }
 }
 
@@ -326,4 +361,26 @@ mod tests {
         assert_eq!(Year::from_str("2001")?.days(), Days::from(365_u16));
         Ok(())
     }
+
+    #[test]
+    fn range_test() -> anyhow::Result<()> {
+        let (a, b) = (Year::from_str("2020")?, Year::from_str("2022")?);
+        let f = |v: Vec<Year>| v.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+        assert_eq!(
+            f(Year::range(a..=b).collect()),
+            vec!["2020", "2021", "2022"]
+        );
+        assert_eq!(f(Year::range(a..b).collect()), vec!["2020", "2021"]);
+        assert_eq!(
+            f(Year::range(a..=b).rev().collect()),
+            vec!["2022", "2021", "2020"]
+        );
+        assert_eq!(Year::range(a..=b).len(), 3);
+        assert_eq!(Year::range(b..a).len(), 0);
+        assert_eq!(Year::range(a..a).len(), 0);
+        assert_eq!(Year::range(..).len(), 8030);
+        assert_eq!(Year::range(..).next(), Some(Year::first()));
+        assert_eq!(Year::range(..).next_back(), Some(Year::last()));
+        Ok(())
+    }
 }
@@ -1,7 +1,10 @@
 use std::convert::TryFrom;
+use std::ops::RangeBounds;
 use thiserror::Error;
 
-use crate::{Days, Months};
+use crate::range_iter::{range_iter, RangeIter, Step};
+
+use crate::{Days, Months, SignedMonths};
 
 use super::{DayOfMonth, Month, ParseMonthError, ParseYearError, Year};
 
@@ -24,18 +27,18 @@ pub enum ParseYearMonthError {
 }
 
 impl YearMonth {
-    pub fn new(year: Year, month: Month) -> Self {
+    pub const fn new(year: Year, month: Month) -> Self {
         Self { year, month }
     }
 
-    pub fn first_year_month_of_year(year: Year) -> Self {
+    pub const fn first_year_month_of_year(year: Year) -> Self {
         Self {
             year,
             month: Month::january(),
         }
     }
 
-    pub fn last_year_month_of_year(year: Year) -> Self {
+    pub const fn last_year_month_of_year(year: Year) -> Self {
         Self {
             year,
             month: Month::december(),
@@ -50,9 +53,8 @@ impl YearMonth {
         DayOfMonth::try_from(1).expect("invalid day of month")
     }
 
-    pub fn last_day_of_month(&self) -> DayOfMonth {
-        let m: u8 = self.month.into();
-        let d: u8 = match m {
+    pub const fn last_day_of_month(&self) -> DayOfMonth {
+        let d: u8 = match self.month.to_u8() {
             1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
             4 | 6 | 9 | 11 => 30,
             2 => {
@@ -64,14 +66,17 @@ impl YearMonth {
             }
             _ => unreachable!(),
         };
-        DayOfMonth::try_from(d).expect("invalid day of month")
+        match DayOfMonth::from_u8(d) {
+            Some(day_of_month) => day_of_month,
+            None => unreachable!(),
+        }
     }
 
-    pub fn month(&self) -> Month {
+    pub const fn month(&self) -> Month {
         self.month
     }
 
-    pub fn year(&self) -> Year {
+    pub const fn year(&self) -> Year {
         self.year
     }
 
@@ -94,6 +99,44 @@ impl YearMonth {
                 .map(|next_year| Self::new(next_year, Month::january())),
         }
     }
+
+    // 0001-01 ... 0
+    pub(crate) fn from_months_from_ce(months_from_ce: i64) -> Option<Self> {
+        let year = Year::try_from(months_from_ce.div_euclid(12) + 1).ok()?;
+        let month = Month::try_from(months_from_ce.rem_euclid(12) + 1).ok()?;
+        Some(Self::new(year, month))
+    }
+
+    // 0001-01 ... 0
+    pub(crate) fn months_from_ce(&self) -> i64 {
+        (i64::from(self.year()) - 1) * 12 + (i64::from(self.month()) - 1)
+    }
+
+    pub fn range<R: RangeBounds<Self>>(range: R) -> RangeIter<Self> {
+        range_iter(range)
+    }
+}
+
+impl Step for YearMonth {
+    fn first() -> Self {
+        Self::first_year_month_of_year(Year::first())
+    }
+
+    fn last() -> Self {
+        Self::last_year_month_of_year(Year::last())
+    }
+
+    fn position(&self) -> u32 {
+        u32::try_from(self.months_from_ce()).expect("months_from_ce is [23_628, 119_987]")
+    }
+
+    fn pred(&self) -> Option<Self> {
+        Self::pred(self)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        Self::succ(self)
+    }
 }
 
 impl std::fmt::Display for YearMonth {
@@ -106,18 +149,43 @@ impl std::ops::Add<Months> for YearMonth {
     type Output = YearMonth;
 
     fn add(self, rhs: Months) -> Self::Output {
-        let y = u32::from(u16::from(self.year()));
-        let m = u32::from(u8::from(self.month()));
-        let ry = u32::from(rhs) / 12;
-        let rm = u32::from(rhs) % 12;
-        let ny = y + ry + (m + rm) / 12;
-        let nm = (m + rm) % 12;
-        // TODO: unwrap
-        let y = u16::try_from(ny).unwrap();
-        let m = u8::try_from(nm).unwrap();
-        // TODO: TryFrom<u32> for Year
-        // TODO: TryFrom<u32> for Month
-        Self::new(Year::try_from(y).unwrap(), Month::try_from(m).unwrap())
+        self + SignedMonths::try_from(rhs).unwrap_or_else(|_| panic!("overflow"))
+    }
+}
+
+impl std::ops::Add<SignedMonths> for YearMonth {
+    type Output = YearMonth;
+
+    fn add(self, rhs: SignedMonths) -> Self::Output {
+        self.months_from_ce()
+            .checked_add(i64::from(i32::from(rhs)))
+            .and_then(Self::from_months_from_ce)
+            .unwrap_or_else(|| panic!("overflow"))
+    }
+}
+
+impl std::ops::Sub<Months> for YearMonth {
+    type Output = YearMonth;
+
+    fn sub(self, rhs: Months) -> Self::Output {
+        self + -SignedMonths::try_from(rhs).unwrap_or_else(|_| panic!("overflow"))
+    }
+}
+
+impl std::ops::Sub<SignedMonths> for YearMonth {
+    type Output = YearMonth;
+
+    fn sub(self, rhs: SignedMonths) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl std::ops::Sub<YearMonth> for YearMonth {
+    type Output = SignedMonths;
+
+    fn sub(self, rhs: YearMonth) -> Self::Output {
+        let months = self.months_from_ce() - rhs.months_from_ce();
+        SignedMonths::from(i32::try_from(months).expect("months is [-119_999, 119_999]"))
     }
 }
 
@@ -284,11 +352,60 @@ mod tests {
             YearMonth::from_str("2000-01")? + Months::from(13),
             YearMonth::from_str("2001-02")?
         );
+        assert_eq!(
+            YearMonth::from_str("2000-12")? + Months::from(0),
+            YearMonth::from_str("2000-12")?
+        );
+        assert_eq!(
+            YearMonth::from_str("2000-12")? + Months::from(1),
+            YearMonth::from_str("2001-01")?
+        );
         // should panic
         // YearMonth::from_str("9999-12")? + Months::from(1),
         Ok(())
     }
 
+    #[test]
+    fn add_signed_months_test() -> anyhow::Result<()> {
+        let ym = |s: &str| YearMonth::from_str(s);
+        assert_eq!(ym("2000-01")? + SignedMonths::from(1), ym("2000-02")?);
+        assert_eq!(ym("2000-01")? + SignedMonths::from(-1), ym("1999-12")?);
+        assert_eq!(ym("2000-01")? + SignedMonths::from(-12), ym("1999-01")?);
+        assert_eq!(ym("2000-01")? + SignedMonths::from(-13), ym("1998-12")?);
+        // should panic
+        // ym("1970-01")? + SignedMonths::from(-1)
+        Ok(())
+    }
+
+    #[test]
+    fn sub_months_test() -> anyhow::Result<()> {
+        let ym = |s: &str| YearMonth::from_str(s);
+        assert_eq!(ym("2000-03")? - Months::from(2), ym("2000-01")?);
+        assert_eq!(ym("2000-01")? - Months::from(1), ym("1999-12")?);
+        assert_eq!(ym("9999-12")? - Months::from(96_359), ym("1970-01")?);
+        // should panic
+        // ym("1970-01")? - Months::from(1)
+        Ok(())
+    }
+
+    #[test]
+    fn sub_signed_months_test() -> anyhow::Result<()> {
+        let ym = |s: &str| YearMonth::from_str(s);
+        assert_eq!(ym("2000-01")? - SignedMonths::from(1), ym("1999-12")?);
+        assert_eq!(ym("1999-12")? - SignedMonths::from(-1), ym("2000-01")?);
+        Ok(())
+    }
+
+    #[test]
+    fn sub_year_month_test() -> anyhow::Result<()> {
+        let ym = |s: &str| YearMonth::from_str(s);
+        assert_eq!(ym("2000-01")? - ym("2000-01")?, SignedMonths::from(0));
+        assert_eq!(ym("2001-02")? - ym("2000-01")?, SignedMonths::from(13));
+        assert_eq!(ym("2000-01")? - ym("2001-02")?, SignedMonths::from(-13));
+        assert_eq!(ym("9999-12")? - ym("1970-01")?, SignedMonths::from(96_359));
+        Ok(())
+    }
+
     #[test]
     fn first_year_month_of_year_test() -> anyhow::Result<()> {
         assert_eq!(
@@ -306,4 +423,32 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn range_test() -> anyhow::Result<()> {
+        let (a, b) = (
+            YearMonth::from_str("2020-12")?,
+            YearMonth::from_str("2021-02")?,
+        );
+        let f = |v: Vec<YearMonth>| v.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+        assert_eq!(
+            f(YearMonth::range(a..=b).collect()),
+            vec!["2020-12", "2021-01", "2021-02"]
+        );
+        assert_eq!(
+            f(YearMonth::range(a..b).collect()),
+            vec!["2020-12", "2021-01"]
+        );
+        assert_eq!(
+            f(YearMonth::range(a..=b).rev().collect()),
+            vec!["2021-02", "2021-01", "2020-12"]
+        );
+        assert_eq!(YearMonth::range(a..=b).len(), 3);
+        assert_eq!(YearMonth::range(b..a).len(), 0);
+        assert_eq!(YearMonth::range(a..a).len(), 0);
+        assert_eq!(YearMonth::range(..).len(), 96360);
+        assert_eq!(YearMonth::range(..).next(), Some(YearMonth::first()));
+        assert_eq!(YearMonth::range(..).next_back(), Some(YearMonth::last()));
+        Ok(())
+    }
 }
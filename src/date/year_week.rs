@@ -0,0 +1,235 @@
+use std::convert::TryFrom;
+use thiserror::Error;
+
+use crate::private::weeks_in_year;
+use crate::Days;
+
+use super::{ParseWeekOfYearError, ParseYearError, WeekOfYear, Year};
+
+// ISO 8601 week-based year and week
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct YearWeek {
+    year: Year,
+    week_of_year: WeekOfYear,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParseYearWeekError {
+    #[error("invalid format")]
+    InvalidFormat,
+    #[error("invalid length")]
+    InvalidLength,
+    #[error("invalid week of year")]
+    InvalidWeekOfYear,
+    #[error("parse week of year")]
+    ParseWeekOfYear(ParseWeekOfYearError),
+    #[error("parse year")]
+    ParseYear(ParseYearError),
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+#[error("invalid year week error")]
+pub struct InvalidYearWeekError;
+
+impl YearWeek {
+    pub fn new(year: Year, week_of_year: WeekOfYear) -> Result<Self, InvalidYearWeekError> {
+        if week_of_year > Self::last_year_week_of_year(year).week_of_year() {
+            return Err(InvalidYearWeekError);
+        }
+        Ok(Self { year, week_of_year })
+    }
+
+    pub fn first_year_week_of_year(year: Year) -> Self {
+        Self {
+            year,
+            week_of_year: WeekOfYear::min(),
+        }
+    }
+
+    pub fn last_year_week_of_year(year: Year) -> Self {
+        let weeks = weeks_in_year(i64::from(year));
+        Self {
+            year,
+            week_of_year: WeekOfYear::try_from(weeks).expect("weeks in year is 52 or 53"),
+        }
+    }
+
+    pub fn days(&self) -> Days {
+        Days::from(7_u8)
+    }
+
+    pub fn week_of_year(&self) -> WeekOfYear {
+        self.week_of_year
+    }
+
+    pub fn year(&self) -> Year {
+        self.year
+    }
+
+    pub fn pred(&self) -> Option<Self> {
+        if self.week_of_year() == WeekOfYear::min() {
+            self.year().pred().map(Self::last_year_week_of_year)
+        } else {
+            self.week_of_year()
+                .pred()
+                .and_then(|last_week| Self::new(self.year(), last_week).ok())
+        }
+    }
+
+    pub fn succ(&self) -> Option<Self> {
+        if self.week_of_year() == Self::last_year_week_of_year(self.year()).week_of_year() {
+            self.year().succ().map(Self::first_year_week_of_year)
+        } else {
+            self.week_of_year()
+                .succ()
+                .and_then(|next_week| Self::new(self.year(), next_week).ok())
+        }
+    }
+}
+
+impl std::fmt::Display for YearWeek {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-W{}", self.year, self.week_of_year)
+    }
+}
+
+impl std::str::FromStr for YearWeek {
+    type Err = ParseYearWeekError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 8 {
+            return Err(Self::Err::InvalidLength);
+        }
+        // the fields are sliced by byte
+        if !s.is_ascii() {
+            return Err(Self::Err::InvalidFormat);
+        }
+        if s.as_bytes().get(4) != Some(&b'-') || s.as_bytes().get(5) != Some(&b'W') {
+            return Err(Self::Err::InvalidFormat);
+        }
+        let year = Year::from_str(&s[0..4]).map_err(Self::Err::ParseYear)?;
+        let week_of_year = WeekOfYear::from_str(&s[6..8]).map_err(Self::Err::ParseWeekOfYear)?;
+        Self::new(year, week_of_year).map_err(|_| Self::Err::InvalidWeekOfYear)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn new_test() -> anyhow::Result<()> {
+        assert_eq!(
+            YearWeek::new(Year::from_str("2021")?, WeekOfYear::from_str("05")?)?,
+            YearWeek::from_str("2021-W05")?
+        );
+        assert_eq!(
+            YearWeek::new(Year::from_str("2020")?, WeekOfYear::from_str("53")?)?,
+            YearWeek::from_str("2020-W53")?
+        );
+        assert!(matches!(
+            YearWeek::new(Year::from_str("2021")?, WeekOfYear::from_str("53")?),
+            Err(InvalidYearWeekError)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn first_year_week_of_year_test() -> anyhow::Result<()> {
+        assert_eq!(
+            YearWeek::first_year_week_of_year(Year::from_str("2021")?),
+            YearWeek::from_str("2021-W01")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn last_year_week_of_year_test() -> anyhow::Result<()> {
+        let f = |s: &str| -> anyhow::Result<YearWeek> {
+            Ok(YearWeek::last_year_week_of_year(Year::from_str(s)?))
+        };
+        assert_eq!(f("1970")?, YearWeek::from_str("1970-W53")?);
+        assert_eq!(f("2015")?, YearWeek::from_str("2015-W53")?);
+        assert_eq!(f("2020")?, YearWeek::from_str("2020-W53")?);
+        assert_eq!(f("2021")?, YearWeek::from_str("2021-W52")?);
+        assert_eq!(f("9999")?, YearWeek::from_str("9999-W52")?);
+        Ok(())
+    }
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        type E = ParseYearWeekError;
+        let f = |s: &str| YearWeek::from_str(s);
+
+        assert!(f("2021-W05").is_ok());
+        assert!(matches!(f("20021-W05"), Err(E::InvalidLength)));
+        assert!(matches!(f("2021+W05"), Err(E::InvalidFormat)));
+        assert!(matches!(f("2021-w05"), Err(E::InvalidFormat)));
+        assert!(matches!(f("2021-Wé"), Err(E::InvalidFormat)));
+        assert!(matches!(f("20é-W05"), Err(E::InvalidFormat)));
+        assert!(matches!(f("+001-W05"), Err(E::ParseYear(_))));
+        assert!(matches!(f("2021-W54"), Err(E::ParseWeekOfYear(_))));
+        assert!(matches!(f("2021-W53"), Err(E::InvalidWeekOfYear)));
+
+        assert_eq!(f("2021-W05")?.to_string(), "2021-W05");
+        Ok(())
+    }
+
+    #[test]
+    fn days_test() -> anyhow::Result<()> {
+        assert_eq!(YearWeek::from_str("2021-W05")?.days(), Days::from(7_u8));
+        Ok(())
+    }
+
+    #[test]
+    fn week_of_year_test() -> anyhow::Result<()> {
+        let year_week = YearWeek::from_str("2021-W05")?;
+        assert_eq!(year_week.week_of_year(), WeekOfYear::from_str("05")?);
+        Ok(())
+    }
+
+    #[test]
+    fn year_test() -> anyhow::Result<()> {
+        let year_week = YearWeek::from_str("2021-W05")?;
+        assert_eq!(year_week.year(), Year::from_str("2021")?);
+        Ok(())
+    }
+
+    #[test]
+    fn pred_test() -> anyhow::Result<()> {
+        assert_eq!(
+            YearWeek::from_str("9999-W52")?.pred(),
+            Some(YearWeek::from_str("9999-W51")?)
+        );
+        assert_eq!(
+            YearWeek::from_str("2021-W01")?.pred(),
+            Some(YearWeek::from_str("2020-W53")?)
+        );
+        assert_eq!(
+            YearWeek::from_str("2022-W01")?.pred(),
+            Some(YearWeek::from_str("2021-W52")?)
+        );
+        assert_eq!(YearWeek::from_str("1970-W01")?.pred(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn succ_test() -> anyhow::Result<()> {
+        assert_eq!(
+            YearWeek::from_str("1970-W01")?.succ(),
+            Some(YearWeek::from_str("1970-W02")?)
+        );
+        assert_eq!(
+            YearWeek::from_str("2020-W53")?.succ(),
+            Some(YearWeek::from_str("2021-W01")?)
+        );
+        assert_eq!(
+            YearWeek::from_str("2021-W52")?.succ(),
+            Some(YearWeek::from_str("2022-W01")?)
+        );
+        assert_eq!(YearWeek::from_str("9999-W52")?.succ(), None);
+        Ok(())
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{CalendarDate, ParseDateError, ParseTimeError, Time};
+use std::convert::TryFrom;
+
+use crate::{
+    AddMonthsError, CalendarDate, Days, EndOfMonthPolicy, Months, Nanosecond, ParseDateError,
+    ParseTimeError, Period, Seconds, Time,
+};
 
 use thiserror::Error;
 
@@ -20,12 +25,18 @@ pub enum ParseDateTimeError {
     ParseTime(ParseTimeError),
 }
 
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum TryFromDateTimeError {
+    #[error("out of range")]
+    OutOfRange,
+}
+
 impl DateTime {
-    pub fn from_date_time(date: CalendarDate, time: Time) -> Self {
+    pub const fn from_date_time(date: CalendarDate, time: Time) -> Self {
         Self { date, time }
     }
 
-    pub fn new(date: CalendarDate, time: Time) -> Self {
+    pub const fn new(date: CalendarDate, time: Time) -> Self {
         Self { date, time }
     }
 
@@ -36,6 +47,83 @@ impl DateTime {
     pub fn time(&self) -> Time {
         self.time
     }
+
+    pub fn add_months(
+        &self,
+        months: Months,
+        policy: EndOfMonthPolicy,
+    ) -> Result<Self, AddMonthsError> {
+        let date = self.date.add_months(months, policy)?;
+        Ok(Self::new(date, self.time))
+    }
+
+    pub fn sub_months(
+        &self,
+        months: Months,
+        policy: EndOfMonthPolicy,
+    ) -> Result<Self, AddMonthsError> {
+        let date = self.date.sub_months(months, policy)?;
+        Ok(Self::new(date, self.time))
+    }
+
+    pub fn add_years(&self, years: u32, policy: EndOfMonthPolicy) -> Result<Self, AddMonthsError> {
+        let date = self.date.add_years(years, policy)?;
+        Ok(Self::new(date, self.time))
+    }
+
+    pub fn sub_years(&self, years: u32, policy: EndOfMonthPolicy) -> Result<Self, AddMonthsError> {
+        let date = self.date.sub_years(years, policy)?;
+        Ok(Self::new(date, self.time))
+    }
+
+    // calendar part: years and months (clamped to the last day of month), then days
+    // exact part: seconds
+    pub fn checked_add_period(&self, period: Period) -> Option<Self> {
+        self.checked_add_signed_period(period, 1)
+    }
+
+    pub fn checked_sub_period(&self, period: Period) -> Option<Self> {
+        self.checked_add_signed_period(period, -1)
+    }
+
+    fn checked_add_signed_period(&self, period: Period, sign: i64) -> Option<Self> {
+        let months = i64::from(period.total_months()?);
+        let days = i64::from(period.days());
+        let seconds = i64::try_from(u64::from(period.seconds())).ok()?;
+        let date = self
+            .date
+            .checked_add_months_and_days(sign * months, sign * days)?;
+        Self::new(date, self.time).checked_add_seconds(sign * seconds)
+    }
+
+    // the fraction of a second is kept
+    pub(crate) fn checked_add_seconds(&self, seconds: i64) -> Option<Self> {
+        self.seconds_from_unix_epoch()
+            .checked_add(seconds)
+            .and_then(Self::from_seconds_from_unix_epoch)
+            .map(|date_time| date_time.with_nanosecond(self.time.nanosecond()))
+    }
+
+    // UTC における DateTime と見なして 1970-01-01T00:00:00 からの経過秒数から DateTime を返す
+    pub(crate) fn from_seconds_from_unix_epoch(seconds_from_unix_epoch: i64) -> Option<Self> {
+        let days = Days::try_from(seconds_from_unix_epoch.div_euclid(86_400)).ok()?;
+        let seconds = u64::try_from(seconds_from_unix_epoch.rem_euclid(86_400)).ok()?;
+        Some(Self::new(
+            CalendarDate::from_days_from_unix_epoch(days),
+            Time::from_seconds_from_midnight(Seconds::from(seconds)),
+        ))
+    }
+
+    pub(crate) fn with_nanosecond(&self, nanosecond: Nanosecond) -> Self {
+        Self::new(self.date, self.time.with_nanosecond(nanosecond))
+    }
+
+    // UTC における DateTime と見なして 1970-01-01T00:00:00 からの経過秒数を返す (秒未満は切り捨て)
+    pub(crate) fn seconds_from_unix_epoch(&self) -> i64 {
+        i64::from(self.date.days_from_unix_epoch()) * 86_400
+            + i64::try_from(u64::from(self.time.seconds_from_midnight()))
+                .expect("seconds_from_midnight is [0, 86400)")
+    }
 }
 
 impl std::fmt::Display for DateTime {
@@ -48,14 +136,18 @@ impl std::str::FromStr for DateTime {
     type Err = ParseDateTimeError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() != 19 {
+        // time-secfrac: 1-9 digits
+        if s.len() != 19 && !(s.len() >= 21 && s.len() <= 29 && s.as_bytes()[19] == b'.') {
             return Err(Self::Err::InvalidLength);
         }
+        if !s.is_ascii() {
+            return Err(Self::Err::InvalidFormat);
+        }
         let date = CalendarDate::from_str(&s[0..10]).map_err(ParseDateTimeError::ParseDate)?;
         if s.as_bytes().get(10) != Some(&b'T') {
             return Err(Self::Err::InvalidFormat);
         }
-        let time = Time::from_str(&s[11..19]).map_err(ParseDateTimeError::ParseTime)?;
+        let time = Time::from_str(&s[11..]).map_err(ParseDateTimeError::ParseTime)?;
         Ok(DateTime { date, time })
     }
 }
@@ -93,15 +185,148 @@ mod tests {
         type E = ParseDateTimeError;
         let f = |s: &str| DateTime::from_str(s);
 
-        assert!(matches!(f("2021-02-03T04:05:06"), Ok(_)));
+        assert!(f("2021-02-03T04:05:06").is_ok());
         assert!(matches!(f("20021-02-03T04:05:06"), Err(E::InvalidLength)));
         assert!(matches!(f("2021+02-03T04:05:06"), Err(E::ParseDate(_))));
         assert!(matches!(f("2021-02-03T04-05:06"), Err(E::ParseTime(_))));
+        assert!(matches!(f("04:05:06.é23456789"), Err(E::InvalidFormat)));
+        assert!(matches!(f("2021-02-03T04:0é06"), Err(E::InvalidFormat)));
 
         assert_eq!(
             f("2021-02-03T04:05:06").map(|d| d.to_string()),
             Ok("2021-02-03T04:05:06".to_string())
         );
+
+        // time-secfrac
+        assert!(matches!(f("2021-02-03T04:05:06."), Err(E::InvalidLength)));
+        assert!(matches!(
+            f("2021-02-03T04:05:06.1234567890"),
+            Err(E::InvalidLength)
+        ));
+        assert!(matches!(f("2021-02-03T04:05:06.1a"), Err(E::ParseTime(_))));
+        assert_eq!(
+            f("2021-02-03T04:05:06.5").map(|d| d.to_string()),
+            Ok("2021-02-03T04:05:06.500".to_string())
+        );
+        assert_eq!(
+            f("2021-02-03T04:05:06.123456789").map(|d| d.to_string()),
+            Ok("2021-02-03T04:05:06.123456789".to_string())
+        );
+    }
+
+    #[test]
+    fn add_months_test() -> anyhow::Result<()> {
+        type E = AddMonthsError;
+        type P = EndOfMonthPolicy;
+        let dt = |s: &str| DateTime::from_str(s);
+        let m = |m: u32| Months::from(m);
+        assert_eq!(
+            dt("2021-01-31T04:05:06")?.add_months(m(1), P::Clamp)?,
+            dt("2021-02-28T04:05:06")?
+        );
+        assert_eq!(
+            dt("2021-01-31T04:05:06")?.add_months(m(1), P::Fail),
+            Err(E::InvalidDayOfMonth)
+        );
+        assert_eq!(
+            dt("2021-01-31T04:05:06")?.add_months(m(1), P::Roll)?,
+            dt("2021-03-03T04:05:06")?
+        );
+        assert_eq!(
+            dt("2021-03-31T04:05:06")?.sub_months(m(1), P::Clamp)?,
+            dt("2021-02-28T04:05:06")?
+        );
+        assert_eq!(
+            dt("9999-12-31T23:59:59")?.add_months(m(1), P::Clamp),
+            Err(E::OutOfRange)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn add_years_test() -> anyhow::Result<()> {
+        type E = AddMonthsError;
+        type P = EndOfMonthPolicy;
+        let dt = |s: &str| DateTime::from_str(s);
+        assert_eq!(
+            dt("2020-02-29T04:05:06")?.add_years(1, P::Clamp)?,
+            dt("2021-02-28T04:05:06")?
+        );
+        assert_eq!(
+            dt("2020-02-29T04:05:06")?.add_years(1, P::Roll)?,
+            dt("2021-03-01T04:05:06")?
+        );
+        assert_eq!(
+            dt("2020-02-29T04:05:06")?.sub_years(1, P::Fail),
+            Err(E::InvalidDayOfMonth)
+        );
+        assert_eq!(
+            dt("1970-01-01T00:00:00")?.sub_years(1, P::Fail),
+            Err(E::OutOfRange)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn checked_add_period_test() -> anyhow::Result<()> {
+        let dt = |s: &str| DateTime::from_str(s);
+        let p = |s: &str| Period::from_str(s);
+        assert_eq!(
+            dt("2021-02-03T04:05:06")?.checked_add_period(p("P1Y2M10DT2H30M")?),
+            Some(dt("2022-04-13T06:35:06")?)
+        );
+        assert_eq!(
+            dt("2021-01-31T23:00:00")?.checked_add_period(p("P1MT1H")?),
+            Some(dt("2021-03-01T00:00:00")?)
+        );
+        assert_eq!(
+            dt("9999-12-31T23:59:59")?.checked_add_period(p("PT1S")?),
+            None
+        );
+        assert_eq!(
+            dt("2021-02-03T04:05:06.123")?.checked_add_period(p("P1DT1S")?),
+            Some(dt("2021-02-04T04:05:07.123")?)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn checked_sub_period_test() -> anyhow::Result<()> {
+        let dt = |s: &str| DateTime::from_str(s);
+        let p = |s: &str| Period::from_str(s);
+        assert_eq!(
+            dt("2022-04-13T06:35:06")?.checked_sub_period(p("P1Y2M10DT2H30M")?),
+            Some(dt("2021-02-03T04:05:06")?)
+        );
+        assert_eq!(
+            dt("1970-01-01T00:00:00")?.checked_sub_period(p("PT1S")?),
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn seconds_from_unix_epoch_test() -> anyhow::Result<()> {
+        let dt = |s: &str| DateTime::from_str(s);
+        assert_eq!(dt("1970-01-01T00:00:00")?.seconds_from_unix_epoch(), 0);
+        assert_eq!(
+            dt("9999-12-31T23:59:59")?.seconds_from_unix_epoch(),
+            253_402_300_799
+        );
+        assert_eq!(
+            DateTime::from_seconds_from_unix_epoch(0),
+            Some(dt("1970-01-01T00:00:00")?)
+        );
+        assert_eq!(
+            DateTime::from_seconds_from_unix_epoch(253_402_300_799),
+            Some(dt("9999-12-31T23:59:59")?)
+        );
+        assert_eq!(DateTime::from_seconds_from_unix_epoch(-1), None);
+        assert_eq!(
+            DateTime::from_seconds_from_unix_epoch(253_402_300_800),
+            None
+        );
+        Ok(())
     }
 
     #[test]
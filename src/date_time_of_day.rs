@@ -0,0 +1,149 @@
+use std::convert::TryFrom;
+
+use crate::{CalendarDate, DateTime, ParseDateTimeError, Time, TimeOfDay, TryFromDateTimeError};
+
+// DateTime that allows the end of the day (e.g. 2021-02-03T24:00:00)
+// 2021-02-03T24:00:00 is ordered just before 2021-02-04T00:00:00
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct DateTimeOfDay {
+    date: CalendarDate,
+    time_of_day: TimeOfDay,
+}
+
+impl DateTimeOfDay {
+    pub fn new(date: CalendarDate, time_of_day: TimeOfDay) -> Self {
+        Self { date, time_of_day }
+    }
+
+    pub fn end_of_day(date: CalendarDate) -> Self {
+        Self::new(date, TimeOfDay::EndOfDay)
+    }
+
+    pub fn date(&self) -> CalendarDate {
+        self.date
+    }
+
+    pub fn is_end_of_day(&self) -> bool {
+        self.time_of_day.is_end_of_day()
+    }
+
+    pub fn time_of_day(&self) -> TimeOfDay {
+        self.time_of_day
+    }
+}
+
+impl std::fmt::Display for DateTimeOfDay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}T{}", self.date, self.time_of_day)
+    }
+}
+
+impl std::str::FromStr for DateTimeOfDay {
+    type Err = ParseDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.get(11..13) != Some("24") {
+            return DateTime::from_str(s).map(Self::from);
+        }
+        // check the format except for the hour
+        let date_time = DateTime::from_str(&format!("{}00{}", &s[..11], &s[13..]))?;
+        let time_of_day = TimeOfDay::from_str(&s[11..]).map_err(ParseDateTimeError::ParseTime)?;
+        Ok(Self::new(date_time.date(), time_of_day))
+    }
+}
+
+impl From<DateTime> for DateTimeOfDay {
+    fn from(date_time: DateTime) -> Self {
+        Self::new(date_time.date(), TimeOfDay::Time(date_time.time()))
+    }
+}
+
+// the end of the day is normalized to 00:00:00 of the next day
+impl TryFrom<DateTimeOfDay> for DateTime {
+    type Error = TryFromDateTimeError;
+
+    fn try_from(date_time_of_day: DateTimeOfDay) -> Result<Self, Self::Error> {
+        match date_time_of_day.time_of_day {
+            TimeOfDay::Time(time) => Ok(DateTime::new(date_time_of_day.date, time)),
+            TimeOfDay::EndOfDay => date_time_of_day
+                .date
+                .succ()
+                .map(|date| DateTime::new(date, Time::min()))
+                .ok_or(TryFromDateTimeError::OutOfRange),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{OffsetDateTime, TimeZoneOffset};
+
+    use super::*;
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        type E = ParseDateTimeError;
+        let f = |s: &str| -> anyhow::Result<String> { Ok(DateTimeOfDay::from_str(s)?.to_string()) };
+        assert_eq!(f("2021-02-03T24:00:00")?, "2021-02-03T24:00:00");
+        assert_eq!(f("2021-02-03T24:00:00.0")?, "2021-02-03T24:00:00");
+        assert_eq!(f("9999-12-31T24:00:00")?, "9999-12-31T24:00:00");
+        assert_eq!(f("2021-02-03T04:05:06.5")?, "2021-02-03T04:05:06.500");
+        let f = DateTimeOfDay::from_str;
+        assert!(matches!(f("2021-02-03T24:00:01"), Err(E::ParseTime(_))));
+        assert!(matches!(f("2021-02-03T25:00:00"), Err(E::ParseTime(_))));
+        assert!(matches!(f("2021-02-30T24:00:00"), Err(E::ParseDate(_))));
+        assert!(matches!(f("2021-02-03 24:00:00"), Err(E::InvalidFormat)));
+        assert!(matches!(f("2021-02-03T24:00"), Err(E::InvalidLength)));
+        Ok(())
+    }
+
+    #[test]
+    fn date_time_conversion_test() -> anyhow::Result<()> {
+        let f = |s: &str| -> anyhow::Result<String> {
+            Ok(DateTime::try_from(DateTimeOfDay::from_str(s)?)?.to_string())
+        };
+        assert_eq!(f("2021-02-03T24:00:00")?, "2021-02-04T00:00:00");
+        assert_eq!(f("2021-02-28T24:00:00")?, "2021-03-01T00:00:00");
+        assert_eq!(f("2021-12-31T24:00:00")?, "2022-01-01T00:00:00");
+        assert_eq!(f("2021-02-03T04:05:06")?, "2021-02-03T04:05:06");
+        assert_eq!(
+            DateTime::try_from(DateTimeOfDay::from_str("9999-12-31T24:00:00")?),
+            Err(TryFromDateTimeError::OutOfRange)
+        );
+
+        let date_time = DateTime::try_from(DateTimeOfDay::from_str("2021-02-03T24:00:00")?)?;
+        assert_eq!(
+            OffsetDateTime::new(date_time, TimeZoneOffset::utc())
+                .instant()
+                .to_string(),
+            "2021-02-04T00:00:00Z"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn accessors_test() -> anyhow::Result<()> {
+        let date = CalendarDate::from_str("2021-02-03")?;
+        let end_of_day = DateTimeOfDay::end_of_day(date);
+        assert_eq!(end_of_day, DateTimeOfDay::from_str("2021-02-03T24:00:00")?);
+        assert_eq!(end_of_day.date(), date);
+        assert!(end_of_day.is_end_of_day());
+        assert_eq!(end_of_day.time_of_day(), TimeOfDay::EndOfDay);
+        let date_time = DateTime::from_str("2021-02-03T04:05:06")?;
+        assert!(!DateTimeOfDay::from(date_time).is_end_of_day());
+        Ok(())
+    }
+
+    #[test]
+    fn ord_test() -> anyhow::Result<()> {
+        let t = |s: &str| DateTimeOfDay::from_str(s);
+        assert!(t("2021-02-03T23:59:59.999")? < t("2021-02-03T24:00:00")?);
+        assert!(t("2021-02-03T24:00:00")? < t("2021-02-04T00:00:00")?);
+        let range = t("2021-02-03T09:00:00")?..t("2021-02-03T24:00:00")?;
+        assert!(range.contains(&t("2021-02-03T23:59:59")?));
+        assert!(!range.contains(&t("2021-02-03T24:00:00")?));
+        Ok(())
+    }
+}
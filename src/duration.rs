@@ -1,7 +1,17 @@
 mod days;
 mod months;
+mod period;
+mod rounding_mode;
 mod seconds;
+mod signed_days;
+mod signed_months;
+mod signed_seconds;
 
 pub use self::days::*;
 pub use self::months::*;
+pub use self::period::*;
+pub use self::rounding_mode::*;
 pub use self::seconds::*;
+pub use self::signed_days::*;
+pub use self::signed_months::*;
+pub use self::signed_seconds::*;
@@ -1,5 +1,6 @@
-use crate::Seconds;
+use crate::{RoundingMode, Seconds};
 use std::convert::TryFrom;
+use std::time::Duration;
 use thiserror::Error;
 
 // 1970-01-01 ... +0
@@ -8,6 +9,15 @@ use thiserror::Error;
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Days(u32);
 
+impl Days {
+    pub fn from_duration(duration: Duration, mode: RoundingMode) -> Result<Self, TryFromDaysError> {
+        let days = mode.divide(duration.as_nanos(), 86_400_000_000_000);
+        u32::try_from(days)
+            .map_err(|_| TryFromDaysError::OutOfRange)
+            .and_then(Self::try_from)
+    }
+}
+
 impl From<Days> for i32 {
     fn from(value: Days) -> Self {
         i32::try_from(value.0).expect("days is [0, 2_932_896]")
@@ -16,7 +26,7 @@ impl From<Days> for i32 {
 
 impl From<Days> for i64 {
     fn from(value: Days) -> Self {
-        i64::try_from(value.0).expect("days is [0, 2_932_896]")
+        i64::from(value.0)
     }
 }
 
@@ -142,6 +152,21 @@ impl From<Days> for Seconds {
     }
 }
 
+impl From<Days> for Duration {
+    fn from(value: Days) -> Self {
+        Duration::from_secs(u64::from(Seconds::from(value)))
+    }
+}
+
+// the fraction of a day is truncated (see Days::from_duration)
+impl std::convert::TryFrom<Duration> for Days {
+    type Error = TryFromDaysError;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_secs() / 86_400)
+    }
+}
+
 #[derive(Debug, Eq, Error, PartialEq)]
 pub enum TryFromDaysError {
     #[error("out of range")]
@@ -229,4 +254,31 @@ mod tests {
         assert_eq!(Seconds::from(Days::from(0_u8)), Seconds::from(0_u64));
         assert_eq!(Seconds::from(Days::from(1_u8)), Seconds::from(86_400_u64));
     }
+
+    #[test]
+    fn duration_conversion_test() {
+        let d = |hours: u64| Duration::from_secs(hours * 3_600);
+        assert_eq!(Duration::from(Days::from(2_u8)), d(48));
+        assert_eq!(Days::try_from(d(47)), Ok(Days::from(1_u8)));
+        assert_eq!(
+            Days::try_from(d(24 * 2_932_897)),
+            Err(TryFromDaysError::OutOfRange)
+        );
+
+        type M = RoundingMode;
+        let f = |duration: Duration, mode: M| Days::from_duration(duration, mode).map(u32::from);
+        assert_eq!(f(d(24), M::Ceil), Ok(1));
+        assert_eq!(f(d(25), M::Ceil), Ok(2));
+        assert_eq!(f(d(47), M::Floor), Ok(1));
+        assert_eq!(f(d(35), M::Round), Ok(1));
+        assert_eq!(f(d(36), M::Round), Ok(2));
+        assert_eq!(
+            f(d(24 * 2_932_896 + 1), M::Ceil),
+            Err(TryFromDaysError::OutOfRange)
+        );
+        assert_eq!(
+            f(Duration::MAX, M::Floor),
+            Err(TryFromDaysError::OutOfRange)
+        );
+    }
 }
@@ -0,0 +1,335 @@
+use std::convert::TryFrom;
+use thiserror::Error;
+
+use crate::{Days, Months, Seconds};
+
+// ISO 8601 duration (e.g. P1Y2M10DT2H30M)
+//
+// calendar part (years, months, days) ... nominal, depends on the date
+// exact part (seconds) ... accurate, independent of the date
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Period {
+    years: u32,
+    months: Months,
+    days: Days,
+    seconds: Seconds,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParsePeriodError {
+    #[error("invalid format")]
+    InvalidFormat,
+    #[error("out of range")]
+    OutOfRange,
+}
+
+impl Period {
+    pub fn new(years: u32, months: Months, days: Days, seconds: Seconds) -> Self {
+        Self {
+            years,
+            months,
+            days,
+            seconds,
+        }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(
+            0,
+            Months::from(0_u32),
+            Days::from(0_u8),
+            Seconds::from(0_u64),
+        )
+    }
+
+    pub fn years(&self) -> u32 {
+        self.years
+    }
+
+    pub fn months(&self) -> Months {
+        self.months
+    }
+
+    pub fn days(&self) -> Days {
+        self.days
+    }
+
+    pub fn seconds(&self) -> Seconds {
+        self.seconds
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    // each component * rhs
+    pub(crate) fn checked_mul(&self, rhs: u32) -> Option<Self> {
+        let days = u64::from(u32::from(self.days)).checked_mul(u64::from(rhs))?;
+        Some(Self::new(
+            self.years.checked_mul(rhs)?,
+            Months::from(u32::from(self.months).checked_mul(rhs)?),
+            Days::try_from(days).ok()?,
+            Seconds::from(u64::from(self.seconds).checked_mul(u64::from(rhs))?),
+        ))
+    }
+
+    // years * 12 + months
+    pub(crate) fn total_months(&self) -> Option<u32> {
+        self.years
+            .checked_mul(12)
+            .and_then(|m| m.checked_add(u32::from(self.months)))
+    }
+}
+
+impl From<Days> for Period {
+    fn from(value: Days) -> Self {
+        Self::new(0, Months::from(0_u32), value, Seconds::from(0_u64))
+    }
+}
+
+impl From<Months> for Period {
+    fn from(value: Months) -> Self {
+        Self::new(0, value, Days::from(0_u8), Seconds::from(0_u64))
+    }
+}
+
+impl From<Seconds> for Period {
+    fn from(value: Seconds) -> Self {
+        Self::new(0, Months::from(0_u32), Days::from(0_u8), value)
+    }
+}
+
+impl std::fmt::Display for Period {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "PT0S");
+        }
+        write!(f, "P")?;
+        if self.years != 0 {
+            write!(f, "{}Y", self.years)?;
+        }
+        let months = u32::from(self.months);
+        if months != 0 {
+            write!(f, "{}M", months)?;
+        }
+        let days = u32::from(self.days);
+        if days != 0 {
+            write!(f, "{}D", days)?;
+        }
+        let seconds = u64::from(self.seconds);
+        if seconds != 0 {
+            write!(f, "T")?;
+            let (h, m, s) = (seconds / 3_600, seconds / 60 % 60, seconds % 60);
+            if h != 0 {
+                write!(f, "{}H", h)?;
+            }
+            if m != 0 {
+                write!(f, "{}M", m)?;
+            }
+            if s != 0 {
+                write!(f, "{}S", s)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Period {
+    type Err = ParsePeriodError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix('P').ok_or(Self::Err::InvalidFormat)?;
+        let (date_part, time_part) = match s.split_once('T') {
+            Some((_, "")) => return Err(Self::Err::InvalidFormat),
+            Some((d, t)) => (d, t),
+            None => (s, ""),
+        };
+        if date_part.is_empty() && time_part.is_empty() {
+            return Err(Self::Err::InvalidFormat);
+        }
+
+        let date_values = parse_values(date_part, &['Y', 'M', 'W', 'D'])?;
+        let time_values = parse_values(time_part, &['H', 'M', 'S'])?;
+        // PnW is not combined with other designators
+        if date_values[2].is_some()
+            && (date_values.iter().filter(|v| v.is_some()).count() != 1 || !time_part.is_empty())
+        {
+            return Err(Self::Err::InvalidFormat);
+        }
+
+        let years =
+            u32::try_from(date_values[0].unwrap_or(0)).map_err(|_| Self::Err::OutOfRange)?;
+        let months =
+            u32::try_from(date_values[1].unwrap_or(0)).map_err(|_| Self::Err::OutOfRange)?;
+        let days = date_values[2]
+            .unwrap_or(0)
+            .checked_mul(7)
+            .and_then(|d| d.checked_add(date_values[3].unwrap_or(0)))
+            .and_then(|d| Days::try_from(d).ok())
+            .ok_or(Self::Err::OutOfRange)?;
+        let seconds = time_values[0]
+            .unwrap_or(0)
+            .checked_mul(3_600)
+            .and_then(|s| s.checked_add(time_values[1].unwrap_or(0).checked_mul(60)?))
+            .and_then(|s| s.checked_add(time_values[2].unwrap_or(0)))
+            .ok_or(Self::Err::OutOfRange)?;
+        Ok(Self::new(
+            years,
+            Months::from(months),
+            days,
+            Seconds::from(seconds),
+        ))
+    }
+}
+
+// "1Y2M" & ['Y', 'M', 'D'] -> [Some(1), Some(2), None]
+fn parse_values(s: &str, designators: &[char]) -> Result<Vec<Option<u64>>, ParsePeriodError> {
+    let mut values = vec![None; designators.len()];
+    let mut next = 0_usize;
+    let mut value: Option<u64> = None;
+    for c in s.chars() {
+        match c {
+            '0'..='9' => {
+                let d = u64::from(c as u8 - b'0');
+                value = Some(
+                    value
+                        .unwrap_or(0)
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add(d))
+                        .ok_or(ParsePeriodError::OutOfRange)?,
+                );
+            }
+            _ => {
+                let index = designators[next..]
+                    .iter()
+                    .position(|&d| d == c)
+                    .map(|i| next + i)
+                    .ok_or(ParsePeriodError::InvalidFormat)?;
+                values[index] = Some(value.take().ok_or(ParsePeriodError::InvalidFormat)?);
+                next = index + 1;
+            }
+        }
+    }
+    if value.is_some() {
+        return Err(ParsePeriodError::InvalidFormat);
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn new_test() -> anyhow::Result<()> {
+        let period = Period::new(
+            1,
+            Months::from(2_u32),
+            Days::from(10_u8),
+            Seconds::from(9_000_u64),
+        );
+        assert_eq!(period.years(), 1);
+        assert_eq!(period.months(), Months::from(2_u32));
+        assert_eq!(period.days(), Days::from(10_u8));
+        assert_eq!(period.seconds(), Seconds::from(9_000_u64));
+        assert_eq!(period, Period::from_str("P1Y2M10DT2H30M")?);
+        Ok(())
+    }
+
+    #[test]
+    fn zero_test() -> anyhow::Result<()> {
+        assert!(Period::zero().is_zero());
+        assert!(!Period::from(Days::from(1_u8)).is_zero());
+        assert_eq!(Period::zero(), Period::from_str("PT0S")?);
+        assert_eq!(Period::zero(), Period::from_str("P0D")?);
+        Ok(())
+    }
+
+    #[test]
+    fn from_test() {
+        assert_eq!(
+            Period::from(Days::from(1_u8)).to_string(),
+            "P1D".to_string()
+        );
+        assert_eq!(
+            Period::from(Months::from(1_u32)).to_string(),
+            "P1M".to_string()
+        );
+        assert_eq!(
+            Period::from(Seconds::from(1_u64)).to_string(),
+            "PT1S".to_string()
+        );
+    }
+
+    #[test]
+    fn checked_mul_test() -> anyhow::Result<()> {
+        assert_eq!(
+            Period::from_str("P1Y2M3DT4H")?.checked_mul(3),
+            Some(Period::from_str("P3Y6M9DT12H")?)
+        );
+        assert_eq!(
+            Period::from_str("P1D")?.checked_mul(0),
+            Some(Period::zero())
+        );
+        assert_eq!(Period::from_str("P1D")?.checked_mul(2_932_897), None);
+        Ok(())
+    }
+
+    #[test]
+    fn total_months_test() -> anyhow::Result<()> {
+        assert_eq!(Period::from_str("P1Y2M")?.total_months(), Some(14));
+        assert_eq!(
+            Period::new(
+                u32::MAX,
+                Months::from(0_u32),
+                Days::from(0_u8),
+                Seconds::from(0_u64)
+            )
+            .total_months(),
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        type E = ParsePeriodError;
+        let f = |s: &str| Period::from_str(s);
+
+        assert_eq!(f("P1Y2M10DT2H30M")?.to_string(), "P1Y2M10DT2H30M");
+        assert_eq!(f("P1Y")?.to_string(), "P1Y");
+        assert_eq!(f("P2M")?.to_string(), "P2M");
+        assert_eq!(f("P3D")?.to_string(), "P3D");
+        assert_eq!(f("P2W")?.to_string(), "P14D");
+        assert_eq!(f("PT4H")?.to_string(), "PT4H");
+        assert_eq!(f("PT5M")?.to_string(), "PT5M");
+        assert_eq!(f("PT6S")?.to_string(), "PT6S");
+        assert_eq!(f("PT90M")?.to_string(), "PT1H30M");
+        assert_eq!(f("PT36H")?.to_string(), "PT36H");
+        assert_eq!(f("P0Y0M0DT0H0M0S")?.to_string(), "PT0S");
+
+        assert_eq!(f(""), Err(E::InvalidFormat));
+        assert_eq!(f("P"), Err(E::InvalidFormat));
+        assert_eq!(f("PT"), Err(E::InvalidFormat));
+        assert_eq!(f("P1DT"), Err(E::InvalidFormat));
+        assert_eq!(f("1D"), Err(E::InvalidFormat));
+        assert_eq!(f("p1D"), Err(E::InvalidFormat));
+        assert_eq!(f("P1d"), Err(E::InvalidFormat));
+        assert_eq!(f("P1"), Err(E::InvalidFormat));
+        assert_eq!(f("PD"), Err(E::InvalidFormat));
+        assert_eq!(f("P1D1Y"), Err(E::InvalidFormat));
+        assert_eq!(f("P1Y1Y"), Err(E::InvalidFormat));
+        assert_eq!(f("P1H"), Err(E::InvalidFormat));
+        assert_eq!(f("PT1D"), Err(E::InvalidFormat));
+        assert_eq!(f("P1W1D"), Err(E::InvalidFormat));
+        assert_eq!(f("P1WT1H"), Err(E::InvalidFormat));
+        assert_eq!(f("P-1D"), Err(E::InvalidFormat));
+        assert_eq!(f("P1.5D"), Err(E::InvalidFormat));
+        assert_eq!(f("P2932897D"), Err(E::OutOfRange));
+        assert_eq!(f("P4294967296Y"), Err(E::OutOfRange));
+        assert_eq!(f("PT99999999999999999999S"), Err(E::OutOfRange));
+        Ok(())
+    }
+}
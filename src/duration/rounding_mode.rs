@@ -0,0 +1,44 @@
+// how to drop the fraction (e.g. Seconds::from_duration)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundingMode {
+    // 1.4 -> 2, 1.5 -> 2
+    Ceil,
+    // 1.5 -> 1, 1.6 -> 1
+    Floor,
+    // 1.4 -> 1, 1.5 -> 2 (half up)
+    Round,
+}
+
+impl RoundingMode {
+    pub(crate) fn divide(self, dividend: u128, divisor: u128) -> u128 {
+        let quotient = dividend / divisor;
+        let remainder = dividend % divisor;
+        let round_up = match self {
+            RoundingMode::Ceil => remainder > 0,
+            RoundingMode::Floor => false,
+            RoundingMode::Round => remainder * 2 >= divisor,
+        };
+        if round_up {
+            quotient + 1
+        } else {
+            quotient
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn divide_test() {
+        type M = RoundingMode;
+        assert_eq!(M::Ceil.divide(10, 10), 1);
+        assert_eq!(M::Ceil.divide(11, 10), 2);
+        assert_eq!(M::Floor.divide(19, 10), 1);
+        assert_eq!(M::Floor.divide(20, 10), 2);
+        assert_eq!(M::Round.divide(14, 10), 1);
+        assert_eq!(M::Round.divide(15, 10), 2);
+        assert_eq!(M::Round.divide(0, 10), 0);
+    }
+}
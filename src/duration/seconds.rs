@@ -1,6 +1,30 @@
+use std::{convert::TryFrom, time::Duration};
+
+use thiserror::Error;
+
+use crate::RoundingMode;
+
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Seconds(u64);
 
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum TryFromSecondsError {
+    #[error("out of range")]
+    OutOfRange,
+}
+
+impl Seconds {
+    pub fn from_duration(
+        duration: Duration,
+        mode: RoundingMode,
+    ) -> Result<Self, TryFromSecondsError> {
+        let seconds = mode.divide(duration.as_nanos(), 1_000_000_000);
+        u64::try_from(seconds)
+            .map(Self)
+            .map_err(|_| TryFromSecondsError::OutOfRange)
+    }
+}
+
 impl From<u64> for Seconds {
     fn from(value: u64) -> Self {
         Self(value)
@@ -13,10 +37,43 @@ impl From<Seconds> for u64 {
     }
 }
 
+// the fraction of a second is truncated (see Seconds::from_duration)
+impl From<Duration> for Seconds {
+    fn from(duration: Duration) -> Self {
+        Self(duration.as_secs())
+    }
+}
+
+impl From<Seconds> for Duration {
+    fn from(seconds: Seconds) -> Self {
+        Duration::from_secs(seconds.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn duration_conversion_test() {
+        let d = Duration::from_millis;
+        assert_eq!(Seconds::from(d(1_999)), Seconds::from(1_u64));
+        assert_eq!(Duration::from(Seconds::from(2_u64)), d(2_000));
+
+        type M = RoundingMode;
+        let f = |duration: Duration, mode: M| Seconds::from_duration(duration, mode).map(u64::from);
+        assert_eq!(f(d(1_000), M::Ceil), Ok(1));
+        assert_eq!(f(d(1_001), M::Ceil), Ok(2));
+        assert_eq!(f(d(1_999), M::Floor), Ok(1));
+        assert_eq!(f(d(1_499), M::Round), Ok(1));
+        assert_eq!(f(d(1_500), M::Round), Ok(2));
+        assert_eq!(
+            f(Duration::new(u64::MAX, 1), M::Ceil),
+            Err(TryFromSecondsError::OutOfRange)
+        );
+        assert_eq!(f(Duration::new(u64::MAX, 1), M::Floor), Ok(u64::MAX));
+    }
+
     #[test]
     fn u64_conversion_test() {
         assert_eq!(u64::from(Seconds::from(0_u64)), 0_u64);
@@ -0,0 +1,182 @@
+use std::convert::TryFrom;
+use thiserror::Error;
+
+use crate::{Days, SignedSeconds};
+
+// -(9999-12-31 - 1970-01-01) ... -2_932_896
+//  (9999-12-31 - 1970-01-01) ... +2_932_896
+// [-2_932_896, 2_932_896]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SignedDays(i32);
+
+impl SignedDays {
+    pub fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    pub fn unsigned_abs(&self) -> Days {
+        Days::try_from(self.0.unsigned_abs()).expect("signed days is [-2_932_896, 2_932_896]")
+    }
+}
+
+impl From<Days> for SignedDays {
+    fn from(value: Days) -> Self {
+        Self(i32::from(value))
+    }
+}
+
+impl From<SignedDays> for i32 {
+    fn from(value: SignedDays) -> Self {
+        value.0
+    }
+}
+
+impl From<SignedDays> for i64 {
+    fn from(value: SignedDays) -> Self {
+        i64::from(value.0)
+    }
+}
+
+impl From<i8> for SignedDays {
+    fn from(value: i8) -> Self {
+        Self(i32::from(value))
+    }
+}
+
+impl From<i16> for SignedDays {
+    fn from(value: i16) -> Self {
+        Self(i32::from(value))
+    }
+}
+
+impl std::convert::TryFrom<i32> for SignedDays {
+    type Error = TryFromSignedDaysError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        if (-2_932_896_i32..=2_932_896_i32).contains(&value) {
+            Ok(Self(value))
+        } else {
+            Err(Self::Error::OutOfRange)
+        }
+    }
+}
+
+impl std::convert::TryFrom<i64> for SignedDays {
+    type Error = TryFromSignedDaysError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        let value_as_i32 = i32::try_from(value).map_err(|_| Self::Error::OutOfRange)?;
+        Self::try_from(value_as_i32)
+    }
+}
+
+impl std::convert::TryFrom<SignedDays> for Days {
+    type Error = TryFromSignedDaysError;
+
+    fn try_from(value: SignedDays) -> Result<Self, Self::Error> {
+        Days::try_from(value.0).map_err(|_| Self::Error::OutOfRange)
+    }
+}
+
+impl From<SignedDays> for SignedSeconds {
+    fn from(value: SignedDays) -> Self {
+        Self::from(i64::from(value.0) * 86_400_i64)
+    }
+}
+
+impl std::ops::Neg for SignedDays {
+    type Output = SignedDays;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum TryFromSignedDaysError {
+    #[error("out of range")]
+    OutOfRange,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn i8_conversion_test() {
+        assert_eq!(i32::from(SignedDays::from(i8::MIN)), -128_i32);
+        assert_eq!(i32::from(SignedDays::from(i8::MAX)), 127_i32);
+    }
+
+    #[test]
+    fn i16_conversion_test() {
+        assert_eq!(i32::from(SignedDays::from(i16::MIN)), -32_768_i32);
+        assert_eq!(i32::from(SignedDays::from(i16::MAX)), 32_767_i32);
+    }
+
+    #[test]
+    fn i32_conversion_test() {
+        assert!(SignedDays::try_from(-2_932_896_i32 - 1).is_err());
+        assert_eq!(
+            SignedDays::try_from(-2_932_896_i32).map(i32::from),
+            Ok(-2_932_896_i32)
+        );
+        assert_eq!(
+            SignedDays::try_from(2_932_896_i32).map(i32::from),
+            Ok(2_932_896_i32)
+        );
+        assert!(SignedDays::try_from(2_932_896_i32 + 1).is_err());
+    }
+
+    #[test]
+    fn i64_conversion_test() {
+        assert!(SignedDays::try_from(-2_932_896_i64 - 1).is_err());
+        assert_eq!(
+            SignedDays::try_from(-2_932_896_i64).map(i64::from),
+            Ok(-2_932_896_i64)
+        );
+        assert_eq!(
+            SignedDays::try_from(2_932_896_i64).map(i64::from),
+            Ok(2_932_896_i64)
+        );
+        assert!(SignedDays::try_from(2_932_896_i64 + 1).is_err());
+    }
+
+    #[test]
+    fn days_conversion_test() {
+        assert_eq!(SignedDays::from(Days::from(1_u8)), SignedDays::from(1_i8));
+        assert_eq!(Days::try_from(SignedDays::from(1_i8)), Ok(Days::from(1_u8)));
+        assert!(Days::try_from(SignedDays::from(-1_i8)).is_err());
+    }
+
+    #[test]
+    fn signed_seconds_conversion_test() {
+        assert_eq!(
+            SignedSeconds::from(SignedDays::from(-1_i8)),
+            SignedSeconds::from(-86_400_i64)
+        );
+        assert_eq!(
+            SignedSeconds::from(SignedDays::from(1_i8)),
+            SignedSeconds::from(86_400_i64)
+        );
+    }
+
+    #[test]
+    fn is_negative_test() {
+        assert!(SignedDays::from(-1_i8).is_negative());
+        assert!(!SignedDays::from(0_i8).is_negative());
+        assert!(!SignedDays::from(1_i8).is_negative());
+    }
+
+    #[test]
+    fn unsigned_abs_test() {
+        assert_eq!(SignedDays::from(-2_i8).unsigned_abs(), Days::from(2_u8));
+        assert_eq!(SignedDays::from(2_i8).unsigned_abs(), Days::from(2_u8));
+    }
+
+    #[test]
+    fn neg_test() {
+        assert_eq!(-SignedDays::from(1_i8), SignedDays::from(-1_i8));
+        assert_eq!(-SignedDays::from(-1_i8), SignedDays::from(1_i8));
+    }
+}
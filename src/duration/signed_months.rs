@@ -0,0 +1,114 @@
+use thiserror::Error;
+
+use crate::Months;
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SignedMonths(i32);
+
+impl SignedMonths {
+    pub fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    pub fn unsigned_abs(&self) -> Months {
+        Months::from(self.0.unsigned_abs())
+    }
+}
+
+impl From<i32> for SignedMonths {
+    fn from(value: i32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<SignedMonths> for i32 {
+    fn from(value: SignedMonths) -> Self {
+        value.0
+    }
+}
+
+impl std::convert::TryFrom<Months> for SignedMonths {
+    type Error = TryFromSignedMonthsError;
+
+    fn try_from(value: Months) -> Result<Self, Self::Error> {
+        i32::try_from(u32::from(value))
+            .map(Self)
+            .map_err(|_| Self::Error::OutOfRange)
+    }
+}
+
+impl std::convert::TryFrom<SignedMonths> for Months {
+    type Error = TryFromSignedMonthsError;
+
+    fn try_from(value: SignedMonths) -> Result<Self, Self::Error> {
+        u32::try_from(value.0)
+            .map(Months::from)
+            .map_err(|_| Self::Error::OutOfRange)
+    }
+}
+
+impl std::ops::Neg for SignedMonths {
+    type Output = SignedMonths;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.checked_neg().unwrap_or_else(|| panic!("overflow")))
+    }
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum TryFromSignedMonthsError {
+    #[error("out of range")]
+    OutOfRange,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn i32_conversion_test() {
+        assert_eq!(i32::from(SignedMonths::from(-1_i32)), -1_i32);
+        assert_eq!(i32::from(SignedMonths::from(0_i32)), 0_i32);
+        assert_eq!(i32::from(SignedMonths::from(1_i32)), 1_i32);
+    }
+
+    #[test]
+    fn seconds_conversion_test() {
+        assert_eq!(
+            SignedMonths::try_from(Months::from(1_u32)),
+            Ok(SignedMonths::from(1_i32))
+        );
+        assert!(SignedMonths::try_from(Months::from(u32::MAX)).is_err());
+        assert_eq!(
+            Months::try_from(SignedMonths::from(1_i32)),
+            Ok(Months::from(1_u32))
+        );
+        assert!(Months::try_from(SignedMonths::from(-1_i32)).is_err());
+    }
+
+    #[test]
+    fn is_negative_test() {
+        assert!(SignedMonths::from(-1_i32).is_negative());
+        assert!(!SignedMonths::from(0_i32).is_negative());
+        assert!(!SignedMonths::from(1_i32).is_negative());
+    }
+
+    #[test]
+    fn unsigned_abs_test() {
+        assert_eq!(
+            SignedMonths::from(-2_i32).unsigned_abs(),
+            Months::from(2_u32)
+        );
+        assert_eq!(
+            SignedMonths::from(2_i32).unsigned_abs(),
+            Months::from(2_u32)
+        );
+    }
+
+    #[test]
+    fn neg_test() {
+        assert_eq!(-SignedMonths::from(1_i32), SignedMonths::from(-1_i32));
+        assert_eq!(-SignedMonths::from(-1_i32), SignedMonths::from(1_i32));
+    }
+}
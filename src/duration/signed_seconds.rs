@@ -0,0 +1,114 @@
+use thiserror::Error;
+
+use crate::Seconds;
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SignedSeconds(i64);
+
+impl SignedSeconds {
+    pub fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    pub fn unsigned_abs(&self) -> Seconds {
+        Seconds::from(self.0.unsigned_abs())
+    }
+}
+
+impl From<i64> for SignedSeconds {
+    fn from(value: i64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<SignedSeconds> for i64 {
+    fn from(value: SignedSeconds) -> Self {
+        value.0
+    }
+}
+
+impl std::convert::TryFrom<Seconds> for SignedSeconds {
+    type Error = TryFromSignedSecondsError;
+
+    fn try_from(value: Seconds) -> Result<Self, Self::Error> {
+        i64::try_from(u64::from(value))
+            .map(Self)
+            .map_err(|_| Self::Error::OutOfRange)
+    }
+}
+
+impl std::convert::TryFrom<SignedSeconds> for Seconds {
+    type Error = TryFromSignedSecondsError;
+
+    fn try_from(value: SignedSeconds) -> Result<Self, Self::Error> {
+        u64::try_from(value.0)
+            .map(Seconds::from)
+            .map_err(|_| Self::Error::OutOfRange)
+    }
+}
+
+impl std::ops::Neg for SignedSeconds {
+    type Output = SignedSeconds;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.checked_neg().unwrap_or_else(|| panic!("overflow")))
+    }
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum TryFromSignedSecondsError {
+    #[error("out of range")]
+    OutOfRange,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn i64_conversion_test() {
+        assert_eq!(i64::from(SignedSeconds::from(-1_i64)), -1_i64);
+        assert_eq!(i64::from(SignedSeconds::from(0_i64)), 0_i64);
+        assert_eq!(i64::from(SignedSeconds::from(1_i64)), 1_i64);
+    }
+
+    #[test]
+    fn seconds_conversion_test() {
+        assert_eq!(
+            SignedSeconds::try_from(Seconds::from(1_u64)),
+            Ok(SignedSeconds::from(1_i64))
+        );
+        assert!(SignedSeconds::try_from(Seconds::from(u64::MAX)).is_err());
+        assert_eq!(
+            Seconds::try_from(SignedSeconds::from(1_i64)),
+            Ok(Seconds::from(1_u64))
+        );
+        assert!(Seconds::try_from(SignedSeconds::from(-1_i64)).is_err());
+    }
+
+    #[test]
+    fn is_negative_test() {
+        assert!(SignedSeconds::from(-1_i64).is_negative());
+        assert!(!SignedSeconds::from(0_i64).is_negative());
+        assert!(!SignedSeconds::from(1_i64).is_negative());
+    }
+
+    #[test]
+    fn unsigned_abs_test() {
+        assert_eq!(
+            SignedSeconds::from(-2_i64).unsigned_abs(),
+            Seconds::from(2_u64)
+        );
+        assert_eq!(
+            SignedSeconds::from(2_i64).unsigned_abs(),
+            Seconds::from(2_u64)
+        );
+    }
+
+    #[test]
+    fn neg_test() {
+        assert_eq!(-SignedSeconds::from(1_i64), SignedSeconds::from(-1_i64));
+        assert_eq!(-SignedSeconds::from(-1_i64), SignedSeconds::from(1_i64));
+    }
+}
@@ -0,0 +1,459 @@
+use thiserror::Error;
+
+use crate::{OffsetDateTime, ParseOffsetDateTimeError, TimeZone, TimeZoneOffset, ZonedDateTime};
+
+// RFC 9557 Internet Extended Date/Time Format (IXDTF)
+// e.g. 2021-02-03T04:05:06+09:00[Asia/Tokyo][u-ca=japanese]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExtendedDateTime {
+    offset_date_time: OffsetDateTime,
+    time_zone: Option<TimeZoneAnnotation>,
+    time_zone_critical: bool,
+    annotations: Vec<Annotation>,
+}
+
+// the first suffix (e.g. [Asia/Tokyo] or [+09:00])
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TimeZoneAnnotation {
+    Name(String),
+    Offset(TimeZoneOffset),
+}
+
+// the suffix tag (e.g. [u-ca=japanese] or [!u-ca=iso8601])
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Annotation {
+    critical: bool,
+    key: String,
+    value: String,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParseExtendedDateTimeError {
+    #[error("conflicting annotations")]
+    ConflictingAnnotations(String),
+    #[error("invalid annotation")]
+    InvalidAnnotation,
+    #[error("invalid format")]
+    InvalidFormat,
+    #[error("invalid time zone")]
+    InvalidTimeZone,
+    #[error("offset mismatch")]
+    OffsetMismatch,
+    #[error("parse offset date time")]
+    ParseOffsetDateTime(ParseOffsetDateTimeError),
+    #[error("unsupported critical annotation")]
+    UnsupportedCriticalAnnotation(String),
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ResolveExtendedDateTimeError {
+    #[error("offset mismatch")]
+    OffsetMismatch,
+    #[error("out of range")]
+    OutOfRange,
+    #[error("time zone mismatch")]
+    TimeZoneMismatch,
+}
+
+impl ExtendedDateTime {
+    pub fn annotations(&self) -> &[Annotation] {
+        &self.annotations
+    }
+
+    // the calendar (u-ca) annotation
+    pub fn calendar(&self) -> Option<&str> {
+        self.annotations
+            .iter()
+            .find(|annotation| annotation.key == "u-ca")
+            .map(|annotation| annotation.value.as_str())
+    }
+
+    pub fn is_time_zone_critical(&self) -> bool {
+        self.time_zone_critical
+    }
+
+    pub fn offset_date_time(&self) -> OffsetDateTime {
+        self.offset_date_time
+    }
+
+    pub fn time_zone(&self) -> Option<&TimeZoneAnnotation> {
+        self.time_zone.as_ref()
+    }
+
+    // time_zone must have the annotated name
+    // the offset must be the offset of time_zone at the instant (except for "Z")
+    pub fn to_zoned_date_time(
+        &self,
+        time_zone: TimeZone,
+    ) -> Result<ZonedDateTime, ResolveExtendedDateTimeError> {
+        if let Some(TimeZoneAnnotation::Name(name)) = &self.time_zone {
+            if name != time_zone.name() {
+                return Err(ResolveExtendedDateTimeError::TimeZoneMismatch);
+            }
+        }
+        let instant = self.offset_date_time.instant();
+        let offset = self.offset_date_time.offset();
+        // "Z": the instant is known but the local offset is not (RFC 9557 section 4.1)
+        if offset != TimeZoneOffset::utc() && offset != time_zone.offset_at(instant) {
+            return Err(ResolveExtendedDateTimeError::OffsetMismatch);
+        }
+        ZonedDateTime::from_instant(instant, time_zone)
+            .map_err(|_| ResolveExtendedDateTimeError::OutOfRange)
+    }
+}
+
+impl std::fmt::Display for ExtendedDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.offset_date_time)?;
+        if let Some(time_zone) = &self.time_zone {
+            write!(
+                f,
+                "[{}{}]",
+                if self.time_zone_critical { "!" } else { "" },
+                time_zone
+            )?;
+        }
+        for annotation in &self.annotations {
+            write!(f, "{}", annotation)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for ExtendedDateTime {
+    type Err = ParseExtendedDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (head, mut rest) = s.split_at(s.find('[').unwrap_or(s.len()));
+        let offset_date_time = OffsetDateTime::from_str(head)
+            .map_err(ParseExtendedDateTimeError::ParseOffsetDateTime)?;
+        let mut time_zone = None;
+        let mut time_zone_critical = false;
+        let mut annotations: Vec<Annotation> = vec![];
+        while !rest.is_empty() {
+            let end = match (rest.starts_with('['), rest.find(']')) {
+                (true, Some(end)) => end,
+                _ => return Err(Self::Err::InvalidFormat),
+            };
+            let (critical, content) = match rest[1..end].strip_prefix('!') {
+                Some(content) => (true, content),
+                None => (false, &rest[1..end]),
+            };
+            rest = &rest[end + 1..];
+            match content.split_once('=') {
+                Some((key, value)) => {
+                    annotations.push(Annotation::new(critical, key, value)?);
+                }
+                None if time_zone.is_none() && annotations.is_empty() => {
+                    time_zone = Some(TimeZoneAnnotation::from_str(content)?);
+                    time_zone_critical = critical;
+                }
+                None => return Err(Self::Err::InvalidAnnotation),
+            }
+        }
+
+        if let Some(TimeZoneAnnotation::Offset(offset)) = time_zone {
+            let actual = offset_date_time.offset();
+            if actual != TimeZoneOffset::utc() && actual != offset {
+                return Err(Self::Err::OffsetMismatch);
+            }
+        }
+        for annotation in annotations.iter().filter(|annotation| annotation.critical) {
+            // the only calendar is the ISO 8601 calendar
+            if annotation.key != "u-ca" || annotation.value != "iso8601" {
+                return Err(Self::Err::UnsupportedCriticalAnnotation(
+                    annotation.key.clone(),
+                ));
+            }
+            if annotations
+                .iter()
+                .filter(|other| other.key == annotation.key)
+                .count()
+                > 1
+            {
+                return Err(Self::Err::ConflictingAnnotations(annotation.key.clone()));
+            }
+        }
+
+        Ok(Self {
+            offset_date_time,
+            time_zone,
+            time_zone_critical,
+            annotations,
+        })
+    }
+}
+
+impl From<OffsetDateTime> for ExtendedDateTime {
+    fn from(offset_date_time: OffsetDateTime) -> Self {
+        Self {
+            offset_date_time,
+            time_zone: None,
+            time_zone_critical: false,
+            annotations: vec![],
+        }
+    }
+}
+
+impl From<ZonedDateTime> for ExtendedDateTime {
+    fn from(zoned_date_time: ZonedDateTime) -> Self {
+        Self {
+            offset_date_time: zoned_date_time.offset_date_time(),
+            time_zone: Some(TimeZoneAnnotation::Name(
+                zoned_date_time.time_zone().name().to_string(),
+            )),
+            time_zone_critical: false,
+            annotations: vec![],
+        }
+    }
+}
+
+impl std::fmt::Display for TimeZoneAnnotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeZoneAnnotation::Name(name) => write!(f, "{}", name),
+            TimeZoneAnnotation::Offset(offset) => write!(f, "{}", offset),
+        }
+    }
+}
+
+impl std::str::FromStr for TimeZoneAnnotation {
+    type Err = ParseExtendedDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with('+') || s.starts_with('-') {
+            return TimeZoneOffset::from_str(s)
+                .map(TimeZoneAnnotation::Offset)
+                .map_err(|_| ParseExtendedDateTimeError::InvalidTimeZone);
+        }
+        // time-zone-part *("/" time-zone-part)
+        let is_valid_part = |part: &str| {
+            part != "."
+                && part != ".."
+                && part.starts_with(|c: char| c.is_ascii_alphabetic() || c == '.' || c == '_')
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-' | '+'))
+        };
+        if !s.split('/').all(is_valid_part) {
+            return Err(ParseExtendedDateTimeError::InvalidTimeZone);
+        }
+        Ok(TimeZoneAnnotation::Name(s.to_string()))
+    }
+}
+
+impl Annotation {
+    // key: [a-z_][a-z0-9_-]*
+    // value: [A-Za-z0-9]+(-[A-Za-z0-9]+)*
+    pub fn new(critical: bool, key: &str, value: &str) -> Result<Self, ParseExtendedDateTimeError> {
+        let is_valid_key = key.starts_with(|c: char| c.is_ascii_lowercase() || c == '_')
+            && key
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-');
+        let is_valid_value = value
+            .split('-')
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric()));
+        if !is_valid_key || !is_valid_value {
+            return Err(ParseExtendedDateTimeError::InvalidAnnotation);
+        }
+        Ok(Self {
+            critical,
+            key: key.to_string(),
+            value: value.to_string(),
+        })
+    }
+
+    pub fn is_critical(&self) -> bool {
+        self.critical
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl std::fmt::Display for Annotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{}{}={}]",
+            if self.critical { "!" } else { "" },
+            self.key,
+            self.value
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::tz::tzif_bytes;
+
+    use super::*;
+
+    fn tokyo() -> anyhow::Result<TimeZone> {
+        let bytes = tzif_bytes(2, &[], &[(32400, false, "JST")], "JST-9");
+        Ok(TimeZone::from_tzif_bytes("Asia/Tokyo", &bytes)?)
+    }
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        let f =
+            |s: &str| -> anyhow::Result<String> { Ok(ExtendedDateTime::from_str(s)?.to_string()) };
+        for s in [
+            "2021-02-03T04:05:06+09:00",
+            "2021-02-03T04:05:06+09:00[Asia/Tokyo]",
+            "2021-02-03T04:05:06+09:00[!Asia/Tokyo]",
+            "2021-02-03T04:05:06+09:00[+09:00]",
+            "2021-02-03T04:05:06+09:00[Asia/Tokyo][u-ca=japanese]",
+            "2021-02-03T04:05:06+09:00[u-ca=japanese][foo=bar-baz][foo=qux]",
+            "2021-02-03T04:05:06+09:00[Asia/Tokyo][!u-ca=iso8601]",
+            "2021-02-03T04:05:06Z[Etc/GMT-9]",
+            "2021-02-03T04:05:06Z[+09:00]",
+            "2021-02-03T04:05:06-03:00[America/Argentina/Buenos_Aires]",
+        ] {
+            assert_eq!(f(s)?, s);
+        }
+
+        let f = ExtendedDateTime::from_str;
+        assert_eq!(
+            f("2021-02-03T04:05:06"),
+            Err(ParseExtendedDateTimeError::ParseOffsetDateTime(
+                ParseOffsetDateTimeError::InvalidLength
+            ))
+        );
+        assert_eq!(
+            f("2021-02-03T04:05:06+09:00[Asia/Tokyo"),
+            Err(ParseExtendedDateTimeError::InvalidFormat)
+        );
+        assert_eq!(
+            f("2021-02-03T04:05:06+09:00[Asia/Tokyo]x"),
+            Err(ParseExtendedDateTimeError::InvalidFormat)
+        );
+        assert_eq!(
+            f("2021-02-03T04:05:06+09:00[Asia/Tokyo][Asia/Tokyo]"),
+            Err(ParseExtendedDateTimeError::InvalidAnnotation)
+        );
+        assert_eq!(
+            f("2021-02-03T04:05:06+09:00[u-ca=japanese][Asia/Tokyo]"),
+            Err(ParseExtendedDateTimeError::InvalidAnnotation)
+        );
+        assert_eq!(
+            f("2021-02-03T04:05:06+09:00[U-CA=japanese]"),
+            Err(ParseExtendedDateTimeError::InvalidAnnotation)
+        );
+        assert_eq!(
+            f("2021-02-03T04:05:06+09:00[u-ca=]"),
+            Err(ParseExtendedDateTimeError::InvalidAnnotation)
+        );
+        assert_eq!(
+            f("2021-02-03T04:05:06+09:00[Asia/../Tokyo]"),
+            Err(ParseExtendedDateTimeError::InvalidTimeZone)
+        );
+        assert_eq!(
+            f("2021-02-03T04:05:06+09:00[+9]"),
+            Err(ParseExtendedDateTimeError::InvalidTimeZone)
+        );
+        assert_eq!(
+            f("2021-02-03T04:05:06+09:00[+08:00]"),
+            Err(ParseExtendedDateTimeError::OffsetMismatch)
+        );
+        assert_eq!(
+            f("2021-02-03T04:05:06+09:00[!u-ca=japanese]"),
+            Err(ParseExtendedDateTimeError::UnsupportedCriticalAnnotation(
+                "u-ca".to_string()
+            ))
+        );
+        assert_eq!(
+            f("2021-02-03T04:05:06+09:00[!foo=bar]"),
+            Err(ParseExtendedDateTimeError::UnsupportedCriticalAnnotation(
+                "foo".to_string()
+            ))
+        );
+        assert_eq!(
+            f("2021-02-03T04:05:06+09:00[!u-ca=iso8601][u-ca=japanese]"),
+            Err(ParseExtendedDateTimeError::ConflictingAnnotations(
+                "u-ca".to_string()
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn accessors_test() -> anyhow::Result<()> {
+        let extended_date_time = ExtendedDateTime::from_str(
+            "2021-02-03T04:05:06+09:00[!Asia/Tokyo][u-ca=japanese][foo=bar]",
+        )?;
+        assert_eq!(
+            extended_date_time.offset_date_time(),
+            OffsetDateTime::from_str("2021-02-03T04:05:06+09:00")?
+        );
+        assert_eq!(
+            extended_date_time.time_zone(),
+            Some(&TimeZoneAnnotation::Name("Asia/Tokyo".to_string()))
+        );
+        assert!(extended_date_time.is_time_zone_critical());
+        assert_eq!(extended_date_time.calendar(), Some("japanese"));
+        let annotations = extended_date_time.annotations();
+        assert_eq!(annotations.len(), 2);
+        assert!(!annotations[1].is_critical());
+        assert_eq!(annotations[1].key(), "foo");
+        assert_eq!(annotations[1].value(), "bar");
+
+        let extended_date_time = ExtendedDateTime::from_str("2021-02-03T04:05:06+09:00")?;
+        assert_eq!(extended_date_time.time_zone(), None);
+        assert!(!extended_date_time.is_time_zone_critical());
+        assert_eq!(extended_date_time.calendar(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn to_zoned_date_time_test() -> anyhow::Result<()> {
+        let f = |s: &str| -> anyhow::Result<Result<String, ResolveExtendedDateTimeError>> {
+            Ok(ExtendedDateTime::from_str(s)?
+                .to_zoned_date_time(tokyo()?)
+                .map(|zoned_date_time| zoned_date_time.to_string()))
+        };
+        assert_eq!(
+            f("2021-02-03T04:05:06+09:00[Asia/Tokyo][u-ca=japanese]")?,
+            Ok("2021-02-03T04:05:06+09:00[Asia/Tokyo]".to_string())
+        );
+        assert_eq!(
+            f("2021-02-03T04:05:06+09:00")?,
+            Ok("2021-02-03T04:05:06+09:00[Asia/Tokyo]".to_string())
+        );
+        assert_eq!(
+            f("2021-02-02T19:05:06Z[Asia/Tokyo]")?,
+            Ok("2021-02-03T04:05:06+09:00[Asia/Tokyo]".to_string())
+        );
+        assert_eq!(
+            f("2021-02-03T04:05:06+08:00[Asia/Tokyo]")?,
+            Err(ResolveExtendedDateTimeError::OffsetMismatch)
+        );
+        assert_eq!(
+            f("2021-02-03T04:05:06+09:00[Asia/Seoul]")?,
+            Err(ResolveExtendedDateTimeError::TimeZoneMismatch)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_test() -> anyhow::Result<()> {
+        let offset_date_time = OffsetDateTime::from_str("2021-02-03T04:05:06+09:00")?;
+        assert_eq!(
+            ExtendedDateTime::from(offset_date_time).to_string(),
+            "2021-02-03T04:05:06+09:00"
+        );
+        let zoned_date_time = ZonedDateTime::from_instant(offset_date_time.instant(), tokyo()?)?;
+        assert_eq!(
+            ExtendedDateTime::from(zoned_date_time).to_string(),
+            "2021-02-03T04:05:06+09:00[Asia/Tokyo]"
+        );
+        Ok(())
+    }
+}
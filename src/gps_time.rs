@@ -0,0 +1,211 @@
+use std::convert::TryFrom;
+
+use thiserror::Error;
+
+use crate::{
+    Instant, LeapSecondDateTime, LeapSecondPolicy, Nanosecond, PreciseInstant, TaiInstant,
+};
+
+// GPS time (the week number and the seconds of the week from 1980-01-06T00:00:00Z)
+// GPS time = TAI - 19 seconds (no leap seconds)
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct GpsTime {
+    // the full week number (not modulo 1024)
+    week: u32,
+    seconds_of_week: u32,
+    nanosecond: Nanosecond,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum TryFromGpsTimeError {
+    #[error("out of range")]
+    OutOfRange,
+}
+
+// 1980-01-06T00:00:19 TAI
+const GPS_EPOCH_TAI_SECONDS: u64 = 315_964_819;
+
+const SECONDS_PER_WEEK: u32 = 604_800;
+
+impl GpsTime {
+    pub fn new(
+        week: u32,
+        seconds_of_week: u32,
+        nanosecond: Nanosecond,
+    ) -> Result<Self, TryFromGpsTimeError> {
+        if seconds_of_week >= SECONDS_PER_WEEK {
+            return Err(TryFromGpsTimeError::OutOfRange);
+        }
+        let seconds = tai_seconds(week, seconds_of_week);
+        TaiInstant::new(seconds, nanosecond).map_err(|_| TryFromGpsTimeError::OutOfRange)?;
+        Ok(Self {
+            week,
+            seconds_of_week,
+            nanosecond,
+        })
+    }
+
+    pub fn nanosecond(&self) -> Nanosecond {
+        self.nanosecond
+    }
+
+    pub fn seconds_of_week(&self) -> u32 {
+        self.seconds_of_week
+    }
+
+    pub fn week(&self) -> u32 {
+        self.week
+    }
+
+    // UTC (e.g. 2016-12-31T23:59:60Z)
+    pub fn to_leap_second_date_time(&self) -> LeapSecondDateTime {
+        TaiInstant::from(*self).to_leap_second_date_time()
+    }
+
+    // the leap second is mapped by policy
+    pub fn to_precise_instant(&self, policy: LeapSecondPolicy) -> PreciseInstant {
+        TaiInstant::from(*self).to_precise_instant(policy)
+    }
+}
+
+fn tai_seconds(week: u32, seconds_of_week: u32) -> u64 {
+    GPS_EPOCH_TAI_SECONDS
+        + u64::from(week) * u64::from(SECONDS_PER_WEEK)
+        + u64::from(seconds_of_week)
+}
+
+impl From<GpsTime> for TaiInstant {
+    fn from(gps_time: GpsTime) -> Self {
+        let seconds = tai_seconds(gps_time.week, gps_time.seconds_of_week);
+        TaiInstant::new(seconds, gps_time.nanosecond).expect("GpsTime is in range")
+    }
+}
+
+impl TryFrom<Instant> for GpsTime {
+    type Error = TryFromGpsTimeError;
+
+    fn try_from(instant: Instant) -> Result<Self, Self::Error> {
+        Self::try_from(PreciseInstant::from(instant))
+    }
+}
+
+impl TryFrom<LeapSecondDateTime> for GpsTime {
+    type Error = TryFromGpsTimeError;
+
+    fn try_from(leap_second_date_time: LeapSecondDateTime) -> Result<Self, Self::Error> {
+        TaiInstant::try_from(leap_second_date_time)
+            .map_err(|_| TryFromGpsTimeError::OutOfRange)
+            .and_then(Self::try_from)
+    }
+}
+
+impl TryFrom<PreciseInstant> for GpsTime {
+    type Error = TryFromGpsTimeError;
+
+    fn try_from(precise_instant: PreciseInstant) -> Result<Self, Self::Error> {
+        TaiInstant::try_from(precise_instant)
+            .map_err(|_| TryFromGpsTimeError::OutOfRange)
+            .and_then(Self::try_from)
+    }
+}
+
+impl TryFrom<TaiInstant> for GpsTime {
+    type Error = TryFromGpsTimeError;
+
+    fn try_from(tai_instant: TaiInstant) -> Result<Self, Self::Error> {
+        let seconds = tai_instant
+            .seconds()
+            .checked_sub(GPS_EPOCH_TAI_SECONDS)
+            .ok_or(TryFromGpsTimeError::OutOfRange)?;
+        let week = u32::try_from(seconds / u64::from(SECONDS_PER_WEEK))
+            .map_err(|_| TryFromGpsTimeError::OutOfRange)?;
+        let seconds_of_week = u32::try_from(seconds % u64::from(SECONDS_PER_WEEK))
+            .expect("seconds of week is [0, 604800)");
+        Ok(Self {
+            week,
+            seconds_of_week,
+            nanosecond: tai_instant.nanosecond(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn new_test() -> anyhow::Result<()> {
+        type E = TryFromGpsTimeError;
+        assert!(GpsTime::new(0, 0, Nanosecond::min()).is_ok());
+        assert!(GpsTime::new(0, 604_799, Nanosecond::max()).is_ok());
+        assert_eq!(
+            GpsTime::new(0, 604_800, Nanosecond::min()),
+            Err(E::OutOfRange)
+        );
+        assert_eq!(
+            GpsTime::new(u32::MAX, 0, Nanosecond::min()),
+            Err(E::OutOfRange)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn precise_instant_conversion_test() -> anyhow::Result<()> {
+        let f = |s: &str| -> anyhow::Result<(u32, u32)> {
+            let gps_time = GpsTime::try_from(PreciseInstant::from_str(s)?)?;
+            Ok((gps_time.week(), gps_time.seconds_of_week()))
+        };
+        assert_eq!(f("1980-01-06T00:00:00Z")?, (0, 0));
+        assert_eq!(f("1999-08-21T23:59:46.5Z")?, (1023, 604_799));
+        // the first week number rollover (modulo 1024)
+        assert_eq!(f("1999-08-21T23:59:47Z")?, (1024, 0));
+        // 18 leap seconds since the GPS epoch
+        assert_eq!(f("2017-01-01T00:00:00Z")?, (1930, 18));
+        assert_eq!(f("2021-02-03T04:05:06Z")?, (2143, 273_924));
+        assert_eq!(
+            GpsTime::try_from(Instant::from_str("1980-01-05T23:59:59Z")?),
+            Err(TryFromGpsTimeError::OutOfRange)
+        );
+
+        let gps_time = GpsTime::new(2143, 273_924, Nanosecond::try_from(500_000_000_u32)?)?;
+        assert_eq!(
+            gps_time
+                .to_precise_instant(LeapSecondPolicy::CollapseToPrevious)
+                .to_string(),
+            "2021-02-03T04:05:06.500Z"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn leap_second_test() -> anyhow::Result<()> {
+        let leap_second = LeapSecondDateTime::from_str("2016-12-31T23:59:60Z")?;
+        let gps_time = GpsTime::try_from(leap_second)?;
+        assert_eq!((gps_time.week(), gps_time.seconds_of_week()), (1930, 17));
+        assert_eq!(gps_time.to_leap_second_date_time(), leap_second);
+        assert_eq!(
+            gps_time
+                .to_precise_instant(LeapSecondPolicy::CollapseToNext)
+                .to_string(),
+            "2017-01-01T00:00:00Z"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn tai_instant_conversion_test() -> anyhow::Result<()> {
+        let tai_instant = TaiInstant::from(GpsTime::new(0, 0, Nanosecond::min())?);
+        assert_eq!(tai_instant.seconds(), 315_964_819);
+        assert_eq!(
+            GpsTime::try_from(tai_instant)?,
+            GpsTime::new(0, 0, Nanosecond::min())?
+        );
+        assert_eq!(
+            GpsTime::try_from(TaiInstant::new(315_964_818, Nanosecond::min())?),
+            Err(TryFromGpsTimeError::OutOfRange)
+        );
+        Ok(())
+    }
+}
@@ -1,12 +1,15 @@
 use std::{
     convert::TryFrom,
-    ops::Add,
-    time::{SystemTime, UNIX_EPOCH},
+    ops::{Add, Sub},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use thiserror::Error;
 
-use crate::{private::date_time_string_from_seconds_from_unix_epoch, DateTime, Days, Seconds};
+use crate::{
+    private::date_time_string_from_seconds_from_unix_epoch, Clock, ClockError, DateTime, Days,
+    Nanosecond, RoundingMode, Seconds, SignedDays, SignedSeconds, SystemClock,
+};
 
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Instant(u64);
@@ -22,6 +25,19 @@ impl Instant {
         Instant::try_from(u64::from(seconds))
     }
 
+    // the fraction of a second is rounded by mode
+    pub fn from_system_time(
+        system_time: SystemTime,
+        mode: RoundingMode,
+    ) -> Result<Self, TryFromInstantError> {
+        let duration = system_time
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| TryFromInstantError::OutOfRange)?;
+        let seconds =
+            Seconds::from_duration(duration, mode).map_err(|_| TryFromInstantError::OutOfRange)?;
+        Self::from_seconds(seconds)
+    }
+
     pub fn max() -> Self {
         MAX
     }
@@ -30,16 +46,19 @@ impl Instant {
         MIN
     }
 
+    // panics if the system clock is out of range (see Instant::try_now)
     pub fn now() -> Self {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("before unix epoch")
-            .as_secs();
-        if (0..=u64::from(Self::max())).contains(&timestamp) {
-            Self(timestamp)
-        } else {
-            panic!("out of range")
-        }
+        Self::try_now().expect("out of range")
+    }
+
+    pub fn now_in<C: Clock + ?Sized>(clock: &C) -> Result<Self, ClockError> {
+        clock
+            .now()
+            .map(|precise_instant| precise_instant.truncate())
+    }
+
+    pub fn try_now() -> Result<Self, ClockError> {
+        Self::now_in(&SystemClock)
     }
 
     pub fn as_seconds(&self) -> Seconds {
@@ -80,6 +99,10 @@ impl std::str::FromStr for Instant {
             .strip_suffix('Z')
             .ok_or(ParseInstantError::InvalidFormat)?;
         let date_time = DateTime::from_str(s).map_err(|_| ParseInstantError::InvalidFormat)?;
+        // whole seconds only
+        if date_time.time().nanosecond() != Nanosecond::min() {
+            return Err(ParseInstantError::InvalidFormat);
+        }
         let days_from_unix_epoch = date_time.date().days_from_unix_epoch();
         let seconds_from_midnight = date_time.time().seconds_from_midnight();
         // TODO: use SECONDS_PER_DAY instead of 86_400
@@ -138,6 +161,21 @@ impl std::convert::TryFrom<u64> for Instant {
     }
 }
 
+// the fraction of a second is truncated (see Instant::from_system_time)
+impl std::convert::TryFrom<SystemTime> for Instant {
+    type Error = TryFromInstantError;
+
+    fn try_from(value: SystemTime) -> Result<Self, Self::Error> {
+        Self::from_system_time(value, RoundingMode::Floor)
+    }
+}
+
+impl From<Instant> for SystemTime {
+    fn from(value: Instant) -> Self {
+        UNIX_EPOCH + Duration::from_secs(value.0)
+    }
+}
+
 impl From<Instant> for i64 {
     fn from(instant: Instant) -> Self {
         instant.0 as i64
@@ -185,10 +223,74 @@ impl Add<Seconds> for Instant {
     }
 }
 
+impl Add<SignedDays> for Instant {
+    type Output = Instant;
+
+    fn add(self, rhs: SignedDays) -> Self::Output {
+        self + SignedSeconds::from(rhs)
+    }
+}
+
+impl Add<SignedSeconds> for Instant {
+    type Output = Instant;
+
+    fn add(self, rhs: SignedSeconds) -> Self::Output {
+        i64::from(self)
+            .checked_add(i64::from(rhs))
+            .and_then(|s| Instant::try_from(s).ok())
+            .unwrap_or_else(|| panic!("overflow"))
+    }
+}
+
+impl Sub<Days> for Instant {
+    type Output = Instant;
+
+    fn sub(self, rhs: Days) -> Self::Output {
+        self - Seconds::from(rhs)
+    }
+}
+
+impl Sub<Seconds> for Instant {
+    type Output = Instant;
+
+    fn sub(self, rhs: Seconds) -> Self::Output {
+        self.0
+            .checked_sub(u64::from(rhs))
+            .and_then(|s| Instant::try_from(s).ok())
+            .unwrap_or_else(|| panic!("overflow"))
+    }
+}
+
+impl Sub<SignedDays> for Instant {
+    type Output = Instant;
+
+    fn sub(self, rhs: SignedDays) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Sub<SignedSeconds> for Instant {
+    type Output = Instant;
+
+    fn sub(self, rhs: SignedSeconds) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Sub<Instant> for Instant {
+    type Output = SignedSeconds;
+
+    fn sub(self, rhs: Instant) -> Self::Output {
+        SignedSeconds::from(i64::from(self) - i64::from(rhs))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
+    use crate::{ManualClock, PreciseInstant};
+
     use super::*;
 
     #[test]
@@ -196,6 +298,69 @@ mod tests {
         assert_ne!(Instant::now().to_string(), "");
     }
 
+    #[test]
+    fn now_in_test() -> anyhow::Result<()> {
+        let clock = ManualClock::new(PreciseInstant::from_str("2021-02-03T04:05:06.789Z")?);
+        assert_eq!(
+            Instant::now_in(&clock)?,
+            Instant::from_str("2021-02-03T04:05:06Z")?
+        );
+        let clock: Box<dyn Clock> = Box::new(clock);
+        assert_eq!(
+            Instant::now_in(clock.as_ref())?,
+            Instant::from_str("2021-02-03T04:05:06Z")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn system_time_conversion_test() -> anyhow::Result<()> {
+        let t = |secs: u64, millis: u64| {
+            UNIX_EPOCH + Duration::from_secs(secs) + Duration::from_millis(millis)
+        };
+        let f = |system_time: SystemTime| Instant::try_from(system_time).map(u64::from);
+        assert_eq!(f(t(0, 0)), Ok(0));
+        assert_eq!(f(t(1_612_325_106, 999)), Ok(1_612_325_106));
+        assert_eq!(f(t(253_402_300_799, 999)), Ok(253_402_300_799));
+        assert_eq!(
+            f(t(253_402_300_800, 0)),
+            Err(TryFromInstantError::OutOfRange)
+        );
+        assert_eq!(
+            f(UNIX_EPOCH - Duration::from_secs(1)),
+            Err(TryFromInstantError::OutOfRange)
+        );
+
+        type M = RoundingMode;
+        let g = |system_time: SystemTime, mode: M| {
+            Instant::from_system_time(system_time, mode).map(u64::from)
+        };
+        assert_eq!(g(t(1_612_325_106, 500), M::Ceil), Ok(1_612_325_107));
+        assert_eq!(g(t(1_612_325_106, 500), M::Floor), Ok(1_612_325_106));
+        assert_eq!(g(t(1_612_325_106, 499), M::Round), Ok(1_612_325_106));
+        assert_eq!(g(t(1_612_325_106, 500), M::Round), Ok(1_612_325_107));
+        assert_eq!(
+            g(t(253_402_300_799, 1), M::Ceil),
+            Err(TryFromInstantError::OutOfRange)
+        );
+
+        let instant = Instant::from_str("2021-02-03T04:05:06Z")?;
+        assert_eq!(SystemTime::from(instant), t(1_612_325_106, 0));
+        assert_eq!(
+            Instant::try_from(SystemTime::from(Instant::max()))?,
+            Instant::max()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn try_now_test() -> anyhow::Result<()> {
+        let before = Instant::now();
+        let now = Instant::try_now()?;
+        assert!(before <= now && now <= Instant::now());
+        Ok(())
+    }
+
     #[test]
     fn i64_conversion_test() -> anyhow::Result<()> {
         // Instant -> i64
@@ -299,6 +464,11 @@ mod tests {
         assert_eq!(Instant::from_str("1970-01-01T00:00:00Z")?, Instant::min());
         assert_eq!(Instant::from_str("9999-12-31T23:59:59Z")?, Instant::max());
         assert!(Instant::from_str("1970-01-01T00:00:00").is_err());
+        assert!(Instant::from_str("1970-01-01T00:00:00.5Z").is_err());
+        assert_eq!(
+            Instant::from_str("1970-01-01T00:00:00.000Z")?,
+            Instant::min()
+        );
         assert_eq!(
             Instant::from_str("1970-01-01T00:00:00Z")?.to_string(),
             "1970-01-01T00:00:00Z"
@@ -328,4 +498,97 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn add_signed_days_test() -> anyhow::Result<()> {
+        let seconds_per_day = 24_i64 * 60_i64 * 60_i64;
+        assert_eq!(
+            Instant::try_from(seconds_per_day)? + SignedDays::from(1_i8),
+            Instant::try_from(seconds_per_day * 2)?
+        );
+        assert_eq!(
+            Instant::try_from(seconds_per_day)? + SignedDays::from(-1_i8),
+            Instant::min()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn add_signed_seconds_test() -> anyhow::Result<()> {
+        assert_eq!(
+            Instant::try_from(1_u64)? + SignedSeconds::from(2_i64),
+            Instant::try_from(3_u64)?
+        );
+        assert_eq!(
+            Instant::try_from(3_u64)? + SignedSeconds::from(-2_i64),
+            Instant::try_from(1_u64)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sub_days_test() -> anyhow::Result<()> {
+        let seconds_per_day = 24_u64 * 60_u64 * 60_u64;
+        assert_eq!(
+            Instant::try_from(1_u64 + seconds_per_day)? - Days::from(1_u8),
+            Instant::try_from(1_u64)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sub_seconds_test() -> anyhow::Result<()> {
+        assert_eq!(
+            Instant::try_from(3_u64)? - Seconds::from(2_u64),
+            Instant::try_from(1_u64)?
+        );
+        assert_eq!(
+            Instant::max() - Seconds::from(u64::from(Instant::max())),
+            Instant::min()
+        );
+        // should_panic
+        // Instant::min() - Seconds::from(1_u64)
+        Ok(())
+    }
+
+    #[test]
+    fn sub_signed_days_test() -> anyhow::Result<()> {
+        let seconds_per_day = 24_i64 * 60_i64 * 60_i64;
+        assert_eq!(
+            Instant::try_from(seconds_per_day)? - SignedDays::from(1_i8),
+            Instant::min()
+        );
+        assert_eq!(
+            Instant::try_from(seconds_per_day)? - SignedDays::from(-1_i8),
+            Instant::try_from(seconds_per_day * 2)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sub_signed_seconds_test() -> anyhow::Result<()> {
+        assert_eq!(
+            Instant::try_from(3_u64)? - SignedSeconds::from(2_i64),
+            Instant::try_from(1_u64)?
+        );
+        assert_eq!(
+            Instant::try_from(1_u64)? - SignedSeconds::from(-2_i64),
+            Instant::try_from(3_u64)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sub_instant_test() -> anyhow::Result<()> {
+        let i1 = Instant::from_str("2021-02-03T04:05:06Z")?;
+        let i2 = Instant::from_str("2021-02-03T04:05:09Z")?;
+        assert_eq!(i2 - i1, SignedSeconds::from(3_i64));
+        assert_eq!(i1 - i2, SignedSeconds::from(-3_i64));
+        assert_eq!(i1 - i1, SignedSeconds::from(0_i64));
+        assert_eq!(
+            Instant::max() - Instant::min(),
+            SignedSeconds::from(253_402_300_799_i64)
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,450 @@
+use std::convert::TryFrom;
+
+use crate::{Instant, OffsetDateTime, ParseOffsetDateTimeError, ParsePeriodError, Period, Seconds};
+
+use thiserror::Error;
+
+// ISO 8601 time interval
+//
+// <start>/<end>       (e.g. 2021-02-03T00:00:00Z/2021-02-04T00:00:00Z)
+// <start>/<duration>  (e.g. 2021-02-03T00:00:00Z/P1D)
+// <duration>/<end>    (e.g. P1D/2021-02-04T00:00:00Z)
+// <duration>          (e.g. P1D)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Interval {
+    start: Option<OffsetDateTime>,
+    end: Option<OffsetDateTime>,
+    period: Option<Period>,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParseIntervalError {
+    #[error("invalid format")]
+    InvalidFormat,
+    #[error("invalid interval")]
+    InvalidInterval,
+    #[error("parse offset date time")]
+    ParseOffsetDateTime(ParseOffsetDateTimeError),
+    #[error("parse period")]
+    ParsePeriod(ParsePeriodError),
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+#[error("invalid interval error")]
+pub struct InvalidIntervalError;
+
+impl Interval {
+    pub fn from_start_end(
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+    ) -> Result<Self, InvalidIntervalError> {
+        if start.instant() > end.instant() {
+            return Err(InvalidIntervalError);
+        }
+        Ok(Self {
+            start: Some(start),
+            end: Some(end),
+            period: None,
+        })
+    }
+
+    pub fn from_start_period(
+        start: OffsetDateTime,
+        period: Period,
+    ) -> Result<Self, InvalidIntervalError> {
+        start
+            .checked_add_period(period)
+            .ok_or(InvalidIntervalError)?;
+        Ok(Self {
+            start: Some(start),
+            end: None,
+            period: Some(period),
+        })
+    }
+
+    pub fn from_period_end(
+        period: Period,
+        end: OffsetDateTime,
+    ) -> Result<Self, InvalidIntervalError> {
+        end.checked_sub_period(period).ok_or(InvalidIntervalError)?;
+        Ok(Self {
+            start: None,
+            end: Some(end),
+            period: Some(period),
+        })
+    }
+
+    pub fn from_period(period: Period) -> Self {
+        Self {
+            start: None,
+            end: None,
+            period: Some(period),
+        }
+    }
+
+    // None if <duration>
+    pub fn start(&self) -> Option<OffsetDateTime> {
+        match (self.start, self.end, self.period) {
+            (Some(start), _, _) => Some(start),
+            (None, Some(end), Some(period)) => {
+                Some(end.checked_sub_period(period).expect("Interval is broken"))
+            }
+            _ => None,
+        }
+    }
+
+    // None if <duration>
+    pub fn end(&self) -> Option<OffsetDateTime> {
+        match (self.start, self.end, self.period) {
+            (_, Some(end), _) => Some(end),
+            (Some(start), None, Some(period)) => Some(
+                start
+                    .checked_add_period(period)
+                    .expect("Interval is broken"),
+            ),
+            _ => None,
+        }
+    }
+
+    // None if <start>/<end>
+    pub fn period(&self) -> Option<Period> {
+        self.period
+    }
+
+    // [start, end)
+    pub fn contains(&self, instant: Instant) -> bool {
+        match (self.start(), self.end()) {
+            (Some(start), Some(end)) => start.instant() <= instant && instant < end.instant(),
+            _ => false,
+        }
+    }
+
+    // start, start + step, start + step * 2, ... (< end)
+    pub fn step_by(&self, step: Period) -> IntervalIter {
+        match (self.start(), self.end()) {
+            (Some(start), Some(end)) => {
+                IntervalIter::new(start, step, false, None, Some(end.instant()))
+            }
+            _ => IntervalIter::empty(),
+        }
+    }
+}
+
+impl std::fmt::Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.start, self.end, self.period) {
+            (Some(start), Some(end), _) => write!(f, "{}/{}", start, end),
+            (Some(start), None, Some(period)) => write!(f, "{}/{}", start, period),
+            (None, Some(end), Some(period)) => write!(f, "{}/{}", period, end),
+            (None, None, Some(period)) => write!(f, "{}", period),
+            _ => unreachable!("Interval is broken"),
+        }
+    }
+}
+
+impl std::str::FromStr for Interval {
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_period = |s: &str| Period::from_str(s).map_err(Self::Err::ParsePeriod);
+        let parse_offset_date_time =
+            |s: &str| OffsetDateTime::from_str(s).map_err(Self::Err::ParseOffsetDateTime);
+        let (first, second) = match s.split_once('/') {
+            None => return Ok(Self::from_period(parse_period(s)?)),
+            Some(parts) => parts,
+        };
+        if second.contains('/') {
+            return Err(Self::Err::InvalidFormat);
+        }
+        match (first.starts_with('P'), second.starts_with('P')) {
+            (false, false) => Self::from_start_end(
+                parse_offset_date_time(first)?,
+                parse_offset_date_time(second)?,
+            ),
+            (false, true) => {
+                Self::from_start_period(parse_offset_date_time(first)?, parse_period(second)?)
+            }
+            (true, false) => {
+                Self::from_period_end(parse_period(first)?, parse_offset_date_time(second)?)
+            }
+            (true, true) => return Err(Self::Err::InvalidFormat),
+        }
+        .map_err(|_| Self::Err::InvalidInterval)
+    }
+}
+
+// a sequence of OffsetDateTime (stops before the first out of range value)
+#[derive(Clone, Debug)]
+pub struct IntervalIter {
+    anchor: Option<OffsetDateTime>,
+    step: Period,
+    backward: bool,
+    index: u32,
+    remaining: Option<u32>,
+    until: Option<Instant>,
+}
+
+impl IntervalIter {
+    // anchor + step * index (or anchor - step * index if backward)
+    pub(crate) fn new(
+        anchor: OffsetDateTime,
+        step: Period,
+        backward: bool,
+        remaining: Option<u32>,
+        until: Option<Instant>,
+    ) -> Self {
+        Self {
+            anchor: Some(anchor),
+            step,
+            backward,
+            index: if backward { 1 } else { 0 },
+            remaining,
+            until,
+        }
+    }
+
+    pub(crate) fn empty() -> Self {
+        Self {
+            anchor: None,
+            step: Period::zero(),
+            backward: false,
+            index: 0,
+            remaining: Some(0),
+            until: None,
+        }
+    }
+
+    // <start>/<end> repeats by the exact seconds between start and end
+    pub(crate) fn from_interval(interval: &Interval, remaining: Option<u32>) -> Self {
+        match (interval.start, interval.end, interval.period) {
+            (Some(start), Some(end), _) => {
+                let seconds = u64::try_from(i64::from(end.instant() - start.instant()))
+                    .expect("start <= end");
+                Self::new(
+                    start,
+                    Period::from(Seconds::from(seconds)),
+                    false,
+                    remaining,
+                    None,
+                )
+            }
+            (Some(start), None, Some(period)) => Self::new(start, period, false, remaining, None),
+            (None, Some(end), Some(period)) => Self::new(end, period, true, remaining, None),
+            _ => Self::empty(),
+        }
+    }
+
+    pub fn instants(self) -> impl Iterator<Item = Instant> {
+        self.map(|offset_date_time| offset_date_time.instant())
+    }
+}
+
+impl Iterator for IntervalIter {
+    type Item = OffsetDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let anchor = self.anchor?;
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let value = self.step.checked_mul(self.index).and_then(|step| {
+            if self.backward {
+                anchor.checked_sub_period(step)
+            } else {
+                anchor.checked_add_period(step)
+            }
+        });
+        let value = match (value, self.until) {
+            (Some(value), Some(until)) if value.instant() >= until => None,
+            (value, _) => value,
+        };
+        // a zero step without a count never ends
+        if value.is_none() || (self.step.is_zero() && self.remaining.is_none()) {
+            self.anchor = None;
+        }
+        match self.index.checked_add(1) {
+            Some(index) => self.index = index,
+            None => self.anchor = None,
+        }
+        self.remaining = self.remaining.map(|remaining| remaining - 1);
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn from_start_end_test() -> anyhow::Result<()> {
+        let odt = |s: &str| OffsetDateTime::from_str(s);
+        let interval = Interval::from_start_end(
+            odt("2021-02-03T00:00:00Z")?,
+            odt("2021-02-04T09:00:00+09:00")?,
+        )?;
+        assert_eq!(interval.start(), Some(odt("2021-02-03T00:00:00Z")?));
+        assert_eq!(interval.end(), Some(odt("2021-02-04T09:00:00+09:00")?));
+        assert_eq!(interval.period(), None);
+        assert!(Interval::from_start_end(
+            odt("2021-02-03T09:00:00+09:00")?,
+            odt("2021-02-03T00:00:00Z")?
+        )
+        .is_ok());
+        assert_eq!(
+            Interval::from_start_end(odt("2021-02-04T00:00:00Z")?, odt("2021-02-03T00:00:00Z")?),
+            Err(InvalidIntervalError)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_start_period_test() -> anyhow::Result<()> {
+        let odt = |s: &str| OffsetDateTime::from_str(s);
+        let interval =
+            Interval::from_start_period(odt("2021-01-31T00:00:00Z")?, Period::from_str("P1M")?)?;
+        assert_eq!(interval.start(), Some(odt("2021-01-31T00:00:00Z")?));
+        assert_eq!(interval.end(), Some(odt("2021-02-28T00:00:00Z")?));
+        assert_eq!(interval.period(), Some(Period::from_str("P1M")?));
+        assert_eq!(
+            Interval::from_start_period(odt("9999-12-31T00:00:00Z")?, Period::from_str("P1D")?),
+            Err(InvalidIntervalError)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_period_end_test() -> anyhow::Result<()> {
+        let odt = |s: &str| OffsetDateTime::from_str(s);
+        let interval =
+            Interval::from_period_end(Period::from_str("PT1H")?, odt("2021-02-03T00:00:00Z")?)?;
+        assert_eq!(interval.start(), Some(odt("2021-02-02T23:00:00Z")?));
+        assert_eq!(interval.end(), Some(odt("2021-02-03T00:00:00Z")?));
+        assert_eq!(interval.period(), Some(Period::from_str("PT1H")?));
+        assert_eq!(
+            Interval::from_period_end(Period::from_str("P1D")?, odt("1970-01-01T00:00:00Z")?),
+            Err(InvalidIntervalError)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_period_test() -> anyhow::Result<()> {
+        let interval = Interval::from_period(Period::from_str("P1D")?);
+        assert_eq!(interval.start(), None);
+        assert_eq!(interval.end(), None);
+        assert_eq!(interval.period(), Some(Period::from_str("P1D")?));
+        Ok(())
+    }
+
+    #[test]
+    fn contains_test() -> anyhow::Result<()> {
+        let interval = Interval::from_str("2021-02-03T00:00:00Z/PT1H")?;
+        let i = |s: &str| -> anyhow::Result<Instant> { Ok(OffsetDateTime::from_str(s)?.instant()) };
+        assert!(!interval.contains(i("2021-02-02T23:59:59Z")?));
+        assert!(interval.contains(i("2021-02-03T00:00:00Z")?));
+        assert!(interval.contains(i("2021-02-03T00:59:59Z")?));
+        assert!(!interval.contains(i("2021-02-03T01:00:00Z")?));
+        assert!(!Interval::from_str("PT1H")?.contains(i("2021-02-03T00:00:00Z")?));
+        Ok(())
+    }
+
+    #[test]
+    fn step_by_test() -> anyhow::Result<()> {
+        let f = |s: &str, step: &str| -> anyhow::Result<Vec<String>> {
+            Ok(Interval::from_str(s)?
+                .step_by(Period::from_str(step)?)
+                .map(|odt| odt.to_string())
+                .collect())
+        };
+        assert_eq!(
+            f("2021-02-03T00:00:00+09:00/P3D", "P1D")?,
+            vec![
+                "2021-02-03T00:00:00+09:00",
+                "2021-02-04T00:00:00+09:00",
+                "2021-02-05T00:00:00+09:00"
+            ]
+        );
+        assert_eq!(
+            f("2021-01-31T00:00:00Z/2021-05-01T00:00:00Z", "P1M")?,
+            vec![
+                "2021-01-31T00:00:00Z",
+                "2021-02-28T00:00:00Z",
+                "2021-03-31T00:00:00Z",
+                "2021-04-30T00:00:00Z"
+            ]
+        );
+        assert_eq!(
+            f("9999-12-31T22:00:00Z/9999-12-31T23:59:59Z", "PT1H")?,
+            vec!["9999-12-31T22:00:00Z", "9999-12-31T23:00:00Z"]
+        );
+        assert_eq!(
+            f("2021-02-03T00:00:00Z/P1D", "PT0S")?,
+            vec!["2021-02-03T00:00:00Z"]
+        );
+        assert!(f("P1D", "PT1H")?.is_empty());
+        let instants = Interval::from_str("1970-01-01T00:00:00Z/PT3M")?
+            .step_by(Period::from_str("PT1M")?)
+            .instants()
+            .collect::<Vec<Instant>>();
+        assert_eq!(
+            instants,
+            vec![
+                Instant::from(0_u8),
+                Instant::from(60_u8),
+                Instant::from(120_u8)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        type E = ParseIntervalError;
+        let f = |s: &str| Interval::from_str(s);
+
+        assert_eq!(
+            f("2021-02-03T00:00:00Z/2021-02-04T00:00:00+09:00")?.to_string(),
+            "2021-02-03T00:00:00Z/2021-02-04T00:00:00+09:00"
+        );
+        assert_eq!(
+            f("2021-02-03T00:00:00Z/P1DT2H")?.to_string(),
+            "2021-02-03T00:00:00Z/P1DT2H"
+        );
+        assert_eq!(
+            f("P1DT2H/2021-02-03T00:00:00Z")?.to_string(),
+            "P1DT2H/2021-02-03T00:00:00Z"
+        );
+        assert_eq!(f("P1DT2H")?.to_string(), "P1DT2H");
+
+        assert_eq!(f(""), Err(E::ParsePeriod(ParsePeriodError::InvalidFormat)));
+        assert_eq!(
+            f("/"),
+            Err(E::ParseOffsetDateTime(
+                ParseOffsetDateTimeError::InvalidLength
+            ))
+        );
+        assert_eq!(f("P1D/P1D"), Err(E::InvalidFormat));
+        assert!(f("CET-1CéST,M3.5.0,M10.5.0/3").is_err());
+        assert_eq!(
+            f("2021-02-03T00:00:00Z/P1D/2021-02-04T00:00:00Z"),
+            Err(E::InvalidFormat)
+        );
+        assert!(matches!(
+            f("2021-02-03T00:00:00Z/2021-02-04"),
+            Err(E::ParseOffsetDateTime(_))
+        ));
+        assert!(matches!(
+            f("2021-02-03T00:00:00Z/P1X"),
+            Err(E::ParsePeriod(_))
+        ));
+        assert!(matches!(f("2021-02-03T00:00:00Z"), Err(E::ParsePeriod(_))));
+        assert_eq!(
+            f("2021-02-04T00:00:00Z/2021-02-03T00:00:00Z"),
+            Err(E::InvalidInterval)
+        );
+        assert_eq!(f("9999-12-31T00:00:00Z/P1D"), Err(E::InvalidInterval));
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+use std::{convert::TryFrom, str::FromStr};
+
+use thiserror::Error;
+
+use crate::{Instant, LeapSecondTable, OffsetDateTime, ParseOffsetDateTimeError, PreciseInstant};
+
+// OffsetDateTime that may be a leap second (e.g. 2016-12-31T23:59:60Z)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LeapSecondDateTime {
+    // the second is 59 if leap_second
+    offset_date_time: OffsetDateTime,
+    leap_second: bool,
+}
+
+// how to map a leap second to Instant (UTC without leap seconds)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LeapSecondPolicy {
+    // 23:59:60.x -> 23:59:59.x
+    CollapseToPrevious,
+    // 23:59:60.x -> 00:00:00.x (POSIX time)
+    CollapseToNext,
+    // the 24 hours from noon to noon (UTC) around the leap second are slowed down evenly
+    // (e.g. 23:59:60.0 -> 23:59:59.500005786)
+    Smear,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParseLeapSecondDateTimeError {
+    #[error("not leap second")]
+    NotLeapSecond,
+    #[error("parse offset date time")]
+    ParseOffsetDateTime(ParseOffsetDateTimeError),
+}
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+impl LeapSecondDateTime {
+    // second 60 must be the leap second in table
+    pub fn parse_with_table(
+        s: &str,
+        table: &LeapSecondTable,
+    ) -> Result<Self, ParseLeapSecondDateTimeError> {
+        if s.get(17..19) != Some("60") {
+            return OffsetDateTime::from_str(s)
+                .map(Self::from)
+                .map_err(ParseLeapSecondDateTimeError::ParseOffsetDateTime);
+        }
+        let offset_date_time = OffsetDateTime::from_str(&format!("{}59{}", &s[..17], &s[19..]))
+            .map_err(ParseLeapSecondDateTimeError::ParseOffsetDateTime)?;
+        let next = Instant::try_from(i64::from(offset_date_time.instant()) + 1)
+            .map_err(|_| ParseLeapSecondDateTimeError::NotLeapSecond)?;
+        if !table.is_leap_second_before(next) {
+            return Err(ParseLeapSecondDateTimeError::NotLeapSecond);
+        }
+        Ok(Self {
+            offset_date_time,
+            leap_second: true,
+        })
+    }
+
+    // offset_date_time: the second before the leap second (23:59:59 in UTC)
+    pub(crate) fn from_second_before_leap_second(offset_date_time: OffsetDateTime) -> Self {
+        Self {
+            offset_date_time,
+            leap_second: true,
+        }
+    }
+
+    pub fn is_leap_second(&self) -> bool {
+        self.leap_second
+    }
+
+    // None if leap second
+    pub fn offset_date_time(&self) -> Option<OffsetDateTime> {
+        if self.leap_second {
+            None
+        } else {
+            Some(self.offset_date_time)
+        }
+    }
+
+    // see LeapSecondDateTime::to_precise_instant_with_table
+    pub fn to_precise_instant(&self, policy: LeapSecondPolicy) -> PreciseInstant {
+        self.to_precise_instant_with_table(policy, &LeapSecondTable::builtin())
+    }
+
+    // LeapSecondPolicy::Smear applies to the other seconds in the smear window too
+    pub fn to_precise_instant_with_table(
+        &self,
+        policy: LeapSecondPolicy,
+        table: &LeapSecondTable,
+    ) -> PreciseInstant {
+        let label = self.offset_date_time.precise_instant();
+        match policy {
+            LeapSecondPolicy::CollapseToPrevious => label,
+            LeapSecondPolicy::CollapseToNext if self.leap_second => label
+                .checked_add_nanos(NANOS_PER_SECOND)
+                .expect("the leap second is followed by a second"),
+            LeapSecondPolicy::CollapseToNext => label,
+            LeapSecondPolicy::Smear => {
+                let leap_second = match table.leap_second_near(i64::from(label.truncate())) {
+                    Some(leap_second) => leap_second,
+                    None => return label,
+                };
+                let next = i128::from(i64::from(leap_second)) * NANOS_PER_SECOND;
+                let start = next - 43_200 * NANOS_PER_SECOND;
+                // SI nanoseconds from the start of the smear window
+                let mut elapsed = label.unix_nanos() - start;
+                if self.leap_second || label.unix_nanos() >= next {
+                    elapsed += NANOS_PER_SECOND;
+                }
+                PreciseInstant::from_unix_nanos(start + elapsed * 86_400 / 86_401)
+                    .expect("the smeared instant is in the smear window")
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for LeapSecondDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = self.offset_date_time.to_string();
+        if self.leap_second {
+            write!(f, "{}60{}", &s[..17], &s[19..])
+        } else {
+            write!(f, "{}", s)
+        }
+    }
+}
+
+// the leap second must be in LeapSecondTable::builtin
+impl std::str::FromStr for LeapSecondDateTime {
+    type Err = ParseLeapSecondDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_table(s, &LeapSecondTable::builtin())
+    }
+}
+
+impl From<OffsetDateTime> for LeapSecondDateTime {
+    fn from(offset_date_time: OffsetDateTime) -> Self {
+        Self {
+            offset_date_time,
+            leap_second: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        type E = ParseLeapSecondDateTimeError;
+        let f = |s: &str| -> anyhow::Result<String> {
+            Ok(LeapSecondDateTime::from_str(s)?.to_string())
+        };
+        assert_eq!(f("2016-12-31T23:59:60Z")?, "2016-12-31T23:59:60Z");
+        assert_eq!(f("2016-12-31T23:59:60.5Z")?, "2016-12-31T23:59:60.500Z");
+        assert_eq!(f("2016-12-31T15:59:60-08:00")?, "2016-12-31T15:59:60-08:00");
+        assert_eq!(f("2015-07-01T08:59:60+09:00")?, "2015-07-01T08:59:60+09:00");
+        assert_eq!(f("2021-02-03T04:05:06+09:00")?, "2021-02-03T04:05:06+09:00");
+        let f = LeapSecondDateTime::from_str;
+        assert_eq!(f("2016-12-30T23:59:60Z"), Err(E::NotLeapSecond));
+        assert_eq!(f("2016-12-31T23:58:60Z"), Err(E::NotLeapSecond));
+        assert_eq!(f("2016-12-31T23:59:60+09:00"), Err(E::NotLeapSecond));
+        assert_eq!(f("9999-12-31T23:59:60Z"), Err(E::NotLeapSecond));
+        assert!(matches!(
+            f("2016-12-31T23:59:61Z"),
+            Err(E::ParseOffsetDateTime(_))
+        ));
+        assert!(matches!(
+            f("2016-12-31T23:60:60Z"),
+            Err(E::ParseOffsetDateTime(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_with_table_test() -> anyhow::Result<()> {
+        let table = LeapSecondTable::new(vec![Instant::from_str("2021-01-01T00:00:00Z")?])?;
+        let f = |s: &str| LeapSecondDateTime::parse_with_table(s, &table);
+        assert!(f("2020-12-31T23:59:60Z")?.is_leap_second());
+        assert_eq!(
+            f("2016-12-31T23:59:60Z"),
+            Err(ParseLeapSecondDateTimeError::NotLeapSecond)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn accessors_test() -> anyhow::Result<()> {
+        let leap_second = LeapSecondDateTime::from_str("2016-12-31T23:59:60Z")?;
+        assert!(leap_second.is_leap_second());
+        assert_eq!(leap_second.offset_date_time(), None);
+        let offset_date_time = OffsetDateTime::from_str("2016-12-31T23:59:59Z")?;
+        let not_leap_second = LeapSecondDateTime::from(offset_date_time);
+        assert!(!not_leap_second.is_leap_second());
+        assert_eq!(not_leap_second.offset_date_time(), Some(offset_date_time));
+        Ok(())
+    }
+
+    #[test]
+    fn to_precise_instant_test() -> anyhow::Result<()> {
+        let f = |s: &str, policy: LeapSecondPolicy| -> anyhow::Result<String> {
+            Ok(LeapSecondDateTime::from_str(s)?
+                .to_precise_instant(policy)
+                .to_string())
+        };
+        type P = LeapSecondPolicy;
+        assert_eq!(
+            f("2016-12-31T23:59:60.5Z", P::CollapseToPrevious)?,
+            "2016-12-31T23:59:59.500Z"
+        );
+        assert_eq!(
+            f("2016-12-31T23:59:60.5Z", P::CollapseToNext)?,
+            "2017-01-01T00:00:00.500Z"
+        );
+        assert_eq!(
+            f("2016-12-31T23:59:59Z", P::CollapseToNext)?,
+            "2016-12-31T23:59:59Z"
+        );
+        assert_eq!(
+            f("2016-12-31T15:59:60-08:00", P::CollapseToNext)?,
+            "2017-01-01T00:00:00Z"
+        );
+
+        // smear
+        assert_eq!(f("2016-12-31T12:00:00Z", P::Smear)?, "2016-12-31T12:00:00Z");
+        assert_eq!(
+            f("2016-12-31T18:00:00Z", P::Smear)?,
+            "2016-12-31T17:59:59.750002893Z"
+        );
+        assert_eq!(
+            f("2016-12-31T23:59:59Z", P::Smear)?,
+            "2016-12-31T23:59:58.500017360Z"
+        );
+        assert_eq!(
+            f("2016-12-31T23:59:60Z", P::Smear)?,
+            "2016-12-31T23:59:59.500005786Z"
+        );
+        assert_eq!(
+            f("2017-01-01T00:00:00Z", P::Smear)?,
+            "2017-01-01T00:00:00.499994213Z"
+        );
+        assert_eq!(
+            f("2017-01-01T11:59:59Z", P::Smear)?,
+            "2017-01-01T11:59:59.000011573Z"
+        );
+        assert_eq!(f("2017-01-01T12:00:00Z", P::Smear)?, "2017-01-01T12:00:00Z");
+        assert_eq!(f("2021-02-03T04:05:06Z", P::Smear)?, "2021-02-03T04:05:06Z");
+        Ok(())
+    }
+}
@@ -0,0 +1,264 @@
+use thiserror::Error;
+
+use crate::Instant;
+
+// the positive leap seconds inserted into UTC
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeapSecondTable {
+    // the instant just after each inserted second (e.g. 2017-01-01T00:00:00Z)
+    leap_seconds: Vec<Instant>,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+#[error("invalid leap second table error")]
+pub struct InvalidLeapSecondTableError;
+
+// TAI - UTC at 1972-01-01T00:00:00Z (the start of UTC with leap seconds)
+pub(crate) const TAI_MINUS_UTC_1972: i64 = 10;
+
+// 1972-01-01T00:00:00Z
+pub(crate) const UTC_1972: i64 = 63_072_000;
+
+// from leap-seconds.list (IERS)
+const BUILTIN_LEAP_SECONDS: [u32; 27] = [
+    78_796_800,    // 1972-07-01
+    94_694_400,    // 1973-01-01
+    126_230_400,   // 1974-01-01
+    157_766_400,   // 1975-01-01
+    189_302_400,   // 1976-01-01
+    220_924_800,   // 1977-01-01
+    252_460_800,   // 1978-01-01
+    283_996_800,   // 1979-01-01
+    315_532_800,   // 1980-01-01
+    362_793_600,   // 1981-07-01
+    394_329_600,   // 1982-07-01
+    425_865_600,   // 1983-07-01
+    489_024_000,   // 1985-07-01
+    567_993_600,   // 1988-01-01
+    631_152_000,   // 1990-01-01
+    662_688_000,   // 1991-01-01
+    709_948_800,   // 1992-07-01
+    741_484_800,   // 1993-07-01
+    773_020_800,   // 1994-07-01
+    820_454_400,   // 1996-01-01
+    867_715_200,   // 1997-07-01
+    915_148_800,   // 1999-01-01
+    1_136_073_600, // 2006-01-01
+    1_230_768_000, // 2009-01-01
+    1_341_100_800, // 2012-07-01
+    1_435_708_800, // 2015-07-01
+    1_483_228_800, // 2017-01-01
+];
+
+impl LeapSecondTable {
+    // leap_seconds: the instant just after each inserted second in ascending order
+    pub fn new(leap_seconds: Vec<Instant>) -> Result<Self, InvalidLeapSecondTableError> {
+        if leap_seconds.iter().any(|t| i64::from(*t) % 86_400 != 0)
+            || leap_seconds.windows(2).any(|w| w[0] >= w[1])
+        {
+            return Err(InvalidLeapSecondTableError);
+        }
+        Ok(Self { leap_seconds })
+    }
+
+    // the leap seconds up to 2016-12-31T23:59:60Z
+    pub fn builtin() -> Self {
+        Self {
+            leap_seconds: BUILTIN_LEAP_SECONDS
+                .iter()
+                .map(|t| Instant::from(*t))
+                .collect(),
+        }
+    }
+
+    // true if a second was inserted just before the instant
+    pub fn is_leap_second_before(&self, instant: Instant) -> bool {
+        self.leap_seconds.binary_search(&instant).is_ok()
+    }
+
+    pub fn leap_seconds(&self) -> &[Instant] {
+        &self.leap_seconds
+    }
+
+    // TAI - UTC (UTC timestamp >= 1972-01-01T00:00:00Z)
+    pub(crate) fn tai_minus_utc(&self, timestamp: i64) -> i64 {
+        let count = self
+            .leap_seconds
+            .partition_point(|t| i64::from(*t) <= timestamp);
+        TAI_MINUS_UTC_1972 + count as i64
+    }
+
+    // (UTC timestamp, is leap second) (TAI timestamp >= 1972-01-01T00:00:10 TAI)
+    // the leap second is represented by the timestamp of 23:59:59
+    pub(crate) fn utc_from_tai(&self, tai_timestamp: i64) -> (i64, bool) {
+        let mut count = 0;
+        for (index, leap_second) in self.leap_seconds.iter().enumerate() {
+            // TAI timestamp of the leap second (23:59:60)
+            let inserted = i64::from(*leap_second) + TAI_MINUS_UTC_1972 + index as i64;
+            if tai_timestamp < inserted {
+                break;
+            }
+            if tai_timestamp == inserted {
+                return (i64::from(*leap_second) - 1, true);
+            }
+            count = index as i64 + 1;
+        }
+        (tai_timestamp - TAI_MINUS_UTC_1972 - count, false)
+    }
+
+    // the nearest inserted second (the instant just after it) within 12 hours
+    pub(crate) fn leap_second_near(&self, timestamp: i64) -> Option<Instant> {
+        let index = self
+            .leap_seconds
+            .partition_point(|t| i64::from(*t) <= timestamp);
+        [index.checked_sub(1), Some(index)]
+            .iter()
+            .flatten()
+            .filter_map(|index| self.leap_seconds.get(*index).copied())
+            .find(|t| (i64::from(*t) - timestamp).abs() < 43_200)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::TryFrom, str::FromStr};
+
+    use super::*;
+
+    #[test]
+    fn new_test() -> anyhow::Result<()> {
+        let t = |s: &str| Instant::from_str(s);
+        assert!(LeapSecondTable::new(vec![]).is_ok());
+        assert!(LeapSecondTable::new(vec![t("2017-01-01T00:00:00Z")?]).is_ok());
+        assert_eq!(
+            LeapSecondTable::new(vec![t("2017-01-01T00:00:01Z")?]),
+            Err(InvalidLeapSecondTableError)
+        );
+        assert_eq!(
+            LeapSecondTable::new(vec![t("2017-01-01T00:00:00Z")?, t("2015-07-01T00:00:00Z")?]),
+            Err(InvalidLeapSecondTableError)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn builtin_test() -> anyhow::Result<()> {
+        let table = LeapSecondTable::builtin();
+        assert_eq!(table.leap_seconds().len(), 27);
+        assert_eq!(
+            table.leap_seconds().first().map(|t| t.to_string()),
+            Some("1972-07-01T00:00:00Z".to_string())
+        );
+        assert_eq!(
+            table.leap_seconds().last().map(|t| t.to_string()),
+            Some("2017-01-01T00:00:00Z".to_string())
+        );
+        assert!(table
+            .leap_seconds()
+            .iter()
+            .all(|t| t.to_string().ends_with("-01T00:00:00Z")));
+        Ok(())
+    }
+
+    #[test]
+    fn parity_with_leap_seconds_list_test() -> anyhow::Result<()> {
+        // depends on the system tz database
+        let path = "/usr/share/zoneinfo/leap-seconds.list";
+        let list = match std::fs::read_to_string(path) {
+            Ok(list) => list,
+            Err(_) => return Ok(()),
+        };
+        // NTP timestamps (seconds from 1900-01-01T00:00:00Z)
+        let mut leap_seconds = vec![];
+        for line in list.lines().filter(|line| !line.starts_with('#')) {
+            if let Some(ntp) = line.split_whitespace().next() {
+                leap_seconds.push(Instant::try_from(ntp.parse::<i64>()? - 2_208_988_800)?);
+            }
+        }
+        // the first line is the start of UTC (1972-01-01), not a leap second
+        assert_eq!(
+            LeapSecondTable::builtin().leap_seconds(),
+            &leap_seconds[1..]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn is_leap_second_before_test() -> anyhow::Result<()> {
+        let table = LeapSecondTable::builtin();
+        let f = |s: &str| -> anyhow::Result<bool> {
+            Ok(table.is_leap_second_before(Instant::from_str(s)?))
+        };
+        assert!(f("2017-01-01T00:00:00Z")?);
+        assert!(f("2015-07-01T00:00:00Z")?);
+        assert!(!f("2016-07-01T00:00:00Z")?);
+        assert!(!f("2017-01-01T00:00:01Z")?);
+        Ok(())
+    }
+
+    #[test]
+    fn tai_minus_utc_test() -> anyhow::Result<()> {
+        let table = LeapSecondTable::builtin();
+        let f = |s: &str| -> anyhow::Result<i64> {
+            Ok(table.tai_minus_utc(i64::from(Instant::from_str(s)?)))
+        };
+        assert_eq!(f("1972-01-01T00:00:00Z")?, 10);
+        assert_eq!(f("1972-06-30T23:59:59Z")?, 10);
+        assert_eq!(f("1972-07-01T00:00:00Z")?, 11);
+        assert_eq!(f("2016-12-31T23:59:59Z")?, 36);
+        assert_eq!(f("2017-01-01T00:00:00Z")?, 37);
+        Ok(())
+    }
+
+    #[test]
+    fn utc_from_tai_test() -> anyhow::Result<()> {
+        let table = LeapSecondTable::builtin();
+        let f = |tai_timestamp: i64| -> (String, bool) {
+            let (timestamp, leap_second) = table.utc_from_tai(tai_timestamp);
+            let instant = Instant::try_from(timestamp).expect("in range");
+            (instant.to_string(), leap_second)
+        };
+        let utc = |s: &str| -> anyhow::Result<i64> { Ok(i64::from(Instant::from_str(s)?)) };
+        assert_eq!(
+            f(UTC_1972 + 10),
+            ("1972-01-01T00:00:00Z".to_string(), false)
+        );
+        assert_eq!(
+            f(utc("2016-12-31T23:59:59Z")? + 36),
+            ("2016-12-31T23:59:59Z".to_string(), false)
+        );
+        // 2016-12-31T23:59:60Z
+        assert_eq!(
+            f(utc("2016-12-31T23:59:59Z")? + 37),
+            ("2016-12-31T23:59:59Z".to_string(), true)
+        );
+        assert_eq!(
+            f(utc("2017-01-01T00:00:00Z")? + 37),
+            ("2017-01-01T00:00:00Z".to_string(), false)
+        );
+        // 1972-06-30T23:59:60Z
+        assert_eq!(
+            f(utc("1972-06-30T23:59:59Z")? + 11),
+            ("1972-06-30T23:59:59Z".to_string(), true)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn leap_second_near_test() -> anyhow::Result<()> {
+        let table = LeapSecondTable::builtin();
+        let f = |s: &str| -> anyhow::Result<Option<String>> {
+            Ok(table
+                .leap_second_near(i64::from(Instant::from_str(s)?))
+                .map(|t| t.to_string()))
+        };
+        let leap_second = Some("2017-01-01T00:00:00Z".to_string());
+        assert_eq!(f("2016-12-31T12:00:00Z")?, None);
+        assert_eq!(f("2016-12-31T12:00:01Z")?, leap_second);
+        assert_eq!(f("2017-01-01T00:00:00Z")?, leap_second);
+        assert_eq!(f("2017-01-01T11:59:59Z")?, leap_second);
+        assert_eq!(f("2017-01-01T12:00:00Z")?, None);
+        assert_eq!(f("2021-01-01T00:00:00Z")?, None);
+        Ok(())
+    }
+}
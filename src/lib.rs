@@ -1,16 +1,54 @@
+#[cfg(feature = "chrono")]
+mod chrono_interop;
+mod clock;
 mod date;
 mod date_time;
+mod date_time_of_day;
 mod duration;
+mod extended_date_time;
+mod gps_time;
 mod instant;
+mod interval;
+mod leap_second_date_time;
+mod leap_second_table;
+#[doc(hidden)]
+pub mod macros;
 mod offset_date_time;
+mod precise_instant;
 mod private;
+mod range_iter;
+mod recurrence;
+mod repeating_interval;
+mod rfc3339_date_time;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod tai_instant;
 mod time;
+#[cfg(feature = "time")]
+mod time_interop;
 mod time_zone_offset;
+mod tz;
+mod zoned_date_time;
 
+pub use self::clock::*;
 pub use self::date::*;
 pub use self::date_time::*;
+pub use self::date_time_of_day::*;
 pub use self::duration::*;
+pub use self::extended_date_time::*;
+pub use self::gps_time::*;
 pub use self::instant::*;
+pub use self::interval::*;
+pub use self::leap_second_date_time::*;
+pub use self::leap_second_table::*;
 pub use self::offset_date_time::*;
+pub use self::precise_instant::*;
+pub use self::range_iter::*;
+pub use self::recurrence::*;
+pub use self::repeating_interval::*;
+pub use self::rfc3339_date_time::*;
+pub use self::tai_instant::*;
 pub use self::time::*;
 pub use self::time_zone_offset::*;
+pub use self::tz::*;
+pub use self::zoned_date_time::*;
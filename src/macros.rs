@@ -0,0 +1,505 @@
+// the literal macros (e.g. date!(2021-02-03) or date!("2021-02-03"))
+// an invalid literal is a compile error
+// the string literal form is the same as FromStr
+// the token form concatenates the tokens, so whitespace between them is ignored
+// (rustfmt may rewrite date!(2021-02-03) as date!(2021 - 02 - 03))
+// the functions are used by the macros (None if the literal is invalid)
+
+use crate::{
+    CalendarDate, DateTime, DayOfMonth, Hour, Minute, Month, Nanosecond, OffsetDateTime, Second,
+    Time, TimeZoneOffset, Year, YearMonth,
+};
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __literal {
+    ($t:ty, $f:ident, $message:literal, $s:expr) => {{
+        const VALUE: $t = match $crate::macros::$f($s) {
+            Some(value) => value,
+            None => panic!($message),
+        };
+        VALUE
+    }};
+}
+
+/// CalendarDate (e.g. `date!(2021-02-03)` or `date!("2021-02-03")`)
+///
+/// ```
+/// use limited_date_time::{date, CalendarDate};
+/// use std::str::FromStr;
+///
+/// const VALUE: CalendarDate = date!("2021-02-03");
+/// assert_eq!(VALUE, CalendarDate::from_str("2021-02-03").unwrap());
+/// assert_eq!(date!(2021-02-03), VALUE);
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = limited_date_time::date!(2021-02-30);
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = limited_date_time::date!(2021-2-3);
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = limited_date_time::date!("2021-02-03 ");
+/// ```
+#[macro_export]
+macro_rules! date {
+    ($s:literal) => {
+        $crate::__literal!($crate::CalendarDate, calendar_date, "invalid date literal", $s)
+    };
+    ($($t:tt)+) => {
+        $crate::__literal!(
+            $crate::CalendarDate,
+            calendar_date,
+            "invalid date literal",
+            concat!($(stringify!($t)),+)
+        )
+    };
+}
+
+/// DateTime (e.g. `datetime!(2021-02-03T04:05:06)` or `datetime!("2021-02-03T04:05:06")`)
+///
+/// ```
+/// use limited_date_time::{datetime, DateTime};
+/// use std::str::FromStr;
+///
+/// const VALUE: DateTime = datetime!("2021-02-03T04:05:06");
+/// assert_eq!(VALUE, DateTime::from_str("2021-02-03T04:05:06").unwrap());
+/// assert_eq!(datetime!(2021-02-03T04:05:06), VALUE);
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = limited_date_time::datetime!(2021-02-03T24:00:00);
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = limited_date_time::datetime!("2021-02-03T04:05:06 ");
+/// ```
+#[macro_export]
+macro_rules! datetime {
+    ($s:literal) => {
+        $crate::__literal!($crate::DateTime, date_time, "invalid date time literal", $s)
+    };
+    ($($t:tt)+) => {
+        $crate::__literal!(
+            $crate::DateTime,
+            date_time,
+            "invalid date time literal",
+            concat!($(stringify!($t)),+)
+        )
+    };
+}
+
+/// OffsetDateTime (e.g. `odt!(2021-02-03T04:05:06+09:00)` or `odt!("2021-02-03T04:05:06+09:00")`)
+///
+/// ```
+/// use limited_date_time::{odt, OffsetDateTime};
+/// use std::str::FromStr;
+///
+/// const VALUE: OffsetDateTime = odt!("2021-02-03T04:05:06+09:00");
+/// assert_eq!(VALUE, OffsetDateTime::from_str("2021-02-03T04:05:06+09:00").unwrap());
+/// assert_eq!(odt!(2021-02-03T04:05:06+09:00), VALUE);
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = limited_date_time::odt!(2021-02-03T04:05:06-00:00);
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = limited_date_time::odt!("2021-02-03T04:05:06+09:00 ");
+/// ```
+#[macro_export]
+macro_rules! odt {
+    ($s:literal) => {
+        $crate::__literal!($crate::OffsetDateTime, offset_date_time, "invalid offset date time literal", $s)
+    };
+    ($($t:tt)+) => {
+        $crate::__literal!(
+            $crate::OffsetDateTime,
+            offset_date_time,
+            "invalid offset date time literal",
+            concat!($(stringify!($t)),+)
+        )
+    };
+}
+
+/// TimeZoneOffset (e.g. `offset!(+09:00)` or `offset!("+09:00")`)
+///
+/// ```
+/// use limited_date_time::{offset, TimeZoneOffset};
+/// use std::str::FromStr;
+///
+/// const VALUE: TimeZoneOffset = offset!("+09:00");
+/// assert_eq!(VALUE, TimeZoneOffset::from_str("+09:00").unwrap());
+/// assert_eq!(offset!(+09:00), VALUE);
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = limited_date_time::offset!(+24:00);
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = limited_date_time::offset!("+09:00 ");
+/// ```
+#[macro_export]
+macro_rules! offset {
+    ($s:literal) => {
+        $crate::__literal!($crate::TimeZoneOffset, time_zone_offset, "invalid time zone offset literal", $s)
+    };
+    ($($t:tt)+) => {
+        $crate::__literal!(
+            $crate::TimeZoneOffset,
+            time_zone_offset,
+            "invalid time zone offset literal",
+            concat!($(stringify!($t)),+)
+        )
+    };
+}
+
+/// Time (e.g. `time!(04:05:06)` or `time!("04:05:06")`)
+///
+/// ```
+/// use limited_date_time::{time, Time};
+/// use std::str::FromStr;
+///
+/// const VALUE: Time = time!("04:05:06");
+/// assert_eq!(VALUE, Time::from_str("04:05:06").unwrap());
+/// assert_eq!(time!(04:05:06), VALUE);
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = limited_date_time::time!(24:00:00);
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = limited_date_time::time!("04:05:06 ");
+/// ```
+#[macro_export]
+macro_rules! time {
+    ($s:literal) => {
+        $crate::__literal!($crate::Time, time, "invalid time literal", $s)
+    };
+    ($($t:tt)+) => {
+        $crate::__literal!(
+            $crate::Time,
+            time,
+            "invalid time literal",
+            concat!($(stringify!($t)),+)
+        )
+    };
+}
+
+/// YearMonth (e.g. `year_month!(2021-02)` or `year_month!("2021-02")`)
+///
+/// ```
+/// use limited_date_time::{year_month, YearMonth};
+/// use std::str::FromStr;
+///
+/// const VALUE: YearMonth = year_month!("2021-02");
+/// assert_eq!(VALUE, YearMonth::from_str("2021-02").unwrap());
+/// assert_eq!(year_month!(2021-02), VALUE);
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = limited_date_time::year_month!(2021-13);
+/// ```
+///
+/// ```compile_fail,E0080
+/// let _ = limited_date_time::year_month!("2021-02 ");
+/// ```
+#[macro_export]
+macro_rules! year_month {
+    ($s:literal) => {
+        $crate::__literal!($crate::YearMonth, year_month, "invalid year month literal", $s)
+    };
+    ($($t:tt)+) => {
+        $crate::__literal!(
+            $crate::YearMonth,
+            year_month,
+            "invalid year month literal",
+            concat!($(stringify!($t)),+)
+        )
+    };
+}
+
+// `?` for Option in const fn
+macro_rules! some {
+    ($e:expr) => {
+        match $e {
+            Some(value) => value,
+            None => return None,
+        }
+    };
+}
+
+pub const fn calendar_date(s: &str) -> Option<CalendarDate> {
+    let bytes = s.as_bytes();
+    if bytes.len() != 10 {
+        return None;
+    }
+    parse_calendar_date(bytes, 0)
+}
+
+pub const fn date_time(s: &str) -> Option<DateTime> {
+    let bytes = s.as_bytes();
+    parse_date_time(bytes, bytes.len())
+}
+
+pub const fn offset_date_time(s: &str) -> Option<OffsetDateTime> {
+    let bytes = s.as_bytes();
+    let len = bytes.len();
+    if len >= 1 && bytes[len - 1] == b'Z' {
+        let date_time = some!(parse_date_time(bytes, len - 1));
+        return Some(OffsetDateTime::new(date_time, TimeZoneOffset::utc()));
+    }
+    if len < 6 {
+        return None;
+    }
+    // "-00:00" (see Rfc3339DateTime)
+    if bytes[len - 6] == b'-'
+        && some!(parse_time_zone_offset(bytes, len - 6)).offset_in_minutes() == 0
+    {
+        return None;
+    }
+    let date_time = some!(parse_date_time(bytes, len - 6));
+    let offset = some!(parse_time_zone_offset(bytes, len - 6));
+    Some(OffsetDateTime::new(date_time, offset))
+}
+
+pub const fn time(s: &str) -> Option<Time> {
+    let bytes = s.as_bytes();
+    parse_time(bytes, 0, bytes.len())
+}
+
+pub const fn time_zone_offset(s: &str) -> Option<TimeZoneOffset> {
+    let bytes = s.as_bytes();
+    if bytes.len() != 6 {
+        return None;
+    }
+    parse_time_zone_offset(bytes, 0)
+}
+
+pub const fn year_month(s: &str) -> Option<YearMonth> {
+    let bytes = s.as_bytes();
+    if bytes.len() != 7 {
+        return None;
+    }
+    parse_year_month(bytes, 0)
+}
+
+// bytes[start..start + len] as a decimal number
+const fn parse_digits(bytes: &[u8], start: usize, len: usize) -> Option<u32> {
+    let mut value = 0_u32;
+    let mut index = start;
+    while index < start + len {
+        let b = bytes[index];
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        value = value * 10 + (b - b'0') as u32;
+        index += 1;
+    }
+    Some(value)
+}
+
+// bytes[start..start + 10]
+const fn parse_calendar_date(bytes: &[u8], start: usize) -> Option<CalendarDate> {
+    let year_month = some!(parse_year_month(bytes, start));
+    if bytes[start + 7] != b'-' {
+        return None;
+    }
+    let day_of_month = some!(DayOfMonth::from_u8(
+        some!(parse_digits(bytes, start + 8, 2)) as u8
+    ));
+    match CalendarDate::from_ymd(year_month.year(), year_month.month(), day_of_month) {
+        Ok(date) => Some(date),
+        Err(_) => None,
+    }
+}
+
+// bytes[0..len]
+const fn parse_date_time(bytes: &[u8], len: usize) -> Option<DateTime> {
+    if len < 19 || bytes[10] != b'T' {
+        return None;
+    }
+    let date = some!(parse_calendar_date(bytes, 0));
+    let time = some!(parse_time(bytes, 11, len - 11));
+    Some(DateTime::new(date, time))
+}
+
+// bytes[start..start + len]
+const fn parse_time(bytes: &[u8], start: usize, len: usize) -> Option<Time> {
+    if len != 8 && !(len >= 10 && len <= 18) {
+        return None;
+    }
+    if bytes[start + 2] != b':' || bytes[start + 5] != b':' {
+        return None;
+    }
+    let hour = some!(Hour::from_u8(some!(parse_digits(bytes, start, 2)) as u8));
+    let minute = some!(Minute::from_u8(
+        some!(parse_digits(bytes, start + 3, 2)) as u8
+    ));
+    let second = some!(Second::from_u8(
+        some!(parse_digits(bytes, start + 6, 2)) as u8
+    ));
+    // time-secfrac: 1-9 digits
+    let nanosecond = if len == 8 {
+        Nanosecond::min()
+    } else {
+        if bytes[start + 8] != b'.' {
+            return None;
+        }
+        let digits = len - 9;
+        let mut value = some!(parse_digits(bytes, start + 9, digits));
+        let mut padding = digits;
+        while padding < 9 {
+            value *= 10;
+            padding += 1;
+        }
+        some!(Nanosecond::from_u32(value))
+    };
+    Some(Time::from_hms_nano(hour, minute, second, nanosecond))
+}
+
+// bytes[start..start + 6]
+const fn parse_time_zone_offset(bytes: &[u8], start: usize) -> Option<TimeZoneOffset> {
+    let sign = match bytes[start] {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    if bytes[start + 3] != b':' {
+        return None;
+    }
+    let h = some!(parse_digits(bytes, start + 1, 2)) as i16;
+    let m = some!(parse_digits(bytes, start + 4, 2)) as i16;
+    match TimeZoneOffset::from_offset_in_minutes(sign * (h * 60 + m)) {
+        Ok(offset) => Some(offset),
+        Err(_) => None,
+    }
+}
+
+// bytes[start..start + 7]
+const fn parse_year_month(bytes: &[u8], start: usize) -> Option<YearMonth> {
+    let year = some!(Year::from_u16(some!(parse_digits(bytes, start, 4)) as u16));
+    if bytes[start + 4] != b'-' {
+        return None;
+    }
+    let month = some!(Month::from_u8(
+        some!(parse_digits(bytes, start + 5, 2)) as u8
+    ));
+    Some(YearMonth::new(year, month))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn macros_test() -> anyhow::Result<()> {
+        const DATE: CalendarDate = crate::date!("2021-02-03");
+        assert_eq!(DATE, CalendarDate::from_str("2021-02-03")?);
+        // the token form ignores whitespace
+        assert_eq!(crate::date!(2021 - 02 - 03), DATE);
+        assert_eq!(
+            crate::datetime!(2021-02-03T04:05:06.789),
+            DateTime::from_str("2021-02-03T04:05:06.789")?
+        );
+        assert_eq!(
+            crate::odt!(2021-02-03T04:05:06+09:00),
+            OffsetDateTime::from_str("2021-02-03T04:05:06+09:00")?
+        );
+        assert_eq!(
+            crate::odt!(2021-02-03T04:05:06.123456789Z),
+            OffsetDateTime::from_str("2021-02-03T04:05:06.123456789Z")?
+        );
+        assert_eq!(crate::offset!(-09:30), TimeZoneOffset::from_hm(-9, -30)?);
+        assert_eq!(crate::time!(04:05:06), Time::from_str("04:05:06")?);
+        assert_eq!(
+            crate::year_month!("2021-02"),
+            YearMonth::from_str("2021-02")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn calendar_date_test() -> anyhow::Result<()> {
+        assert_eq!(
+            calendar_date("2020-02-29"),
+            Some(CalendarDate::from_str("2020-02-29")?)
+        );
+        assert_eq!(calendar_date("2021-02-29"), None);
+        assert_eq!(calendar_date("1969-12-31"), None);
+        assert_eq!(calendar_date("2021-13-01"), None);
+        assert_eq!(calendar_date("2021-2-3"), None);
+        assert_eq!(calendar_date("2021/02/03"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn date_time_test() -> anyhow::Result<()> {
+        assert_eq!(
+            date_time("2021-02-03T04:05:06"),
+            Some(DateTime::from_str("2021-02-03T04:05:06")?)
+        );
+        assert_eq!(date_time("2021-02-03 04:05:06"), None);
+        assert_eq!(date_time("2021-02-03T04:05"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn offset_date_time_test() -> anyhow::Result<()> {
+        assert_eq!(
+            offset_date_time("2021-02-03T04:05:06Z"),
+            Some(OffsetDateTime::from_str("2021-02-03T04:05:06Z")?)
+        );
+        assert_eq!(
+            offset_date_time("2021-02-03T04:05:06.5-09:30"),
+            Some(OffsetDateTime::from_str("2021-02-03T04:05:06.5-09:30")?)
+        );
+        assert_eq!(offset_date_time("2021-02-03T04:05:06-00:00"), None);
+        assert_eq!(offset_date_time("2021-02-03T04:05:06"), None);
+        assert_eq!(offset_date_time("2021-02-03T04:05:06z"), None);
+        assert_eq!(offset_date_time("Z"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn time_test() -> anyhow::Result<()> {
+        assert_eq!(time("23:59:59"), Some(Time::from_str("23:59:59")?));
+        assert_eq!(time("04:05:06.5"), Some(Time::from_str("04:05:06.5")?));
+        assert_eq!(
+            time("04:05:06.123456789"),
+            Some(Time::from_str("04:05:06.123456789")?)
+        );
+        assert_eq!(time("24:00:00"), None);
+        assert_eq!(time("04:60:00"), None);
+        assert_eq!(time("04:05:60"), None);
+        assert_eq!(time("04:05:06."), None);
+        assert_eq!(time("04:05:06.1234567890"), None);
+        assert_eq!(time("04:05:06,5"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn time_zone_offset_test() -> anyhow::Result<()> {
+        assert_eq!(time_zone_offset("+09:00"), Some(TimeZoneOffset::from_h(9)?));
+        assert_eq!(time_zone_offset("+24:00"), None);
+        assert_eq!(time_zone_offset("09:00"), None);
+        assert_eq!(time_zone_offset("Z"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn year_month_test() -> anyhow::Result<()> {
+        assert_eq!(year_month("9999-12"), Some(YearMonth::from_str("9999-12")?));
+        assert_eq!(year_month("10000-01"), None);
+        assert_eq!(year_month("2021-00"), None);
+        assert_eq!(year_month("2021-1"), None);
+        Ok(())
+    }
+}
@@ -1,8 +1,10 @@
 use std::{convert::TryFrom, str::FromStr};
 
 use crate::{
-    private::date_time_string_from_seconds_from_unix_epoch, DateTime, Instant, ParseDateTimeError,
-    ParseTimeZoneOffsetError, TimeZoneOffset,
+    private::date_time_string_from_seconds_from_unix_epoch, AddMonthsError, Clock, ClockError,
+    DateTime, EndOfMonthPolicy, Instant, LoadTimeZoneError, LocalTimeZoneConfig, Months,
+    Nanosecond, ParseDateTimeError, ParseTimeZoneOffsetError, Period, PreciseInstant, SystemClock,
+    TimeZoneOffset,
 };
 
 use thiserror::Error;
@@ -13,6 +15,14 @@ pub struct OffsetDateTime {
     offset: TimeZoneOffset,
 }
 
+#[derive(Debug, Error)]
+pub enum NowLocalError {
+    #[error("load time zone")]
+    LoadTimeZone(LoadTimeZoneError),
+    #[error("out of range")]
+    OutOfRange,
+}
+
 #[derive(Debug, Eq, Error, PartialEq)]
 pub enum ParseOffsetDateTimeError {
     #[error("invalid length")]
@@ -21,6 +31,9 @@ pub enum ParseOffsetDateTimeError {
     ParseDateTime(ParseDateTimeError),
     #[error("parse time zone offset")]
     ParseTimeZoneOffset(ParseTimeZoneOffsetError),
+    // "-00:00" (see Rfc3339DateTime)
+    #[error("unknown offset")]
+    UnknownOffset,
 }
 
 #[derive(Debug, Eq, Error, PartialEq)]
@@ -30,7 +43,7 @@ pub enum TryFromOffsetDateTimeError {
 }
 
 impl OffsetDateTime {
-    pub fn new(date_time: DateTime, offset: TimeZoneOffset) -> Self {
+    pub const fn new(date_time: DateTime, offset: TimeZoneOffset) -> Self {
         // FIXME: (1970-01-01T00:00:00 & -00:01) or (9999-12-31T00:00:00 & +00:01)
         Self { date_time, offset }
     }
@@ -46,10 +59,58 @@ impl OffsetDateTime {
         Ok(Self::new(date_time, offset))
     }
 
+    pub fn from_precise_instant(
+        precise_instant: PreciseInstant,
+        offset: TimeZoneOffset,
+    ) -> Result<Self, TryFromOffsetDateTimeError> {
+        Self::from_instant(precise_instant.truncate(), offset)
+            .map(|t| t.with_nanosecond(precise_instant.nanosecond()))
+    }
+
+    // the fraction of a second is truncated (see OffsetDateTime::precise_instant)
     pub fn instant(&self) -> Instant {
         Instant::from(*self)
     }
 
+    pub fn precise_instant(&self) -> PreciseInstant {
+        PreciseInstant::new(self.instant(), self.date_time.time().nanosecond())
+    }
+
+    pub fn now_in<C: Clock + ?Sized>(
+        clock: &C,
+        offset: TimeZoneOffset,
+    ) -> Result<Self, ClockError> {
+        Self::from_precise_instant(clock.now()?, offset).map_err(|_| ClockError::OutOfRange)
+    }
+
+    // panics if the system clock is out of range (see OffsetDateTime::try_now_local)
+    pub fn now_local() -> Result<Self, LoadTimeZoneError> {
+        match Self::try_now_local() {
+            Ok(now) => Ok(now),
+            Err(NowLocalError::LoadTimeZone(e)) => Err(e),
+            Err(NowLocalError::OutOfRange) => panic!("out of range"),
+        }
+    }
+
+    // the current date time in the system local time zone (see TimeZone::local)
+    pub fn now_local_in<C: Clock + ?Sized>(clock: &C) -> Result<Self, NowLocalError> {
+        Self::now_local_with(clock, &LocalTimeZoneConfig::from_env())
+    }
+
+    pub fn try_now_local() -> Result<Self, NowLocalError> {
+        Self::now_local_in(&SystemClock)
+    }
+
+    pub(crate) fn now_local_with<C: Clock + ?Sized>(
+        clock: &C,
+        config: &LocalTimeZoneConfig,
+    ) -> Result<Self, NowLocalError> {
+        let time_zone = config.load().map_err(NowLocalError::LoadTimeZone)?;
+        let now = clock.now().map_err(|_| NowLocalError::OutOfRange)?;
+        Self::from_precise_instant(now, time_zone.offset_at(now.truncate()))
+            .map_err(|_| NowLocalError::OutOfRange)
+    }
+
     pub fn date_time(&self) -> DateTime {
         self.date_time
     }
@@ -57,6 +118,87 @@ impl OffsetDateTime {
     pub fn offset(&self) -> TimeZoneOffset {
         self.offset
     }
+
+    // added to the local date time (the offset is kept)
+    pub fn add_months(
+        &self,
+        months: Months,
+        policy: EndOfMonthPolicy,
+    ) -> Result<Self, AddMonthsError> {
+        self.date_time
+            .add_months(months, policy)
+            .and_then(|date_time| self.with_local_date_time(date_time))
+    }
+
+    // subtracted from the local date time (the offset is kept)
+    pub fn sub_months(
+        &self,
+        months: Months,
+        policy: EndOfMonthPolicy,
+    ) -> Result<Self, AddMonthsError> {
+        self.date_time
+            .sub_months(months, policy)
+            .and_then(|date_time| self.with_local_date_time(date_time))
+    }
+
+    // added to the local date time (the offset is kept)
+    pub fn add_years(&self, years: u32, policy: EndOfMonthPolicy) -> Result<Self, AddMonthsError> {
+        self.date_time
+            .add_years(years, policy)
+            .and_then(|date_time| self.with_local_date_time(date_time))
+    }
+
+    // subtracted from the local date time (the offset is kept)
+    pub fn sub_years(&self, years: u32, policy: EndOfMonthPolicy) -> Result<Self, AddMonthsError> {
+        self.date_time
+            .sub_years(years, policy)
+            .and_then(|date_time| self.with_local_date_time(date_time))
+    }
+
+    // calendar part: added to the local date time (clamped to the last day of month)
+    // exact part: added to the instant
+    pub fn checked_add_period(&self, period: Period) -> Option<Self> {
+        let months = i64::from(period.total_months()?);
+        let days = i64::from(period.days());
+        let seconds = i64::try_from(u64::from(period.seconds())).ok()?;
+        self.checked_add_calendar_and_exact(months, days, seconds)
+    }
+
+    // calendar part: subtracted from the local date time (clamped to the last day of month)
+    // exact part: subtracted from the instant
+    pub fn checked_sub_period(&self, period: Period) -> Option<Self> {
+        let months = i64::from(period.total_months()?);
+        let days = i64::from(period.days());
+        let seconds = i64::try_from(u64::from(period.seconds())).ok()?;
+        self.checked_add_calendar_and_exact(-months, -days, -seconds)
+    }
+
+    // check that the instant is in range
+    fn with_local_date_time(&self, date_time: DateTime) -> Result<Self, AddMonthsError> {
+        let timestamp =
+            date_time.seconds_from_unix_epoch() - i64::from(self.offset.offset_in_minutes()) * 60;
+        Instant::try_from(timestamp).map_err(|_| AddMonthsError::OutOfRange)?;
+        Ok(Self::new(date_time, self.offset))
+    }
+
+    fn checked_add_calendar_and_exact(&self, months: i64, days: i64, seconds: i64) -> Option<Self> {
+        let date = self
+            .date_time
+            .date()
+            .checked_add_months_and_days(months, days)?;
+        let date_time = DateTime::new(date, self.date_time.time());
+        let timestamp = date_time
+            .seconds_from_unix_epoch()
+            .checked_sub(i64::from(self.offset.offset_in_minutes()) * 60)?
+            .checked_add(seconds)?;
+        let instant = Instant::try_from(timestamp).ok()?;
+        let offset_date_time = Self::from_instant(instant, self.offset).ok()?;
+        Some(offset_date_time.with_nanosecond(self.date_time.time().nanosecond()))
+    }
+
+    pub(crate) fn with_nanosecond(&self, nanosecond: Nanosecond) -> Self {
+        Self::new(self.date_time.with_nanosecond(nanosecond), self.offset)
+    }
 }
 
 impl std::fmt::Display for OffsetDateTime {
@@ -78,22 +220,26 @@ impl std::str::FromStr for OffsetDateTime {
     type Err = ParseOffsetDateTimeError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() != 25 && s.len() != 20 {
+        // date-time part: 19 characters or 21-29 characters (time-secfrac)
+        let (date_time, offset) = match s.strip_suffix('Z') {
+            Some(date_time) => (date_time, None),
+            None if s.len() >= 6 && s.is_char_boundary(s.len() - 6) => {
+                let (date_time, offset) = s.split_at(s.len() - 6);
+                (date_time, Some(offset))
+            }
+            None => return Err(Self::Err::InvalidLength),
+        };
+        if date_time.len() != 19 && !(21..=29).contains(&date_time.len()) {
             return Err(Self::Err::InvalidLength);
         }
         let date_time =
-            DateTime::from_str(&s[0..19]).map_err(ParseOffsetDateTimeError::ParseDateTime)?;
-        let offset = if s.len() == 25 {
-            TimeZoneOffset::from_str(&s[19..25])
-                .map_err(ParseOffsetDateTimeError::ParseTimeZoneOffset)
-        } else if s.chars().nth(19) == Some('Z') {
-            TimeZoneOffset::from_str("+00:00")
-                .map_err(ParseOffsetDateTimeError::ParseTimeZoneOffset)
-        } else {
-            Err(ParseOffsetDateTimeError::ParseTimeZoneOffset(
-                ParseTimeZoneOffsetError::InvalidFormat,
-            ))
-        }?;
+            DateTime::from_str(date_time).map_err(ParseOffsetDateTimeError::ParseDateTime)?;
+        let offset = match offset {
+            None => TimeZoneOffset::utc(),
+            Some("-00:00") => return Err(ParseOffsetDateTimeError::UnknownOffset),
+            Some(offset) => TimeZoneOffset::from_str(offset)
+                .map_err(ParseOffsetDateTimeError::ParseTimeZoneOffset)?,
+        };
         Ok(Self::new(date_time, offset))
     }
 }
@@ -105,6 +251,7 @@ impl From<Instant> for OffsetDateTime {
     }
 }
 
+// the fraction of a second is truncated
 impl From<OffsetDateTime> for Instant {
     fn from(offset_date_time: OffsetDateTime) -> Self {
         let date_time = offset_date_time.date_time();
@@ -131,7 +278,9 @@ fn date_time_from_timestamp(timestamp: i64) -> DateTime {
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
+    use std::{path::PathBuf, str::FromStr};
+
+    use crate::ManualClock;
 
     use super::*;
 
@@ -210,12 +359,68 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn now_in_test() -> anyhow::Result<()> {
+        let clock = ManualClock::new(PreciseInstant::from_str("2021-02-03T19:05:06.5Z")?);
+        assert_eq!(
+            OffsetDateTime::now_in(&clock, TimeZoneOffset::from_h(9)?)?.to_string(),
+            "2021-02-04T04:05:06.500+09:00"
+        );
+        let clock = ManualClock::from(Instant::max());
+        assert_eq!(
+            OffsetDateTime::now_in(&clock, TimeZoneOffset::from_h(9)?),
+            Err(ClockError::OutOfRange)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn now_local_test() -> anyhow::Result<()> {
+        let zoneinfo_dir = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/zoneinfo"));
+        let missing = zoneinfo_dir.join("missing");
+        let f = |clock: &ManualClock, tz: Option<&str>| {
+            let config = LocalTimeZoneConfig::new(
+                tz.map(str::to_string),
+                missing.clone(),
+                zoneinfo_dir.clone(),
+            );
+            OffsetDateTime::now_local_with(clock, &config)
+        };
+        let clock = ManualClock::new(PreciseInstant::from_str("2021-02-03T19:05:06.5Z")?);
+        assert_eq!(
+            f(&clock, Some("Asia/Tokyo"))?.to_string(),
+            "2021-02-04T04:05:06.500+09:00"
+        );
+        assert_eq!(f(&clock, Some(""))?.to_string(), "2021-02-03T19:05:06.500Z");
+        assert!(matches!(
+            f(&clock, None),
+            Err(NowLocalError::LoadTimeZone(LoadTimeZoneError::Io(_)))
+        ));
+
+        let clock = ManualClock::from(Instant::min());
+        assert_eq!(
+            f(&clock, Some("Asia/Tokyo"))?.to_string(),
+            "1970-01-01T09:00:00+09:00"
+        );
+        assert!(matches!(
+            f(&clock, Some("EST5")),
+            Err(NowLocalError::OutOfRange)
+        ));
+        let clock = ManualClock::from(Instant::max());
+        assert_eq!(f(&clock, Some(""))?.to_string(), "9999-12-31T23:59:59Z");
+        assert!(matches!(
+            f(&clock, Some("Asia/Tokyo")),
+            Err(NowLocalError::OutOfRange)
+        ));
+        Ok(())
+    }
+
     #[test]
     fn str_conversion_test() {
         type E = ParseOffsetDateTimeError;
         let f = |s: &str| OffsetDateTime::from_str(s);
 
-        assert!(matches!(f("2021-02-03T04:05:06+07:00"), Ok(_)));
+        assert!(f("2021-02-03T04:05:06+07:00").is_ok());
         assert!(matches!(
             f("20021-02-03T04:05:06+07:00"),
             Err(E::InvalidLength)
@@ -241,6 +446,166 @@ mod tests {
             f("2021-02-03T04:05:06Z").map(|d| d.to_string()),
             Ok("2021-02-03T04:05:06Z".to_string())
         );
+        assert_eq!(f("2021-02-03T04:05:06-00:00"), Err(E::UnknownOffset));
+
+        // time-secfrac
+        assert!(matches!(
+            f("2021-02-03T04:05:06.+07:00"),
+            Err(E::InvalidLength)
+        ));
+        assert!(matches!(
+            f("2021-02-03T04:05:06.1a+07:00"),
+            Err(E::ParseDateTime(_))
+        ));
+        assert_eq!(
+            f("2021-02-03T04:05:06.123+07:00").map(|d| d.to_string()),
+            Ok("2021-02-03T04:05:06.123+07:00".to_string())
+        );
+        assert_eq!(
+            f("2021-02-03T04:05:06.123456789Z").map(|d| d.to_string()),
+            Ok("2021-02-03T04:05:06.123456789Z".to_string())
+        );
+    }
+
+    #[test]
+    fn nanosecond_test() -> anyhow::Result<()> {
+        let offset_date_time = OffsetDateTime::from_str("2021-02-03T04:05:06.999+09:00")?;
+        assert_eq!(
+            offset_date_time.date_time().time().nanosecond(),
+            Nanosecond::try_from(999_000_000_u32)?
+        );
+        // truncated
+        assert_eq!(
+            offset_date_time.instant(),
+            Instant::from_str("2021-02-02T19:05:06Z")?
+        );
+        assert_eq!(
+            offset_date_time
+                .checked_add_period(Period::from_str("PT1S")?)
+                .map(|t| t.to_string()),
+            Some("2021-02-03T04:05:07.999+09:00".to_string())
+        );
+        assert_eq!(
+            offset_date_time
+                .add_months(Months::from(1), EndOfMonthPolicy::Clamp)?
+                .to_string(),
+            "2021-03-03T04:05:06.999+09:00"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn precise_instant_test() -> anyhow::Result<()> {
+        let offset_date_time = OffsetDateTime::from_str("2021-02-03T04:05:06.789+09:00")?;
+        let precise_instant = PreciseInstant::from_str("2021-02-02T19:05:06.789Z")?;
+        assert_eq!(offset_date_time.precise_instant(), precise_instant);
+        assert_eq!(
+            OffsetDateTime::from_precise_instant(precise_instant, TimeZoneOffset::from_h(9)?)?,
+            offset_date_time
+        );
+        assert!(OffsetDateTime::from_precise_instant(
+            PreciseInstant::from_str("1970-01-01T00:00:00.5Z")?,
+            TimeZoneOffset::from_h(-9)?
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn add_months_test() -> anyhow::Result<()> {
+        type E = AddMonthsError;
+        type P = EndOfMonthPolicy;
+        let odt = |s: &str| OffsetDateTime::from_str(s);
+        let m = |m: u32| Months::from(m);
+        assert_eq!(
+            odt("2021-01-31T23:00:00-09:00")?.add_months(m(1), P::Clamp)?,
+            odt("2021-02-28T23:00:00-09:00")?
+        );
+        assert_eq!(
+            odt("2021-01-31T23:00:00-09:00")?.add_months(m(1), P::Fail),
+            Err(E::InvalidDayOfMonth)
+        );
+        assert_eq!(
+            odt("2021-01-31T23:00:00-09:00")?.add_months(m(1), P::Roll)?,
+            odt("2021-03-03T23:00:00-09:00")?
+        );
+        assert_eq!(
+            odt("2021-03-31T00:00:00+09:00")?.sub_months(m(1), P::Clamp)?,
+            odt("2021-02-28T00:00:00+09:00")?
+        );
+        // the local date time is in range, but the instant is out of range
+        assert_eq!(
+            odt("9999-10-31T23:59:59-00:01")?.add_months(m(2), P::Clamp),
+            Err(E::OutOfRange)
+        );
+        assert_eq!(
+            odt("1970-02-01T00:00:00+00:01")?.sub_months(m(1), P::Clamp),
+            Err(E::OutOfRange)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn add_years_test() -> anyhow::Result<()> {
+        type E = AddMonthsError;
+        type P = EndOfMonthPolicy;
+        let odt = |s: &str| OffsetDateTime::from_str(s);
+        assert_eq!(
+            odt("2020-02-29T12:00:00+09:00")?.add_years(1, P::Clamp)?,
+            odt("2021-02-28T12:00:00+09:00")?
+        );
+        assert_eq!(
+            odt("2020-02-29T12:00:00+09:00")?.sub_years(1, P::Roll)?,
+            odt("2019-03-01T12:00:00+09:00")?
+        );
+        assert_eq!(
+            odt("2020-02-29T12:00:00+09:00")?.add_years(1, P::Fail),
+            Err(E::InvalidDayOfMonth)
+        );
+        assert_eq!(
+            odt("9998-12-31T23:59:59-00:01")?.add_years(1, P::Fail),
+            Err(E::OutOfRange)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn checked_add_period_test() -> anyhow::Result<()> {
+        let odt = |s: &str| OffsetDateTime::from_str(s);
+        let p = |s: &str| Period::from_str(s);
+        assert_eq!(
+            odt("2021-02-03T04:05:06+09:00")?.checked_add_period(p("P1Y2M10DT2H30M")?),
+            Some(odt("2022-04-13T06:35:06+09:00")?)
+        );
+        assert_eq!(
+            odt("2021-01-31T23:00:00+09:00")?.checked_add_period(p("P1MT1H")?),
+            Some(odt("2021-03-01T00:00:00+09:00")?)
+        );
+        assert_eq!(
+            odt("9999-12-31T23:59:59Z")?.checked_add_period(p("PT1S")?),
+            None
+        );
+        // the local date time is in range, but the instant is out of range
+        assert_eq!(
+            odt("9999-12-30T23:59:59-00:01")?.checked_add_period(p("P1D")?),
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn checked_sub_period_test() -> anyhow::Result<()> {
+        let odt = |s: &str| OffsetDateTime::from_str(s);
+        let p = |s: &str| Period::from_str(s);
+        assert_eq!(
+            odt("2022-04-13T06:35:06+09:00")?.checked_sub_period(p("P1Y2M10DT2H30M")?),
+            Some(odt("2021-02-03T04:05:06+09:00")?)
+        );
+        assert_eq!(
+            odt("1970-01-02T00:00:00+00:01")?.checked_sub_period(p("P1D")?),
+            None
+        );
+        Ok(())
     }
 
     #[test]
@@ -0,0 +1,312 @@
+use std::{
+    convert::TryFrom,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    Clock, ClockError, DateTime, Instant, Nanosecond, ParseInstantError, SystemClock,
+    TryFromInstantError,
+};
+
+// Instant with the fraction of a second (nanosecond precision)
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct PreciseInstant {
+    instant: Instant,
+    nanosecond: Nanosecond,
+}
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+impl PreciseInstant {
+    pub fn new(instant: Instant, nanosecond: Nanosecond) -> Self {
+        Self {
+            instant,
+            nanosecond,
+        }
+    }
+
+    // panics if the system clock is out of range (see PreciseInstant::try_now)
+    pub fn now() -> Self {
+        Self::try_now().expect("out of range")
+    }
+
+    pub fn now_in<C: Clock + ?Sized>(clock: &C) -> Result<Self, ClockError> {
+        clock.now()
+    }
+
+    pub fn try_now() -> Result<Self, ClockError> {
+        Self::now_in(&SystemClock)
+    }
+
+    // e.g. JavaScript Date#getTime
+    pub fn from_unix_millis(millis: i64) -> Result<Self, TryFromInstantError> {
+        Self::from_unix_nanos(i128::from(millis) * 1_000_000)
+    }
+
+    pub fn from_unix_micros(micros: i64) -> Result<Self, TryFromInstantError> {
+        Self::from_unix_nanos(i128::from(micros) * 1_000)
+    }
+
+    pub fn from_unix_nanos(nanos: i128) -> Result<Self, TryFromInstantError> {
+        let seconds = i64::try_from(nanos.div_euclid(NANOS_PER_SECOND))
+            .map_err(|_| TryFromInstantError::OutOfRange)?;
+        let instant = Instant::try_from(seconds)?;
+        let nanosecond = Nanosecond::try_from(nanos.rem_euclid(NANOS_PER_SECOND) as u32)
+            .expect("nanosecond is [0, 1e9)");
+        Ok(Self::new(instant, nanosecond))
+    }
+
+    pub fn nanosecond(&self) -> Nanosecond {
+        self.nanosecond
+    }
+
+    // the fraction of a second is discarded
+    pub fn truncate(&self) -> Instant {
+        self.instant
+    }
+
+    // the fraction of a second is truncated
+    pub fn unix_millis(&self) -> i64 {
+        i64::from(self.instant) * 1_000 + i64::from(u32::from(self.nanosecond) / 1_000_000)
+    }
+
+    // the fraction of a microsecond is truncated
+    pub fn unix_micros(&self) -> i64 {
+        i64::from(self.instant) * 1_000_000 + i64::from(u32::from(self.nanosecond) / 1_000)
+    }
+
+    pub fn unix_nanos(&self) -> i128 {
+        i128::from(i64::from(self.instant)) * NANOS_PER_SECOND
+            + i128::from(u32::from(self.nanosecond))
+    }
+
+    pub fn checked_add_nanos(&self, nanos: i128) -> Option<Self> {
+        self.unix_nanos()
+            .checked_add(nanos)
+            .and_then(|nanos| Self::from_unix_nanos(nanos).ok())
+    }
+
+    pub fn checked_sub_nanos(&self, nanos: i128) -> Option<Self> {
+        self.unix_nanos()
+            .checked_sub(nanos)
+            .and_then(|nanos| Self::from_unix_nanos(nanos).ok())
+    }
+
+    // negative if other is later
+    pub fn nanos_since(&self, other: PreciseInstant) -> i128 {
+        self.unix_nanos() - other.unix_nanos()
+    }
+}
+
+impl std::fmt::Display for PreciseInstant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = self.instant.to_string();
+        let date_time = s.strip_suffix('Z').ok_or(std::fmt::Error)?;
+        match self.nanosecond.to_fraction_string() {
+            Some(fraction) => write!(f, "{}.{}Z", date_time, fraction),
+            None => write!(f, "{}Z", date_time),
+        }
+    }
+}
+
+impl std::str::FromStr for PreciseInstant {
+    type Err = ParseInstantError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s
+            .strip_suffix('Z')
+            .ok_or(ParseInstantError::InvalidFormat)?;
+        let date_time = DateTime::from_str(s).map_err(|_| ParseInstantError::InvalidFormat)?;
+        let instant = Instant::try_from(date_time.seconds_from_unix_epoch())
+            .map_err(|_| ParseInstantError::OutOfRange)?;
+        Ok(Self::new(instant, date_time.time().nanosecond()))
+    }
+}
+
+impl From<Instant> for PreciseInstant {
+    fn from(instant: Instant) -> Self {
+        Self::new(instant, Nanosecond::min())
+    }
+}
+
+impl TryFrom<SystemTime> for PreciseInstant {
+    type Error = TryFromInstantError;
+
+    fn try_from(value: SystemTime) -> Result<Self, Self::Error> {
+        let duration = value
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| TryFromInstantError::OutOfRange)?;
+        let instant = Instant::try_from(duration.as_secs())?;
+        let nanosecond =
+            Nanosecond::try_from(duration.subsec_nanos()).expect("subsec_nanos is [0, 1e9)");
+        Ok(Self::new(instant, nanosecond))
+    }
+}
+
+impl From<PreciseInstant> for SystemTime {
+    fn from(value: PreciseInstant) -> Self {
+        SystemTime::from(value.instant) + Duration::from_nanos(u64::from(value.nanosecond))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::ManualClock;
+
+    use super::*;
+
+    #[test]
+    fn now_test() {
+        assert_ne!(PreciseInstant::now().to_string(), "");
+    }
+
+    #[test]
+    fn now_in_test() -> anyhow::Result<()> {
+        let now = PreciseInstant::from_str("2021-02-03T04:05:06.789Z")?;
+        assert_eq!(PreciseInstant::now_in(&ManualClock::new(now))?, now);
+        assert!(PreciseInstant::try_now()? >= now);
+        Ok(())
+    }
+
+    #[test]
+    fn unix_millis_conversion_test() -> anyhow::Result<()> {
+        let f = |millis: i64| -> anyhow::Result<String> {
+            Ok(PreciseInstant::from_unix_millis(millis)?.to_string())
+        };
+        assert_eq!(f(0)?, "1970-01-01T00:00:00Z");
+        assert_eq!(f(1_612_325_106_789)?, "2021-02-03T04:05:06.789Z");
+        assert_eq!(f(253_402_300_799_999)?, "9999-12-31T23:59:59.999Z");
+        assert!(PreciseInstant::from_unix_millis(-1).is_err());
+        assert!(PreciseInstant::from_unix_millis(253_402_300_800_000).is_err());
+        assert_eq!(
+            PreciseInstant::from_unix_millis(1_612_325_106_789)?.unix_millis(),
+            1_612_325_106_789
+        );
+        assert_eq!(
+            PreciseInstant::from_str("2021-02-03T04:05:06.789999Z")?.unix_millis(),
+            1_612_325_106_789
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unix_micros_conversion_test() -> anyhow::Result<()> {
+        let precise_instant = PreciseInstant::from_unix_micros(1_612_325_106_789_012)?;
+        assert_eq!(precise_instant.to_string(), "2021-02-03T04:05:06.789012Z");
+        assert_eq!(precise_instant.unix_micros(), 1_612_325_106_789_012);
+        assert!(PreciseInstant::from_unix_micros(-1).is_err());
+        assert!(PreciseInstant::from_unix_micros(i64::MAX).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn unix_nanos_conversion_test() -> anyhow::Result<()> {
+        let precise_instant = PreciseInstant::from_unix_nanos(1_612_325_106_789_012_345)?;
+        assert_eq!(
+            precise_instant.to_string(),
+            "2021-02-03T04:05:06.789012345Z"
+        );
+        assert_eq!(precise_instant.unix_nanos(), 1_612_325_106_789_012_345);
+        assert_eq!(
+            precise_instant.nanosecond(),
+            Nanosecond::try_from(789_012_345_u32)?
+        );
+        assert!(PreciseInstant::from_unix_nanos(-1).is_err());
+        assert!(PreciseInstant::from_unix_nanos(i128::MAX).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn system_time_conversion_test() -> anyhow::Result<()> {
+        let system_time = UNIX_EPOCH + Duration::new(1_612_325_106, 789_012_345);
+        let precise_instant = PreciseInstant::try_from(system_time)?;
+        assert_eq!(
+            precise_instant.to_string(),
+            "2021-02-03T04:05:06.789012345Z"
+        );
+        assert_eq!(SystemTime::from(precise_instant), system_time);
+        assert_eq!(
+            PreciseInstant::try_from(UNIX_EPOCH - Duration::from_nanos(1)),
+            Err(TryFromInstantError::OutOfRange)
+        );
+        assert_eq!(
+            PreciseInstant::try_from(UNIX_EPOCH + Duration::from_secs(253_402_300_800)),
+            Err(TryFromInstantError::OutOfRange)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        let f =
+            |s: &str| -> anyhow::Result<String> { Ok(PreciseInstant::from_str(s)?.to_string()) };
+        assert_eq!(f("1970-01-01T00:00:00Z")?, "1970-01-01T00:00:00Z");
+        assert_eq!(f("2021-02-03T04:05:06.5Z")?, "2021-02-03T04:05:06.500Z");
+        assert_eq!(
+            f("9999-12-31T23:59:59.999999999Z")?,
+            "9999-12-31T23:59:59.999999999Z"
+        );
+        assert_eq!(
+            PreciseInstant::from_str("2021-02-03T04:05:06.5"),
+            Err(ParseInstantError::InvalidFormat)
+        );
+        assert_eq!(
+            PreciseInstant::from_str("2021-02-03T04:05:06.5+09:00"),
+            Err(ParseInstantError::InvalidFormat)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn truncate_test() -> anyhow::Result<()> {
+        let precise_instant = PreciseInstant::from_str("2021-02-03T04:05:06.999Z")?;
+        assert_eq!(
+            precise_instant.truncate(),
+            Instant::from_str("2021-02-03T04:05:06Z")?
+        );
+        let instant = Instant::from_str("2021-02-03T04:05:06Z")?;
+        assert_eq!(PreciseInstant::from(instant).truncate(), instant);
+        assert_eq!(
+            PreciseInstant::new(instant, Nanosecond::try_from(1_u32)?).to_string(),
+            "2021-02-03T04:05:06.000000001Z"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn arithmetic_test() -> anyhow::Result<()> {
+        let t = |s: &str| PreciseInstant::from_str(s);
+        assert_eq!(
+            t("2021-02-03T04:05:06.999Z")?.checked_add_nanos(1_000_000),
+            Some(t("2021-02-03T04:05:07Z")?)
+        );
+        assert_eq!(
+            t("2021-02-03T04:05:07Z")?.checked_sub_nanos(1),
+            Some(t("2021-02-03T04:05:06.999999999Z")?)
+        );
+        assert_eq!(t("1970-01-01T00:00:00Z")?.checked_sub_nanos(1), None);
+        assert_eq!(
+            t("9999-12-31T23:59:59.999999999Z")?.checked_add_nanos(1),
+            None
+        );
+        assert_eq!(
+            t("2021-02-03T04:05:07.1Z")?.nanos_since(t("2021-02-03T04:05:06.9Z")?),
+            200_000_000
+        );
+        assert_eq!(
+            t("2021-02-03T04:05:06.9Z")?.nanos_since(t("2021-02-03T04:05:07.1Z")?),
+            -200_000_000
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ord_test() -> anyhow::Result<()> {
+        let t = |s: &str| PreciseInstant::from_str(s);
+        assert!(t("2021-02-03T04:05:06.1Z")? < t("2021-02-03T04:05:06.2Z")?);
+        assert!(t("2021-02-03T04:05:06.999Z")? < t("2021-02-03T04:05:07Z")?);
+        Ok(())
+    }
+}
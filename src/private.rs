@@ -45,6 +45,23 @@ fn date_from_ordinal_date(ordinal_date: (i64, i64)) -> (i64, i64, i64) {
     unreachable!()
 }
 
+pub(crate) fn date_from_days_from_unix_epoch(days_from_unix_epoch: i64) -> (i64, i64, i64) {
+    let days_from_ce = days_from_unix_epoch + DAYS_FROM_CE_TO_UNIX_EPOCH;
+    let ordinal_date = ordinal_date_from_days_from_ce(days_from_ce);
+    date_from_ordinal_date(ordinal_date)
+}
+
+pub(crate) fn date_from_week_date((year, week, day_of_week): (i64, i64, i64)) -> (i64, i64, i64) {
+    // 01-04 is always in the first week of the (week-based) year
+    let days_from_unix_epoch_of_jan4 = days_from_unix_epoch_from_date((year, 1, 4));
+    let day_of_week_of_jan4 = day_of_week_from_days_from_unix_epoch(days_from_unix_epoch_of_jan4);
+    let days_from_unix_epoch_of_first_monday =
+        days_from_unix_epoch_of_jan4 - (day_of_week_of_jan4 - 1);
+    date_from_days_from_unix_epoch(
+        days_from_unix_epoch_of_first_monday + (week - 1) * 7 + (day_of_week - 1),
+    )
+}
+
 pub(crate) fn date_time_string_from_seconds_from_unix_epoch(
     seconds_from_unix_epoch: i64,
 ) -> Result<String, TimestampError> {
@@ -71,13 +88,19 @@ fn days_from_ce_from_year(y: i64) -> i64 {
     y * 365 + y / 4 - y / 100 + y / 400
 }
 
+// 1970-01-01 is Thursday
+// [1, 7] (1: Monday, 7: Sunday)
+pub(crate) fn day_of_week_from_days_from_unix_epoch(days_from_unix_epoch: i64) -> i64 {
+    (days_from_unix_epoch + 3).rem_euclid(7) + 1
+}
+
 pub(crate) fn days_from_unix_epoch_from_date(date: (i64, i64, i64)) -> i64 {
     let ordinal_date = ordinal_date_from_date(date);
     let days_from_ce = days_from_ce_from_ordinal_date(ordinal_date);
     days_from_ce - DAYS_FROM_CE_TO_UNIX_EPOCH
 }
 
-fn is_leap_year(year: i64) -> bool {
+pub(crate) fn is_leap_year(year: i64) -> bool {
     if year < 0 {
         panic!()
     }
@@ -131,7 +154,7 @@ pub(crate) fn seconds_from_midnight_from_time((h, min, s): (i64, i64, i64)) -> i
     (h * 60 + min) * 60 + s
 }
 
-fn time_from_seconds_from_midnight(seconds: i64) -> (i64, i64, i64) {
+pub(crate) fn time_from_seconds_from_midnight(seconds: i64) -> (i64, i64, i64) {
     if !(0..SECONDS_PER_DAY).contains(&seconds) {
         panic!()
     }
@@ -147,6 +170,30 @@ fn time_from_seconds_from_midnight(seconds: i64) -> (i64, i64, i64) {
     (h, min, s)
 }
 
+pub(crate) fn week_date_from_date(date: (i64, i64, i64)) -> (i64, i64, i64) {
+    let (year, day_of_year) = ordinal_date_from_date(date);
+    let day_of_week = day_of_week_from_days_from_unix_epoch(days_from_unix_epoch_from_date(date));
+    let week = (day_of_year - day_of_week + 10) / 7;
+    if week < 1 {
+        (year - 1, weeks_in_year(year - 1), day_of_week)
+    } else if week > weeks_in_year(year) {
+        (year + 1, 1, day_of_week)
+    } else {
+        (year, week, day_of_week)
+    }
+}
+
+// the number of weeks in the week-based year (52 or 53)
+pub(crate) fn weeks_in_year(year: i64) -> i64 {
+    let day_of_week_of_jan1 =
+        day_of_week_from_days_from_unix_epoch(days_from_unix_epoch_from_date((year, 1, 1)));
+    if day_of_week_of_jan1 == 4 || (is_leap_year(year) && day_of_week_of_jan1 == 3) {
+        53
+    } else {
+        52
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -240,6 +287,28 @@ mod tests {
         assert_eq!(date_from_ordinal_date((2000, 366)), (2000, 12, 31));
     }
 
+    #[test]
+    fn date_from_days_from_unix_epoch_test() {
+        let f = date_from_days_from_unix_epoch;
+        assert_eq!(f(-1), (1969, 12, 31));
+        assert_eq!(f(0), (1970, 1, 1));
+        assert_eq!(f(2_932_896), (9999, 12, 31));
+        for d in 0..=2_932_896 {
+            assert_eq!(days_from_unix_epoch_from_date(f(d)), d);
+        }
+    }
+
+    #[test]
+    fn date_from_week_date_test() {
+        // See: week_date_from_date_test
+        let f = date_from_week_date;
+        assert_eq!(f((1970, 1, 1)), (1969, 12, 29));
+        assert_eq!(f((1970, 1, 4)), (1970, 1, 1));
+        assert_eq!(f((2021, 5, 3)), (2021, 2, 3));
+        assert_eq!(f((2020, 53, 5)), (2021, 1, 1));
+        assert_eq!(f((9999, 52, 5)), (9999, 12, 31));
+    }
+
     #[test]
     fn date_time_string_from_seconds_from_unix_epoch_test() -> anyhow::Result<()> {
         let f = date_time_string_from_seconds_from_unix_epoch;
@@ -257,12 +326,35 @@ mod tests {
         // See: ordinal_date_from_days_from_ce_test
     }
 
+    #[test]
+    fn day_of_week_from_days_from_unix_epoch_test() {
+        let f = day_of_week_from_days_from_unix_epoch;
+        assert_eq!(f(-4), 7); // 1969-12-28 Sunday
+        assert_eq!(f(-3), 1); // 1969-12-29 Monday
+        assert_eq!(f(0), 4); // 1970-01-01 Thursday
+        assert_eq!(f(3), 7); // 1970-01-04 Sunday
+        assert_eq!(f(4), 1); // 1970-01-05 Monday
+        for d in 0..=2_932_896 {
+            let naive_date = chrono::NaiveDate::from_num_days_from_ce_opt(
+                (d + DAYS_FROM_CE_TO_UNIX_EPOCH) as i32,
+            )
+            .unwrap();
+            assert_eq!(
+                f(d),
+                i64::from(Datelike::weekday(&naive_date).number_from_monday())
+            );
+        }
+    }
+
     #[test]
     fn days_from_unix_epoch_from_date_test() -> anyhow::Result<()> {
         let f = |y: i64, m: i64, d: i64| days_from_unix_epoch_from_date((y, m, d));
         let g = |y: i64, m: i64, d: i64| {
-            chrono::NaiveDate::from_ymd(y as i32, m as u32, d as u32)
-                .and_hms(0, 0, 0)
+            chrono::NaiveDate::from_ymd_opt(y as i32, m as u32, d as u32)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .and_utc()
                 .timestamp()
                 / SECONDS_PER_DAY
         };
@@ -282,7 +374,9 @@ mod tests {
     #[test]
     fn days_from_ce_from_year_test() -> anyhow::Result<()> {
         let f = days_from_ce_from_year;
-        let g = |y| Datelike::num_days_from_ce(&chrono::NaiveDate::from_ymd(y as i32, 1, 1));
+        let g = |y| {
+            Datelike::num_days_from_ce(&chrono::NaiveDate::from_ymd_opt(y as i32, 1, 1).unwrap())
+        };
         assert_eq!(f(0), 0); // 0000-12-31 ... 0 d
         assert_eq!(g(1), 1); // 0001-01-01 ... 1 d
         assert_eq!(f(1), 365); // 0001-12-31 ... 365 d
@@ -311,7 +405,7 @@ mod tests {
             let ordinal_date = ordinal_date_from_days_from_ce(d);
             let (y1, m1, d1) = date_from_ordinal_date(ordinal_date);
             let (y2, m2, d2) = {
-                let naive_date = chrono::NaiveDate::from_num_days_from_ce(d as i32);
+                let naive_date = chrono::NaiveDate::from_num_days_from_ce_opt(d as i32).unwrap();
                 (
                     Datelike::year(&naive_date) as i64,
                     Datelike::month(&naive_date) as i64,
@@ -327,7 +421,7 @@ mod tests {
         for d in 1..=(253_402_300_799 / 86_400) {
             let (y1, d1) = ordinal_date_from_days_from_ce(d);
             let (y2, d2) = {
-                let naive_date = chrono::NaiveDate::from_num_days_from_ce(d as i32);
+                let naive_date = chrono::NaiveDate::from_num_days_from_ce_opt(d as i32).unwrap();
                 (
                     Datelike::year(&naive_date) as i64,
                     Datelike::ordinal(&naive_date) as i64,
@@ -345,7 +439,7 @@ mod tests {
     #[test]
     fn seconds_from_midnight_from_time_test() {
         for s in 0..86_400 {
-            let time = chrono::NaiveTime::from_num_seconds_from_midnight(s as u32, 0);
+            let time = chrono::NaiveTime::from_num_seconds_from_midnight_opt(s as u32, 0).unwrap();
             let t1 = (
                 time.hour() as i64,
                 time.minute() as i64,
@@ -361,4 +455,39 @@ mod tests {
     fn time_from_seconds_from_midnight_test() {
         // See: seconds_from_midnight_from_time_test
     }
+
+    #[test]
+    fn week_date_from_date_test() {
+        for d in 0..=2_932_896 {
+            let date = date_from_days_from_unix_epoch(d);
+            let week_date = week_date_from_date(date);
+            let naive_date = chrono::NaiveDate::from_num_days_from_ce_opt(
+                (d + DAYS_FROM_CE_TO_UNIX_EPOCH) as i32,
+            )
+            .unwrap();
+            let iso_week = Datelike::iso_week(&naive_date);
+            assert_eq!(
+                week_date,
+                (
+                    i64::from(iso_week.year()),
+                    i64::from(iso_week.week()),
+                    i64::from(Datelike::weekday(&naive_date).number_from_monday())
+                )
+            );
+            assert_eq!(date_from_week_date(week_date), date);
+        }
+    }
+
+    #[test]
+    fn weeks_in_year_test() {
+        let f = weeks_in_year;
+        assert_eq!(f(1970), 53);
+        assert_eq!(f(2020), 53);
+        assert_eq!(f(2021), 52);
+        assert_eq!(f(9999), 52);
+        for y in 1970..=9999 {
+            let naive_date = chrono::NaiveDate::from_ymd_opt(y as i32, 12, 28).unwrap();
+            assert_eq!(f(y), i64::from(Datelike::iso_week(&naive_date).week()));
+        }
+    }
 }
@@ -0,0 +1,89 @@
+use std::convert::TryFrom;
+use std::ops::{Bound, RangeBounds};
+
+// a type that can be iterated by pred / succ
+//
+// std::iter::Step is unstable, so this crate uses its own trait.
+pub(crate) trait Step: Copy + Ord {
+    fn first() -> Self;
+
+    fn last() -> Self;
+
+    // the number of succ from Self::first()
+    fn position(&self) -> u32;
+
+    fn pred(&self) -> Option<Self>;
+
+    fn succ(&self) -> Option<Self>;
+}
+
+// e.g. CalendarDate::range(a..=b), Month::range(..).rev()
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RangeIter<T> {
+    front: T,
+    back: T,
+    len: usize,
+}
+
+pub(crate) fn range_iter<T: Step, R: RangeBounds<T>>(range: R) -> RangeIter<T> {
+    let start = match range.start_bound() {
+        Bound::Included(start) => Some(*start),
+        Bound::Excluded(start) => start.succ(),
+        Bound::Unbounded => Some(T::first()),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => Some(*end),
+        Bound::Excluded(end) => end.pred(),
+        Bound::Unbounded => Some(T::last()),
+    };
+    match (start, end) {
+        (Some(start), Some(end)) if start <= end => RangeIter {
+            front: start,
+            back: end,
+            len: usize::try_from(end.position() - start.position()).expect("position is u32") + 1,
+        },
+        _ => RangeIter {
+            front: T::first(),
+            back: T::first(),
+            len: 0,
+        },
+    }
+}
+
+impl<T: Step> Iterator for RangeIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let item = self.front;
+        self.len -= 1;
+        if self.len > 0 {
+            self.front = self.front.succ().expect("front < back");
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T: Step> DoubleEndedIterator for RangeIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let item = self.back;
+        self.len -= 1;
+        if self.len > 0 {
+            self.back = self.back.pred().expect("front < back");
+        }
+        Some(item)
+    }
+}
+
+impl<T: Step> ExactSizeIterator for RangeIter<T> {}
+
+impl<T: Step> std::iter::FusedIterator for RangeIter<T> {}
@@ -0,0 +1,13 @@
+mod frequency;
+mod occurrences;
+mod offset_occurrences;
+mod recurrence_rule;
+mod until;
+mod weekday_num;
+
+pub use self::frequency::*;
+pub use self::occurrences::*;
+pub use self::offset_occurrences::*;
+pub use self::recurrence_rule::*;
+pub use self::until::*;
+pub use self::weekday_num::*;
@@ -0,0 +1,75 @@
+use thiserror::Error;
+
+// RFC 5545 FREQ
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Frequency {
+    Secondly,
+    Minutely,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParseFrequencyError {
+    #[error("invalid format")]
+    InvalidFormat,
+}
+
+impl std::fmt::Display for Frequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Frequency::Secondly => "SECONDLY",
+                Frequency::Minutely => "MINUTELY",
+                Frequency::Hourly => "HOURLY",
+                Frequency::Daily => "DAILY",
+                Frequency::Weekly => "WEEKLY",
+                Frequency::Monthly => "MONTHLY",
+                Frequency::Yearly => "YEARLY",
+            }
+        )
+    }
+}
+
+impl std::str::FromStr for Frequency {
+    type Err = ParseFrequencyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "SECONDLY" => Ok(Frequency::Secondly),
+            "MINUTELY" => Ok(Frequency::Minutely),
+            "HOURLY" => Ok(Frequency::Hourly),
+            "DAILY" => Ok(Frequency::Daily),
+            "WEEKLY" => Ok(Frequency::Weekly),
+            "MONTHLY" => Ok(Frequency::Monthly),
+            "YEARLY" => Ok(Frequency::Yearly),
+            _ => Err(Self::Err::InvalidFormat),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        type E = ParseFrequencyError;
+        let f = |s: &str| Frequency::from_str(s);
+        for s in &[
+            "SECONDLY", "MINUTELY", "HOURLY", "DAILY", "WEEKLY", "MONTHLY", "YEARLY",
+        ] {
+            assert_eq!(f(s)?.to_string(), *s);
+        }
+        assert_eq!(f("daily"), Err(E::InvalidFormat));
+        assert_eq!(f(""), Err(E::InvalidFormat));
+        Ok(())
+    }
+}
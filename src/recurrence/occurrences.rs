@@ -0,0 +1,675 @@
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use thiserror::Error;
+
+use crate::private::days_from_unix_epoch_from_date;
+use crate::{CalendarDate, DateTime, Days, Month, Year, YearMonth};
+
+use super::frequency::Frequency;
+use super::recurrence_rule::RecurrenceRule;
+use super::until::Until;
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum OccurrenceError {
+    #[error("out of range")]
+    OutOfRange,
+}
+
+// a lazy iterator of occurrences (local date time)
+//
+// yields Err(OccurrenceError::OutOfRange) once if the rule runs off the end
+// (9999-12-31T23:59:59) before COUNT or UNTIL is reached
+#[derive(Clone, Debug)]
+pub struct Occurrences {
+    rule: RecurrenceRule,
+    start: DateTime,
+    // seconds from unix epoch (local date time)
+    until: Option<i64>,
+    // YEARLY: year
+    // MONTHLY: months from CE
+    // WEEKLY: days from unix epoch (week start)
+    // DAILY: days from unix epoch
+    // HOURLY, MINUTELY, SECONDLY: seconds from unix epoch
+    period: i64,
+    buffer: VecDeque<DateTime>,
+    emitted: u32,
+    finished: bool,
+}
+
+impl Occurrences {
+    // offset_in_seconds: UNTIL with 'Z' + offset_in_seconds = local date time
+    pub(crate) fn new(rule: RecurrenceRule, start: DateTime, offset_in_seconds: i64) -> Self {
+        let until = rule.until().map(|until| match until {
+            Until::Date(date) => days(date) * 86_400 + 86_399,
+            Until::DateTime(date_time) => date_time.seconds_from_unix_epoch(),
+            Until::Utc(date_time) => date_time.seconds_from_unix_epoch() + offset_in_seconds,
+        });
+        let date = start.date();
+        let period = match rule.frequency() {
+            Frequency::Yearly => i64::from(date.year()),
+            Frequency::Monthly => date.year_month().months_from_ce(),
+            Frequency::Weekly => days(date) - days_from_week_start(date, &rule),
+            Frequency::Daily => days(date),
+            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly => {
+                start.seconds_from_unix_epoch()
+            }
+        };
+        Self {
+            rule,
+            start,
+            until,
+            period,
+            buffer: VecDeque::new(),
+            emitted: 0,
+            finished: false,
+        }
+    }
+
+    // the first second of the current period (seconds from unix epoch)
+    fn period_start(&self) -> i64 {
+        match self.rule.frequency() {
+            Frequency::Yearly => days_from_unix_epoch_from_date((self.period, 1, 1)) * 86_400,
+            Frequency::Monthly => {
+                let (year, month) = (
+                    self.period.div_euclid(12) + 1,
+                    self.period.rem_euclid(12) + 1,
+                );
+                days_from_unix_epoch_from_date((year, month, 1)) * 86_400
+            }
+            Frequency::Weekly | Frequency::Daily => self.period * 86_400,
+            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly => self.period,
+        }
+    }
+
+    // None if the current period is out of range
+    fn candidates(&self) -> Option<Vec<DateTime>> {
+        let rule = &self.rule;
+        let dates = match rule.frequency() {
+            Frequency::Yearly => self.year_dates(Year::try_from(self.period).ok()?),
+            Frequency::Monthly => {
+                let year_month = YearMonth::from_months_from_ce(self.period)?;
+                if rule.by_month().is_empty() || rule.by_month().contains(&year_month.month()) {
+                    self.month_dates(year_month)
+                } else {
+                    vec![]
+                }
+            }
+            Frequency::Weekly => {
+                // the first week may start before the unix epoch
+                date_from_days(self.period.max(0))?;
+                (self.period..self.period + 7)
+                    .filter_map(date_from_days)
+                    .filter(|date| {
+                        if rule.by_day().is_empty() {
+                            date.day_of_week() == self.start.date().day_of_week()
+                        } else {
+                            rule.by_day()
+                                .iter()
+                                .any(|d| d.day_of_week() == date.day_of_week())
+                        }
+                    })
+                    .filter(|date| self.is_in_by_month(date))
+                    .collect()
+            }
+            Frequency::Daily => {
+                let date = date_from_days(self.period)?;
+                if self.is_in_limits(&date) {
+                    vec![date]
+                } else {
+                    vec![]
+                }
+            }
+            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly => {
+                let date_time = DateTime::from_seconds_from_unix_epoch(self.period)?;
+                let date_times = if self.is_in_limits(&date_time.date()) {
+                    vec![date_time]
+                } else {
+                    vec![]
+                };
+                return Some(self.select_by_set_pos(date_times));
+            }
+        };
+        let date_times = dates
+            .into_iter()
+            .map(|date| DateTime::new(date, self.start.time()))
+            .collect();
+        Some(self.select_by_set_pos(date_times))
+    }
+
+    fn advance(&mut self) {
+        let interval = i64::from(self.rule.interval());
+        let step = match self.rule.frequency() {
+            Frequency::Yearly | Frequency::Monthly | Frequency::Daily => interval,
+            Frequency::Weekly => interval * 7,
+            Frequency::Hourly => interval * 3_600,
+            Frequency::Minutely => interval * 60,
+            Frequency::Secondly => interval,
+        };
+        let step_count = match self.rule.frequency() {
+            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly => {
+                // skip the rest of the day if the day is not in limits
+                match date_from_days(self.period.div_euclid(86_400)) {
+                    Some(date) if !self.is_in_limits(&date) => {
+                        let next_day = (self.period.div_euclid(86_400) + 1) * 86_400;
+                        (next_day - self.period + step - 1) / step
+                    }
+                    _ => 1,
+                }
+            }
+            _ => 1,
+        };
+        self.period += step * step_count;
+    }
+
+    fn year_dates(&self, year: Year) -> Vec<CalendarDate> {
+        let rule = &self.rule;
+        if !rule.by_month().is_empty() {
+            let mut months = rule.by_month().to_vec();
+            months.sort();
+            months.dedup();
+            months
+                .into_iter()
+                .flat_map(|month| self.month_dates(YearMonth::new(year, month)))
+                .collect()
+        } else if !rule.by_month_day().is_empty() {
+            Month::range(..)
+                .flat_map(|month| self.month_dates(YearMonth::new(year, month)))
+                .collect()
+        } else if !rule.by_day().is_empty() {
+            // the n-th day of week in the year
+            let first = CalendarDate::first_date_of_year(year);
+            let last = CalendarDate::last_date_of_year(year);
+            CalendarDate::range(first..=last)
+                .filter(|date| self.is_in_by_day(date, &first, &last))
+                .collect()
+        } else {
+            self.month_dates(YearMonth::new(year, self.start.date().month()))
+        }
+    }
+
+    fn month_dates(&self, year_month: YearMonth) -> Vec<CalendarDate> {
+        let rule = &self.rule;
+        if rule.by_month_day().is_empty() && rule.by_day().is_empty() {
+            // the invalid date (e.g. 2021-02-30) is ignored
+            return CalendarDate::from_ymd(
+                year_month.year(),
+                year_month.month(),
+                self.start.date().day_of_month(),
+            )
+            .into_iter()
+            .collect();
+        }
+        // the n-th day of week in the month
+        let first = CalendarDate::first_date_of_month(year_month);
+        let last = CalendarDate::last_date_of_month(year_month);
+        CalendarDate::range(first..=last)
+            .filter(|date| rule.by_month_day().is_empty() || self.is_in_by_month_day(date))
+            .filter(|date| rule.by_day().is_empty() || self.is_in_by_day(date, &first, &last))
+            .collect()
+    }
+
+    fn is_in_by_day(&self, date: &CalendarDate, first: &CalendarDate, last: &CalendarDate) -> bool {
+        self.rule.by_day().iter().any(|d| {
+            d.day_of_week() == date.day_of_week()
+                && match d.ordinal().map(i64::from) {
+                    None => true,
+                    Some(n) if n > 0 => (days(*date) - days(*first)) / 7 + 1 == n,
+                    Some(n) => (days(*last) - days(*date)) / 7 + 1 == -n,
+                }
+        })
+    }
+
+    fn is_in_by_month(&self, date: &CalendarDate) -> bool {
+        self.rule.by_month().is_empty() || self.rule.by_month().contains(&date.month())
+    }
+
+    fn is_in_by_month_day(&self, date: &CalendarDate) -> bool {
+        let day_of_month = i64::from(date.day_of_month());
+        let last_day_of_month = i64::from(date.year_month().last_day_of_month());
+        self.rule.by_month_day().iter().any(|d| {
+            let d = i64::from(*d);
+            if d > 0 {
+                day_of_month == d
+            } else {
+                day_of_month == last_day_of_month + 1 + d
+            }
+        })
+    }
+
+    // BYMONTH, BYMONTHDAY and BYDAY limit DAILY, HOURLY, MINUTELY and SECONDLY
+    fn is_in_limits(&self, date: &CalendarDate) -> bool {
+        let rule = &self.rule;
+        self.is_in_by_month(date)
+            && (rule.by_month_day().is_empty() || self.is_in_by_month_day(date))
+            && (rule.by_day().is_empty()
+                || rule
+                    .by_day()
+                    .iter()
+                    .any(|d| d.day_of_week() == date.day_of_week()))
+    }
+
+    fn select_by_set_pos(&self, mut date_times: Vec<DateTime>) -> Vec<DateTime> {
+        date_times.sort();
+        date_times.dedup();
+        if self.rule.by_set_pos().is_empty() {
+            return date_times;
+        }
+        let len = i64::try_from(date_times.len()).expect("len <= 366");
+        let mut selected = self
+            .rule
+            .by_set_pos()
+            .iter()
+            .filter_map(|pos| {
+                let pos = i64::from(*pos);
+                let index = if pos > 0 { pos - 1 } else { len + pos };
+                usize::try_from(index)
+                    .ok()
+                    .and_then(|index| date_times.get(index).copied())
+            })
+            .collect::<Vec<DateTime>>();
+        selected.sort();
+        selected.dedup();
+        selected
+    }
+}
+
+impl Iterator for Occurrences {
+    type Item = Result<DateTime, OccurrenceError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.finished {
+                return None;
+            }
+            if let Some(count) = self.rule.count() {
+                if self.emitted >= count {
+                    self.finished = true;
+                    return None;
+                }
+            }
+            if let Some(date_time) = self.buffer.pop_front() {
+                if let Some(until) = self.until {
+                    if date_time.seconds_from_unix_epoch() > until {
+                        self.finished = true;
+                        return None;
+                    }
+                }
+                self.emitted += 1;
+                return Some(Ok(date_time));
+            }
+            if let Some(until) = self.until {
+                if self.period_start() > until {
+                    self.finished = true;
+                    return None;
+                }
+            }
+            match self.candidates() {
+                None => {
+                    self.finished = true;
+                    return Some(Err(OccurrenceError::OutOfRange));
+                }
+                Some(date_times) => {
+                    let start = self.start;
+                    self.buffer
+                        .extend(date_times.into_iter().filter(|dt| *dt >= start));
+                    self.advance();
+                }
+            }
+        }
+    }
+}
+
+impl std::iter::FusedIterator for Occurrences {}
+
+fn date_from_days(days_from_unix_epoch: i64) -> Option<CalendarDate> {
+    Days::try_from(days_from_unix_epoch)
+        .ok()
+        .map(CalendarDate::from_days_from_unix_epoch)
+}
+
+fn days(date: CalendarDate) -> i64 {
+    i64::from(date.days_from_unix_epoch())
+}
+
+// WKST=MO: Monday -> 0, Sunday -> 6
+fn days_from_week_start(date: CalendarDate, rule: &RecurrenceRule) -> i64 {
+    let day_of_week = i64::from(date.day_of_week());
+    let week_start = i64::from(rule.week_start());
+    (day_of_week - week_start).rem_euclid(7)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn f(rule: &str, start: &str) -> anyhow::Result<Vec<String>> {
+        let rule = RecurrenceRule::from_str(rule)?;
+        let start = DateTime::from_str(start)?;
+        Ok(rule
+            .occurrences(start)
+            .take(20)
+            .map(|o| o.map(|dt| dt.to_string()))
+            .collect::<Result<Vec<String>, _>>()?)
+    }
+
+    #[test]
+    fn daily_test() -> anyhow::Result<()> {
+        assert_eq!(
+            f("FREQ=DAILY;COUNT=3", "2021-02-27T09:00:00")?,
+            vec![
+                "2021-02-27T09:00:00",
+                "2021-02-28T09:00:00",
+                "2021-03-01T09:00:00"
+            ]
+        );
+        assert_eq!(
+            f("FREQ=DAILY;INTERVAL=10;COUNT=3", "2021-02-03T09:00:00")?,
+            vec![
+                "2021-02-03T09:00:00",
+                "2021-02-13T09:00:00",
+                "2021-02-23T09:00:00"
+            ]
+        );
+        assert_eq!(
+            f("FREQ=DAILY;UNTIL=20210205", "2021-02-03T09:00:00")?,
+            vec![
+                "2021-02-03T09:00:00",
+                "2021-02-04T09:00:00",
+                "2021-02-05T09:00:00"
+            ]
+        );
+        assert_eq!(
+            f("FREQ=DAILY;UNTIL=20210205T090000", "2021-02-03T09:00:01")?,
+            vec!["2021-02-03T09:00:01", "2021-02-04T09:00:01"]
+        );
+        // every weekday in January
+        assert_eq!(
+            f(
+                "FREQ=DAILY;BYMONTH=1;BYDAY=MO,TU,WE,TH,FR;COUNT=5",
+                "2021-12-30T09:00:00"
+            )?,
+            vec![
+                "2022-01-03T09:00:00",
+                "2022-01-04T09:00:00",
+                "2022-01-05T09:00:00",
+                "2022-01-06T09:00:00",
+                "2022-01-07T09:00:00"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn weekly_test() -> anyhow::Result<()> {
+        // every other week on Tuesday and Thursday, for 8 occurrences
+        assert_eq!(
+            f(
+                "FREQ=WEEKLY;INTERVAL=2;COUNT=8;WKST=SU;BYDAY=TU,TH",
+                "2021-02-02T09:00:00"
+            )?,
+            vec![
+                "2021-02-02T09:00:00",
+                "2021-02-04T09:00:00",
+                "2021-02-16T09:00:00",
+                "2021-02-18T09:00:00",
+                "2021-03-02T09:00:00",
+                "2021-03-04T09:00:00",
+                "2021-03-16T09:00:00",
+                "2021-03-18T09:00:00"
+            ]
+        );
+        // without BYDAY
+        assert_eq!(
+            f("FREQ=WEEKLY;COUNT=3", "2021-02-03T09:00:00")?,
+            vec![
+                "2021-02-03T09:00:00",
+                "2021-02-10T09:00:00",
+                "2021-02-17T09:00:00"
+            ]
+        );
+        // the week of 1970-01-01 (Thursday) starts before the unix epoch
+        assert_eq!(
+            f("FREQ=WEEKLY;BYDAY=MO,FR;COUNT=3", "1970-01-01T00:00:00")?,
+            vec![
+                "1970-01-02T00:00:00",
+                "1970-01-05T00:00:00",
+                "1970-01-09T00:00:00"
+            ]
+        );
+        // WKST changes the result
+        assert_eq!(
+            f(
+                "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO",
+                "2021-08-03T09:00:00"
+            )?,
+            vec![
+                "2021-08-03T09:00:00",
+                "2021-08-08T09:00:00",
+                "2021-08-17T09:00:00",
+                "2021-08-22T09:00:00"
+            ]
+        );
+        assert_eq!(
+            f(
+                "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU",
+                "2021-08-03T09:00:00"
+            )?,
+            vec![
+                "2021-08-03T09:00:00",
+                "2021-08-15T09:00:00",
+                "2021-08-17T09:00:00",
+                "2021-08-29T09:00:00"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn monthly_test() -> anyhow::Result<()> {
+        // the 31st is skipped in short months
+        assert_eq!(
+            f("FREQ=MONTHLY;COUNT=3", "2021-01-31T09:00:00")?,
+            vec![
+                "2021-01-31T09:00:00",
+                "2021-03-31T09:00:00",
+                "2021-05-31T09:00:00"
+            ]
+        );
+        // the first Friday
+        assert_eq!(
+            f("FREQ=MONTHLY;COUNT=3;BYDAY=1FR", "2021-02-01T09:00:00")?,
+            vec![
+                "2021-02-05T09:00:00",
+                "2021-03-05T09:00:00",
+                "2021-04-02T09:00:00"
+            ]
+        );
+        // the first and the last Sunday, every other month
+        assert_eq!(
+            f(
+                "FREQ=MONTHLY;INTERVAL=2;COUNT=4;BYDAY=1SU,-1SU",
+                "2021-01-01T09:00:00"
+            )?,
+            vec![
+                "2021-01-03T09:00:00",
+                "2021-01-31T09:00:00",
+                "2021-03-07T09:00:00",
+                "2021-03-28T09:00:00"
+            ]
+        );
+        // the last day of the month
+        assert_eq!(
+            f("FREQ=MONTHLY;COUNT=3;BYMONTHDAY=-1", "2021-01-15T09:00:00")?,
+            vec![
+                "2021-01-31T09:00:00",
+                "2021-02-28T09:00:00",
+                "2021-03-31T09:00:00"
+            ]
+        );
+        // Friday the 13th
+        assert_eq!(
+            f(
+                "FREQ=MONTHLY;COUNT=3;BYDAY=FR;BYMONTHDAY=13",
+                "2021-01-01T00:00:00"
+            )?,
+            vec![
+                "2021-08-13T00:00:00",
+                "2022-05-13T00:00:00",
+                "2023-01-13T00:00:00"
+            ]
+        );
+        // the last weekday of the month
+        assert_eq!(
+            f(
+                "FREQ=MONTHLY;COUNT=3;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1",
+                "2021-01-01T18:00:00"
+            )?,
+            vec![
+                "2021-01-29T18:00:00",
+                "2021-02-26T18:00:00",
+                "2021-03-31T18:00:00"
+            ]
+        );
+        // BYMONTH limits MONTHLY
+        assert_eq!(
+            f("FREQ=MONTHLY;COUNT=3;BYMONTH=1,7", "2021-02-10T00:00:00")?,
+            vec![
+                "2021-07-10T00:00:00",
+                "2022-01-10T00:00:00",
+                "2022-07-10T00:00:00"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn yearly_test() -> anyhow::Result<()> {
+        assert_eq!(
+            f("FREQ=YEARLY;COUNT=3", "2020-02-29T00:00:00")?,
+            vec![
+                "2020-02-29T00:00:00",
+                "2024-02-29T00:00:00",
+                "2028-02-29T00:00:00"
+            ]
+        );
+        assert_eq!(
+            f("FREQ=YEARLY;COUNT=4;BYMONTH=6,7", "2021-06-10T09:00:00")?,
+            vec![
+                "2021-06-10T09:00:00",
+                "2021-07-10T09:00:00",
+                "2022-06-10T09:00:00",
+                "2022-07-10T09:00:00"
+            ]
+        );
+        // the 20th Monday of the year
+        assert_eq!(
+            f("FREQ=YEARLY;COUNT=3;BYDAY=20MO", "2021-01-01T09:00:00")?,
+            vec![
+                "2021-05-17T09:00:00",
+                "2022-05-16T09:00:00",
+                "2023-05-15T09:00:00"
+            ]
+        );
+        // the last Thursday of November (US Thanksgiving)
+        assert_eq!(
+            f(
+                "FREQ=YEARLY;COUNT=3;BYMONTH=11;BYDAY=-1TH",
+                "2021-01-01T00:00:00"
+            )?,
+            vec![
+                "2021-11-25T00:00:00",
+                "2022-11-24T00:00:00",
+                "2023-11-30T00:00:00"
+            ]
+        );
+        // the first and the last day of every month
+        assert_eq!(
+            f("FREQ=YEARLY;COUNT=4;BYMONTHDAY=1,-1", "2021-01-01T00:00:00")?,
+            vec![
+                "2021-01-01T00:00:00",
+                "2021-01-31T00:00:00",
+                "2021-02-01T00:00:00",
+                "2021-02-28T00:00:00"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sub_daily_test() -> anyhow::Result<()> {
+        assert_eq!(
+            f("FREQ=HOURLY;INTERVAL=3;COUNT=3", "2021-02-03T22:00:00")?,
+            vec![
+                "2021-02-03T22:00:00",
+                "2021-02-04T01:00:00",
+                "2021-02-04T04:00:00"
+            ]
+        );
+        assert_eq!(
+            f("FREQ=MINUTELY;INTERVAL=15;COUNT=3", "2021-02-03T09:50:00")?,
+            vec![
+                "2021-02-03T09:50:00",
+                "2021-02-03T10:05:00",
+                "2021-02-03T10:20:00"
+            ]
+        );
+        assert_eq!(
+            f("FREQ=SECONDLY;COUNT=2", "2021-02-03T23:59:59")?,
+            vec!["2021-02-03T23:59:59", "2021-02-04T00:00:00"]
+        );
+        // skips days not in BYDAY (steps are anchored at the start)
+        assert_eq!(
+            f(
+                "FREQ=HOURLY;INTERVAL=5;BYDAY=MO;COUNT=3",
+                "2021-02-06T22:00:00"
+            )?,
+            vec![
+                "2021-02-08T04:00:00",
+                "2021-02-08T09:00:00",
+                "2021-02-08T14:00:00"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn out_of_range_test() -> anyhow::Result<()> {
+        let rule = RecurrenceRule::from_str("FREQ=YEARLY")?;
+        let occurrences = rule
+            .occurrences(DateTime::from_str("9998-02-03T00:00:00")?)
+            .collect::<Vec<Result<DateTime, OccurrenceError>>>();
+        assert_eq!(
+            occurrences,
+            vec![
+                Ok(DateTime::from_str("9998-02-03T00:00:00")?),
+                Ok(DateTime::from_str("9999-02-03T00:00:00")?),
+                Err(OccurrenceError::OutOfRange)
+            ]
+        );
+
+        let rule = RecurrenceRule::from_str("FREQ=DAILY;COUNT=5")?;
+        let mut occurrences = rule.occurrences(DateTime::from_str("9999-12-30T00:00:00")?);
+        assert!(matches!(occurrences.next(), Some(Ok(_))));
+        assert!(matches!(occurrences.next(), Some(Ok(_))));
+        assert_eq!(occurrences.next(), Some(Err(OccurrenceError::OutOfRange)));
+        assert_eq!(occurrences.next(), None);
+
+        // COUNT is reached before the end
+        let rule = RecurrenceRule::from_str("FREQ=DAILY;COUNT=2")?;
+        let occurrences = rule.occurrences(DateTime::from_str("9999-12-30T00:00:00")?);
+        assert_eq!(occurrences.filter(|o| o.is_err()).count(), 0);
+
+        // UNTIL is reached before the end
+        let rule = RecurrenceRule::from_str("FREQ=WEEKLY;UNTIL=99991231")?;
+        let occurrences = rule.occurrences(DateTime::from_str("9999-12-01T00:00:00")?);
+        assert_eq!(occurrences.filter(|o| o.is_err()).count(), 0);
+
+        // no occurrence (February 30th)
+        let rule = RecurrenceRule::from_str("FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=30")?;
+        let mut occurrences = rule.occurrences(DateTime::from_str("2021-01-01T00:00:00")?);
+        assert_eq!(occurrences.next(), Some(Err(OccurrenceError::OutOfRange)));
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+use std::convert::TryFrom;
+
+use crate::{Instant, OffsetDateTime, TimeZoneOffset};
+
+use super::occurrences::{OccurrenceError, Occurrences};
+use super::recurrence_rule::RecurrenceRule;
+
+// a lazy iterator of occurrences (date time with a fixed offset)
+//
+// yields Err(OccurrenceError::OutOfRange) once if the rule runs off the end
+#[derive(Clone, Debug)]
+pub struct OffsetOccurrences {
+    occurrences: Occurrences,
+    offset: TimeZoneOffset,
+    finished: bool,
+}
+
+impl OffsetOccurrences {
+    pub(crate) fn new(rule: RecurrenceRule, start: OffsetDateTime) -> Self {
+        let offset = start.offset();
+        let offset_in_seconds = i64::from(offset.offset_in_minutes()) * 60;
+        Self {
+            occurrences: Occurrences::new(rule, start.date_time(), offset_in_seconds),
+            offset,
+            finished: false,
+        }
+    }
+}
+
+impl Iterator for OffsetOccurrences {
+    type Item = Result<OffsetDateTime, OccurrenceError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let result = self.occurrences.next()?.and_then(|date_time| {
+            // the local date time is in range, but the instant may be out of range
+            let timestamp = date_time.seconds_from_unix_epoch()
+                - i64::from(self.offset.offset_in_minutes()) * 60;
+            Instant::try_from(timestamp).map_err(|_| OccurrenceError::OutOfRange)?;
+            Ok(OffsetDateTime::new(date_time, self.offset))
+        });
+        if result.is_err() {
+            self.finished = true;
+        }
+        Some(result)
+    }
+}
+
+impl std::iter::FusedIterator for OffsetOccurrences {}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn offset_occurrences_test() -> anyhow::Result<()> {
+        let f = |rule: &str, start: &str| -> anyhow::Result<Vec<Result<String, OccurrenceError>>> {
+            let rule = RecurrenceRule::from_str(rule)?;
+            let start = OffsetDateTime::from_str(start)?;
+            Ok(rule
+                .offset_occurrences(start)
+                .take(10)
+                .map(|o| o.map(|odt| odt.to_string()))
+                .collect())
+        };
+        assert_eq!(
+            f("FREQ=DAILY;COUNT=2", "2021-02-03T09:00:00+09:00")?,
+            vec![
+                Ok("2021-02-03T09:00:00+09:00".to_string()),
+                Ok("2021-02-04T09:00:00+09:00".to_string())
+            ]
+        );
+        // UNTIL with 'Z' is an instant (2021-02-04T09:00:00+09:00)
+        assert_eq!(
+            f(
+                "FREQ=DAILY;UNTIL=20210204T000000Z",
+                "2021-02-03T09:00:00+09:00"
+            )?,
+            vec![
+                Ok("2021-02-03T09:00:00+09:00".to_string()),
+                Ok("2021-02-04T09:00:00+09:00".to_string())
+            ]
+        );
+        assert_eq!(
+            f(
+                "FREQ=DAILY;UNTIL=20210203T235959Z",
+                "2021-02-03T09:00:00+09:00"
+            )?,
+            vec![Ok("2021-02-03T09:00:00+09:00".to_string())]
+        );
+        // UNTIL without 'Z' is a local date time
+        assert_eq!(
+            f(
+                "FREQ=DAILY;UNTIL=20210204T090000",
+                "2021-02-03T09:00:00+09:00"
+            )?,
+            vec![
+                Ok("2021-02-03T09:00:00+09:00".to_string()),
+                Ok("2021-02-04T09:00:00+09:00".to_string())
+            ]
+        );
+        // the local date time is in range, but the instant is out of range
+        assert_eq!(
+            f("FREQ=HOURLY", "9999-12-31T22:00:00-01:00")?,
+            vec![
+                Ok("9999-12-31T22:00:00-01:00".to_string()),
+                Err(OccurrenceError::OutOfRange)
+            ]
+        );
+        Ok(())
+    }
+}
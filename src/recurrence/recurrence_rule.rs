@@ -0,0 +1,307 @@
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+use thiserror::Error;
+
+use crate::{DateTime, DayOfWeek, Month, OffsetDateTime};
+
+use super::frequency::Frequency;
+use super::occurrences::Occurrences;
+use super::offset_occurrences::OffsetOccurrences;
+use super::until::Until;
+use super::weekday_num::{day_of_week_code, day_of_week_from_code, WeekdayNum};
+
+// RFC 5545 RRULE (e.g. FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1)
+//
+// supported: FREQ, INTERVAL, COUNT, UNTIL, BYDAY, BYMONTHDAY, BYMONTH, BYSETPOS, WKST
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecurrenceRule {
+    frequency: Frequency,
+    interval: u32,
+    count: Option<u32>,
+    until: Option<Until>,
+    by_day: Vec<WeekdayNum>,
+    by_month_day: Vec<i8>,
+    by_month: Vec<Month>,
+    by_set_pos: Vec<i16>,
+    week_start: DayOfWeek,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParseRecurrenceRuleError {
+    #[error("count and until")]
+    CountAndUntil,
+    #[error("duplicate part")]
+    DuplicatePart,
+    #[error("invalid format")]
+    InvalidFormat,
+    #[error("invalid value")]
+    InvalidValue,
+    #[error("missing frequency")]
+    MissingFrequency,
+    #[error("unsupported part")]
+    UnsupportedPart,
+}
+
+impl RecurrenceRule {
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    pub fn count(&self) -> Option<u32> {
+        self.count
+    }
+
+    pub fn until(&self) -> Option<Until> {
+        self.until
+    }
+
+    pub fn by_day(&self) -> &[WeekdayNum] {
+        &self.by_day
+    }
+
+    pub fn by_month_day(&self) -> &[i8] {
+        &self.by_month_day
+    }
+
+    pub fn by_month(&self) -> &[Month] {
+        &self.by_month
+    }
+
+    pub fn by_set_pos(&self) -> &[i16] {
+        &self.by_set_pos
+    }
+
+    pub fn week_start(&self) -> DayOfWeek {
+        self.week_start
+    }
+
+    // DTSTART is a local date time (UNTIL is compared as a local date time)
+    pub fn occurrences(&self, start: DateTime) -> Occurrences {
+        Occurrences::new(self.clone(), start, 0)
+    }
+
+    // DTSTART is a date time with a fixed offset (UNTIL with 'Z' is compared as an instant)
+    pub fn offset_occurrences(&self, start: OffsetDateTime) -> OffsetOccurrences {
+        OffsetOccurrences::new(self.clone(), start)
+    }
+}
+
+impl std::fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |items: Vec<String>| items.join(",");
+        write!(f, "FREQ={}", self.frequency)?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until)?;
+        }
+        if !self.by_month.is_empty() {
+            let items = self.by_month.iter().map(|m| u8::from(*m).to_string());
+            write!(f, ";BYMONTH={}", join(items.collect()))?;
+        }
+        if !self.by_month_day.is_empty() {
+            let items = self.by_month_day.iter().map(|d| d.to_string());
+            write!(f, ";BYMONTHDAY={}", join(items.collect()))?;
+        }
+        if !self.by_day.is_empty() {
+            let items = self.by_day.iter().map(|d| d.to_string());
+            write!(f, ";BYDAY={}", join(items.collect()))?;
+        }
+        if !self.by_set_pos.is_empty() {
+            let items = self.by_set_pos.iter().map(|p| p.to_string());
+            write!(f, ";BYSETPOS={}", join(items.collect()))?;
+        }
+        if self.week_start != DayOfWeek::monday() {
+            write!(f, ";WKST={}", day_of_week_code(self.week_start))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for RecurrenceRule {
+    type Err = ParseRecurrenceRuleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix("RRULE:").unwrap_or(s);
+        let mut names = BTreeSet::new();
+        let mut frequency = None;
+        let mut rule = RecurrenceRule {
+            frequency: Frequency::Daily,
+            interval: 1,
+            count: None,
+            until: None,
+            by_day: vec![],
+            by_month_day: vec![],
+            by_month: vec![],
+            by_set_pos: vec![],
+            week_start: DayOfWeek::monday(),
+        };
+        for part in s.split(';') {
+            let (name, value) = part.split_once('=').ok_or(Self::Err::InvalidFormat)?;
+            if value.is_empty() {
+                return Err(Self::Err::InvalidFormat);
+            }
+            if !names.insert(name) {
+                return Err(Self::Err::DuplicatePart);
+            }
+            match name {
+                "FREQ" => {
+                    frequency =
+                        Some(Frequency::from_str(value).map_err(|_| Self::Err::InvalidValue)?)
+                }
+                "INTERVAL" => rule.interval = parse_number(value, 1, u32::MAX)?,
+                "COUNT" => rule.count = Some(parse_number(value, 1, u32::MAX)?),
+                "UNTIL" => {
+                    rule.until = Some(Until::from_str(value).map_err(|_| Self::Err::InvalidValue)?)
+                }
+                "BYDAY" => {
+                    rule.by_day = parse_list(value, |v| {
+                        WeekdayNum::from_str(v).map_err(|_| Self::Err::InvalidValue)
+                    })?
+                }
+                "BYMONTHDAY" => rule.by_month_day = parse_list(value, |v| parse_signed(v, 31))?,
+                "BYMONTH" => {
+                    rule.by_month = parse_list(value, |v| {
+                        let month = parse_number(v, 1, 12)?;
+                        Month::try_from(month).map_err(|_| Self::Err::InvalidValue)
+                    })?
+                }
+                "BYSETPOS" => rule.by_set_pos = parse_list(value, |v| parse_signed(v, 366))?,
+                "WKST" => {
+                    rule.week_start = day_of_week_from_code(value).ok_or(Self::Err::InvalidValue)?
+                }
+                // BYSECOND, BYMINUTE, BYHOUR, BYYEARDAY, BYWEEKNO, ...
+                _ => return Err(Self::Err::UnsupportedPart),
+            }
+        }
+        rule.frequency = frequency.ok_or(Self::Err::MissingFrequency)?;
+        if rule.count.is_some() && rule.until.is_some() {
+            return Err(Self::Err::CountAndUntil);
+        }
+        // BYDAY with an ordinal is only for MONTHLY or YEARLY
+        if rule.by_day.iter().any(|d| d.ordinal().is_some())
+            && rule.frequency != Frequency::Monthly
+            && rule.frequency != Frequency::Yearly
+        {
+            return Err(Self::Err::InvalidValue);
+        }
+        // BYMONTHDAY is not for WEEKLY
+        if !rule.by_month_day.is_empty() && rule.frequency == Frequency::Weekly {
+            return Err(Self::Err::InvalidValue);
+        }
+        // BYSETPOS is used in conjunction with another BYxxx
+        if !rule.by_set_pos.is_empty()
+            && rule.by_day.is_empty()
+            && rule.by_month_day.is_empty()
+            && rule.by_month.is_empty()
+        {
+            return Err(Self::Err::InvalidValue);
+        }
+        Ok(rule)
+    }
+}
+
+fn parse_number(s: &str, min: u32, max: u32) -> Result<u32, ParseRecurrenceRuleError> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ParseRecurrenceRuleError::InvalidValue);
+    }
+    match s.parse::<u32>() {
+        Ok(n) if (min..=max).contains(&n) => Ok(n),
+        _ => Err(ParseRecurrenceRuleError::InvalidValue),
+    }
+}
+
+// [-max, -1] or [1, max]
+fn parse_signed<T: TryFrom<i64>>(s: &str, max: u32) -> Result<T, ParseRecurrenceRuleError> {
+    let (negative, digits) = match s.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let n = i64::from(parse_number(digits, 1, max)?);
+    T::try_from(if negative { -n } else { n }).map_err(|_| ParseRecurrenceRuleError::InvalidValue)
+}
+
+fn parse_list<T, F>(s: &str, f: F) -> Result<Vec<T>, ParseRecurrenceRuleError>
+where
+    F: Fn(&str) -> Result<T, ParseRecurrenceRuleError>,
+{
+    s.split(',').map(f).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        type E = ParseRecurrenceRuleError;
+        let f = |s: &str| RecurrenceRule::from_str(s);
+
+        let rule = f("FREQ=MONTHLY;INTERVAL=2;COUNT=10;BYDAY=1SU,-1SU;WKST=SU")?;
+        assert_eq!(rule.frequency(), Frequency::Monthly);
+        assert_eq!(rule.interval(), 2);
+        assert_eq!(rule.count(), Some(10));
+        assert_eq!(rule.until(), None);
+        assert_eq!(
+            rule.by_day(),
+            &[WeekdayNum::from_str("1SU")?, WeekdayNum::from_str("-1SU")?]
+        );
+        assert_eq!(rule.week_start(), DayOfWeek::sunday());
+        assert_eq!(
+            rule.to_string(),
+            "FREQ=MONTHLY;INTERVAL=2;COUNT=10;BYDAY=1SU,-1SU;WKST=SU"
+        );
+
+        let rule = f("RRULE:FREQ=YEARLY;UNTIL=20301231T235959Z;BYMONTH=1,2;BYMONTHDAY=-1")?;
+        assert_eq!(rule.by_month(), &[Month::january(), Month::february()]);
+        assert_eq!(rule.by_month_day(), &[-1]);
+        assert_eq!(
+            rule.to_string(),
+            "FREQ=YEARLY;UNTIL=20301231T235959Z;BYMONTH=1,2;BYMONTHDAY=-1"
+        );
+        assert_eq!(
+            f("BYSETPOS=-1;BYDAY=MO,TU,WE,TH,FR;FREQ=MONTHLY")?.to_string(),
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1"
+        );
+        assert_eq!(f("FREQ=DAILY;INTERVAL=1")?.to_string(), "FREQ=DAILY");
+
+        assert_eq!(f(""), Err(E::InvalidFormat));
+        assert_eq!(f("FREQ"), Err(E::InvalidFormat));
+        assert_eq!(f("FREQ="), Err(E::InvalidFormat));
+        assert_eq!(f("FREQ=DAILY;"), Err(E::InvalidFormat));
+        assert_eq!(f("INTERVAL=2"), Err(E::MissingFrequency));
+        assert_eq!(f("FREQ=DAILY;FREQ=WEEKLY"), Err(E::DuplicatePart));
+        assert_eq!(f("FREQ=daily"), Err(E::InvalidValue));
+        assert_eq!(f("FREQ=DAILY;INTERVAL=0"), Err(E::InvalidValue));
+        assert_eq!(f("FREQ=DAILY;INTERVAL=-1"), Err(E::InvalidValue));
+        assert_eq!(f("FREQ=DAILY;COUNT=0"), Err(E::InvalidValue));
+        assert_eq!(f("FREQ=DAILY;UNTIL=2021-02-03"), Err(E::InvalidValue));
+        assert_eq!(f("FREQ=DAILY;BYMONTH=13"), Err(E::InvalidValue));
+        assert_eq!(f("FREQ=DAILY;BYMONTHDAY=0"), Err(E::InvalidValue));
+        assert_eq!(f("FREQ=DAILY;BYMONTHDAY=-32"), Err(E::InvalidValue));
+        assert_eq!(f("FREQ=DAILY;BYDAY=XX"), Err(E::InvalidValue));
+        assert_eq!(f("FREQ=DAILY;BYDAY=MO,"), Err(E::InvalidValue));
+        assert_eq!(f("FREQ=DAILY;BYDAY=1MO"), Err(E::InvalidValue));
+        assert_eq!(f("FREQ=WEEKLY;BYMONTHDAY=1"), Err(E::InvalidValue));
+        assert_eq!(f("FREQ=DAILY;BYSETPOS=1"), Err(E::InvalidValue));
+        assert_eq!(f("FREQ=DAILY;BYMONTH=1;BYSETPOS=367"), Err(E::InvalidValue));
+        assert_eq!(f("FREQ=DAILY;WKST=mo"), Err(E::InvalidValue));
+        assert_eq!(
+            f("FREQ=DAILY;COUNT=1;UNTIL=20210203"),
+            Err(E::CountAndUntil)
+        );
+        assert_eq!(f("FREQ=DAILY;BYHOUR=1"), Err(E::UnsupportedPart));
+        assert_eq!(f("FREQ=DAILY;X-NAME=1"), Err(E::UnsupportedPart));
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use thiserror::Error;
+
+use crate::{CalendarDate, DateTime, ParseDateError, ParseDateTimeError};
+
+// RFC 5545 UNTIL
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Until {
+    // 19971224
+    Date(CalendarDate),
+    // 19971224T000000 (local time)
+    DateTime(DateTime),
+    // 19971224T000000Z
+    Utc(DateTime),
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParseUntilError {
+    #[error("invalid format")]
+    InvalidFormat,
+    #[error("invalid length")]
+    InvalidLength,
+    #[error("parse date")]
+    ParseDate(ParseDateError),
+    #[error("parse date time")]
+    ParseDateTime(ParseDateTimeError),
+}
+
+impl std::fmt::Display for Until {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let write_date = |f: &mut std::fmt::Formatter<'_>, date: CalendarDate| {
+            write!(f, "{}{}{}", date.year(), date.month(), date.day_of_month())
+        };
+        let write_date_time = |f: &mut std::fmt::Formatter<'_>, date_time: DateTime| {
+            write_date(f, date_time.date())?;
+            let time = date_time.time();
+            write!(f, "T{}{}{}", time.hour(), time.minute(), time.second())
+        };
+        match self {
+            Until::Date(date) => write_date(f, *date),
+            Until::DateTime(date_time) => write_date_time(f, *date_time),
+            Until::Utc(date_time) => {
+                write_date_time(f, *date_time)?;
+                write!(f, "Z")
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for Until {
+    type Err = ParseUntilError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 8 && s.len() != 15 && s.len() != 16 {
+            return Err(Self::Err::InvalidLength);
+        }
+        let bytes = s.as_bytes();
+        let is_digits = |r: std::ops::Range<usize>| bytes[r].iter().all(u8::is_ascii_digit);
+        if !is_digits(0..8) {
+            return Err(Self::Err::InvalidFormat);
+        }
+        // YYYYMMDD -> YYYY-MM-DD
+        let date = format!("{}-{}-{}", &s[0..4], &s[4..6], &s[6..8]);
+        if s.len() == 8 {
+            return CalendarDate::from_str(&date)
+                .map(Until::Date)
+                .map_err(Self::Err::ParseDate);
+        }
+        if bytes[8] != b'T' || !is_digits(9..15) || (s.len() == 16 && bytes[15] != b'Z') {
+            return Err(Self::Err::InvalidFormat);
+        }
+        // YYYYMMDDTHHMMSS -> YYYY-MM-DDTHH:MM:SS
+        let date_time = format!("{}T{}:{}:{}", date, &s[9..11], &s[11..13], &s[13..15]);
+        let date_time = DateTime::from_str(&date_time).map_err(Self::Err::ParseDateTime)?;
+        Ok(if s.len() == 16 {
+            Until::Utc(date_time)
+        } else {
+            Until::DateTime(date_time)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        type E = ParseUntilError;
+        let f = |s: &str| Until::from_str(s);
+
+        assert_eq!(
+            f("19971224")?,
+            Until::Date(CalendarDate::from_str("1997-12-24")?)
+        );
+        assert_eq!(
+            f("19971224T000000")?,
+            Until::DateTime(DateTime::from_str("1997-12-24T00:00:00")?)
+        );
+        assert_eq!(
+            f("19971224T235959Z")?,
+            Until::Utc(DateTime::from_str("1997-12-24T23:59:59")?)
+        );
+        assert_eq!(f("19971224")?.to_string(), "19971224");
+        assert_eq!(f("19971224T000000")?.to_string(), "19971224T000000");
+        assert_eq!(f("19971224T235959Z")?.to_string(), "19971224T235959Z");
+
+        assert_eq!(f(""), Err(E::InvalidLength));
+        assert_eq!(f("1997-12-24"), Err(E::InvalidLength));
+        assert_eq!(f("1997122X"), Err(E::InvalidFormat));
+        assert_eq!(f("19971224 000000"), Err(E::InvalidFormat));
+        assert_eq!(f("19971224T00000X"), Err(E::InvalidFormat));
+        assert_eq!(f("19971224T000000X"), Err(E::InvalidFormat));
+        assert!(matches!(f("19971324"), Err(E::ParseDate(_))));
+        assert!(matches!(f("19691231"), Err(E::ParseDate(_))));
+        assert!(matches!(f("19971224T240000"), Err(E::ParseDateTime(_))));
+        Ok(())
+    }
+}
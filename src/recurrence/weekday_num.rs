@@ -0,0 +1,163 @@
+use std::convert::TryFrom;
+use thiserror::Error;
+
+use crate::DayOfWeek;
+
+// RFC 5545 weekdaynum (e.g. MO, 1MO, -1FR)
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct WeekdayNum {
+    ordinal: Option<i8>,
+    day_of_week: DayOfWeek,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParseWeekdayNumError {
+    #[error("invalid format")]
+    InvalidFormat,
+    #[error("out of range")]
+    OutOfRange,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+#[error("invalid weekday num error")]
+pub struct InvalidWeekdayNumError;
+
+const DAY_OF_WEEK_CODES: [&str; 7] = ["MO", "TU", "WE", "TH", "FR", "SA", "SU"];
+
+impl WeekdayNum {
+    // ordinal: [-53, -1] or [1, 53]
+    pub fn new(
+        ordinal: Option<i8>,
+        day_of_week: DayOfWeek,
+    ) -> Result<Self, InvalidWeekdayNumError> {
+        if let Some(ordinal) = ordinal {
+            if ordinal == 0 || !(-53..=53).contains(&ordinal) {
+                return Err(InvalidWeekdayNumError);
+            }
+        }
+        Ok(Self {
+            ordinal,
+            day_of_week,
+        })
+    }
+
+    pub fn day_of_week(&self) -> DayOfWeek {
+        self.day_of_week
+    }
+
+    pub fn ordinal(&self) -> Option<i8> {
+        self.ordinal
+    }
+}
+
+impl std::fmt::Display for WeekdayNum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(ordinal) = self.ordinal {
+            write!(f, "{}", ordinal)?;
+        }
+        write!(f, "{}", day_of_week_code(self.day_of_week))
+    }
+}
+
+impl std::str::FromStr for WeekdayNum {
+    type Err = ParseWeekdayNumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() < 2 || !s.is_char_boundary(s.len() - 2) {
+            return Err(Self::Err::InvalidFormat);
+        }
+        let (ordinal, code) = s.split_at(s.len() - 2);
+        let day_of_week = day_of_week_from_code(code).ok_or(Self::Err::InvalidFormat)?;
+        let ordinal = if ordinal.is_empty() {
+            None
+        } else {
+            let digits = ordinal
+                .strip_prefix(|c| c == '+' || c == '-')
+                .unwrap_or(ordinal);
+            if digits.is_empty() || digits.len() > 2 || !digits.chars().all(|c| c.is_ascii_digit())
+            {
+                return Err(Self::Err::InvalidFormat);
+            }
+            Some(
+                ordinal
+                    .parse::<i8>()
+                    .map_err(|_| Self::Err::InvalidFormat)?,
+            )
+        };
+        Self::new(ordinal, day_of_week).map_err(|_| Self::Err::OutOfRange)
+    }
+}
+
+// "MO" -> Monday
+pub(crate) fn day_of_week_from_code(s: &str) -> Option<DayOfWeek> {
+    DAY_OF_WEEK_CODES
+        .iter()
+        .position(|code| *code == s)
+        .and_then(|i| DayOfWeek::try_from(i as u8 + 1).ok())
+}
+
+// Monday -> "MO"
+pub(crate) fn day_of_week_code(day_of_week: DayOfWeek) -> &'static str {
+    DAY_OF_WEEK_CODES[usize::from(u8::from(day_of_week)) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn new_test() -> anyhow::Result<()> {
+        let w = WeekdayNum::new(Some(-1), DayOfWeek::friday())?;
+        assert_eq!(w.ordinal(), Some(-1));
+        assert_eq!(w.day_of_week(), DayOfWeek::friday());
+        assert!(WeekdayNum::new(None, DayOfWeek::monday()).is_ok());
+        assert!(WeekdayNum::new(Some(53), DayOfWeek::monday()).is_ok());
+        assert!(WeekdayNum::new(Some(-53), DayOfWeek::monday()).is_ok());
+        assert_eq!(
+            WeekdayNum::new(Some(0), DayOfWeek::monday()),
+            Err(InvalidWeekdayNumError)
+        );
+        assert_eq!(
+            WeekdayNum::new(Some(54), DayOfWeek::monday()),
+            Err(InvalidWeekdayNumError)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        type E = ParseWeekdayNumError;
+        let f = |s: &str| WeekdayNum::from_str(s);
+
+        assert_eq!(f("MO")?.to_string(), "MO");
+        assert_eq!(f("SU")?.to_string(), "SU");
+        assert_eq!(f("1MO")?.to_string(), "1MO");
+        assert_eq!(f("+2TU")?.to_string(), "2TU");
+        assert_eq!(f("-1FR")?.to_string(), "-1FR");
+        assert_eq!(f("53SA")?.to_string(), "53SA");
+
+        assert_eq!(f(""), Err(E::InvalidFormat));
+        assert_eq!(f("M"), Err(E::InvalidFormat));
+        assert_eq!(f("mo"), Err(E::InvalidFormat));
+        assert_eq!(f("XX"), Err(E::InvalidFormat));
+        assert_eq!(f("-MO"), Err(E::InvalidFormat));
+        assert_eq!(f("1.MO"), Err(E::InvalidFormat));
+        assert_eq!(f("100MO"), Err(E::InvalidFormat));
+        assert_eq!(f("0MO"), Err(E::OutOfRange));
+        assert_eq!(f("54MO"), Err(E::OutOfRange));
+        Ok(())
+    }
+
+    #[test]
+    fn day_of_week_code_test() {
+        for code in &DAY_OF_WEEK_CODES {
+            let day_of_week = day_of_week_from_code(code).expect("valid code");
+            assert_eq!(day_of_week_code(day_of_week), *code);
+        }
+        assert_eq!(day_of_week_from_code("MO"), Some(DayOfWeek::monday()));
+        assert_eq!(day_of_week_from_code("SU"), Some(DayOfWeek::sunday()));
+        assert_eq!(day_of_week_from_code("Mo"), None);
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{Interval, IntervalIter, ParseIntervalError};
+
+use thiserror::Error;
+
+// ISO 8601 repeating interval (e.g. R5/2021-02-03T00:00:00Z/P1D)
+//
+// Rn/<interval> ... n repetitions
+// R/<interval>  ... unbounded
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RepeatingInterval {
+    repetitions: Option<u32>,
+    interval: Interval,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParseRepeatingIntervalError {
+    #[error("invalid format")]
+    InvalidFormat,
+    #[error("out of range")]
+    OutOfRange,
+    #[error("parse interval")]
+    ParseInterval(ParseIntervalError),
+}
+
+impl RepeatingInterval {
+    // repetitions: None is unbounded
+    pub fn new(repetitions: Option<u32>, interval: Interval) -> Self {
+        Self {
+            repetitions,
+            interval,
+        }
+    }
+
+    pub fn interval(&self) -> Interval {
+        self.interval
+    }
+
+    pub fn repetitions(&self) -> Option<u32> {
+        self.repetitions
+    }
+
+    // the start of each repetition
+    //
+    // <start>/<end>, <start>/<duration> ... start, start + duration, ...
+    // <duration>/<end> ... end - duration, end - duration * 2, ... (backward)
+    // <duration> ... (empty)
+    pub fn iter(&self) -> IntervalIter {
+        IntervalIter::from_interval(&self.interval, self.repetitions)
+    }
+}
+
+impl std::fmt::Display for RepeatingInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.repetitions {
+            Some(repetitions) => write!(f, "R{}/{}", repetitions, self.interval),
+            None => write!(f, "R/{}", self.interval),
+        }
+    }
+}
+
+impl std::str::FromStr for RepeatingInterval {
+    type Err = ParseRepeatingIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (repetitions, interval) = s
+            .strip_prefix('R')
+            .and_then(|s| s.split_once('/'))
+            .ok_or(Self::Err::InvalidFormat)?;
+        let repetitions = if repetitions.is_empty() {
+            None
+        } else {
+            if !repetitions.chars().all(|c| c.is_ascii_digit()) {
+                return Err(Self::Err::InvalidFormat);
+            }
+            Some(
+                repetitions
+                    .parse::<u32>()
+                    .map_err(|_| Self::Err::OutOfRange)?,
+            )
+        };
+        let interval = Interval::from_str(interval).map_err(Self::Err::ParseInterval)?;
+        Ok(Self::new(repetitions, interval))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::Instant;
+
+    use super::*;
+
+    #[test]
+    fn new_test() -> anyhow::Result<()> {
+        let interval = Interval::from_str("2021-02-03T00:00:00Z/P1D")?;
+        let repeating_interval = RepeatingInterval::new(Some(5), interval);
+        assert_eq!(repeating_interval.repetitions(), Some(5));
+        assert_eq!(repeating_interval.interval(), interval);
+        assert_eq!(
+            repeating_interval,
+            RepeatingInterval::from_str("R5/2021-02-03T00:00:00Z/P1D")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn iter_test() -> anyhow::Result<()> {
+        let f = |s: &str| -> anyhow::Result<Vec<String>> {
+            Ok(RepeatingInterval::from_str(s)?
+                .iter()
+                .map(|odt| odt.to_string())
+                .collect())
+        };
+        assert_eq!(
+            f("R3/2021-02-03T00:00:00+09:00/P1D")?,
+            vec![
+                "2021-02-03T00:00:00+09:00",
+                "2021-02-04T00:00:00+09:00",
+                "2021-02-05T00:00:00+09:00"
+            ]
+        );
+        assert_eq!(
+            f("R3/2021-01-31T00:00:00Z/P1M")?,
+            vec![
+                "2021-01-31T00:00:00Z",
+                "2021-02-28T00:00:00Z",
+                "2021-03-31T00:00:00Z"
+            ]
+        );
+        assert_eq!(
+            f("R2/2021-02-03T00:00:00Z/2021-02-03T09:30:00+09:00")?,
+            vec!["2021-02-03T00:00:00Z", "2021-02-03T00:30:00Z"]
+        );
+        assert_eq!(
+            f("R3/P1D/2021-02-03T00:00:00Z")?,
+            vec![
+                "2021-02-02T00:00:00Z",
+                "2021-02-01T00:00:00Z",
+                "2021-01-31T00:00:00Z"
+            ]
+        );
+        assert_eq!(f("R0/2021-02-03T00:00:00Z/P1D")?, Vec::<String>::new());
+        assert_eq!(f("R3/P1D")?, Vec::<String>::new());
+        // stops before the first out of range value
+        assert_eq!(
+            f("R/9999-12-29T00:00:00Z/P1D")?,
+            vec![
+                "9999-12-29T00:00:00Z",
+                "9999-12-30T00:00:00Z",
+                "9999-12-31T00:00:00Z"
+            ]
+        );
+        assert_eq!(
+            RepeatingInterval::from_str("R/2021-02-03T00:00:00Z/PT1H")?
+                .iter()
+                .take(1_000)
+                .count(),
+            1_000
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn instants_test() -> anyhow::Result<()> {
+        let instants = RepeatingInterval::from_str("R3/1970-01-01T09:00:00+09:00/PT1M")?
+            .iter()
+            .instants()
+            .collect::<Vec<Instant>>();
+        assert_eq!(
+            instants,
+            vec![
+                Instant::from(0_u8),
+                Instant::from(60_u8),
+                Instant::from(120_u8)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        type E = ParseRepeatingIntervalError;
+        let f = |s: &str| RepeatingInterval::from_str(s);
+
+        assert_eq!(
+            f("R5/2021-02-03T00:00:00Z/P1D")?.to_string(),
+            "R5/2021-02-03T00:00:00Z/P1D"
+        );
+        assert_eq!(
+            f("R/P1D/2021-02-03T00:00:00Z")?.to_string(),
+            "R/P1D/2021-02-03T00:00:00Z"
+        );
+        assert_eq!(f("R1/P1D")?.to_string(), "R1/P1D");
+
+        assert_eq!(f(""), Err(E::InvalidFormat));
+        assert_eq!(f("R5"), Err(E::InvalidFormat));
+        assert_eq!(f("5/P1D"), Err(E::InvalidFormat));
+        assert_eq!(f("r5/P1D"), Err(E::InvalidFormat));
+        assert_eq!(f("R+5/P1D"), Err(E::InvalidFormat));
+        assert_eq!(f("R-1/P1D"), Err(E::InvalidFormat));
+        assert_eq!(f("R4294967296/P1D"), Err(E::OutOfRange));
+        assert!(matches!(f("R5/P1D/P1D"), Err(E::ParseInterval(_))));
+        assert!(matches!(f("R5/"), Err(E::ParseInterval(_))));
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{DateTime, Instant, OffsetDateTime, ParseOffsetDateTimeError, TimeZoneOffset};
+
+// RFC 3339 date time that allows the unknown local offset ("-00:00")
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Rfc3339DateTime {
+    // UTC if the local offset is unknown
+    offset_date_time: OffsetDateTime,
+    local_offset: LocalOffset,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LocalOffset {
+    Known(TimeZoneOffset),
+    // UTC is known, the local offset is not (RFC 3339 section 4.3)
+    Unknown,
+}
+
+impl Rfc3339DateTime {
+    pub fn from_instant_with_unknown_offset(instant: Instant) -> Self {
+        Self {
+            offset_date_time: OffsetDateTime::from(instant),
+            local_offset: LocalOffset::Unknown,
+        }
+    }
+
+    // the UTC date time if the local offset is unknown
+    pub fn date_time(&self) -> DateTime {
+        self.offset_date_time.date_time()
+    }
+
+    pub fn instant(&self) -> Instant {
+        self.offset_date_time.instant()
+    }
+
+    pub fn local_offset(&self) -> LocalOffset {
+        self.local_offset
+    }
+
+    // None if the local offset is unknown
+    pub fn offset_date_time(&self) -> Option<OffsetDateTime> {
+        match self.local_offset {
+            LocalOffset::Known(_) => Some(self.offset_date_time),
+            LocalOffset::Unknown => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Rfc3339DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.local_offset {
+            LocalOffset::Known(_) => write!(f, "{}", self.offset_date_time),
+            LocalOffset::Unknown => write!(f, "{}-00:00", self.offset_date_time.date_time()),
+        }
+    }
+}
+
+impl std::str::FromStr for Rfc3339DateTime {
+    type Err = ParseOffsetDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match OffsetDateTime::from_str(s) {
+            Ok(offset_date_time) => Ok(Self::from(offset_date_time)),
+            Err(ParseOffsetDateTimeError::UnknownOffset) => {
+                // keep the fraction of a second
+                let utc = OffsetDateTime::from_str(&format!("{}Z", &s[..s.len() - 6]))?;
+                Ok(Self {
+                    offset_date_time: utc,
+                    local_offset: LocalOffset::Unknown,
+                })
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl From<OffsetDateTime> for Rfc3339DateTime {
+    fn from(offset_date_time: OffsetDateTime) -> Self {
+        Self {
+            offset_date_time,
+            local_offset: LocalOffset::Known(offset_date_time.offset()),
+        }
+    }
+}
+
+impl From<Rfc3339DateTime> for Instant {
+    fn from(rfc3339_date_time: Rfc3339DateTime) -> Self {
+        rfc3339_date_time.instant()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        let f =
+            |s: &str| -> anyhow::Result<String> { Ok(Rfc3339DateTime::from_str(s)?.to_string()) };
+        assert_eq!(f("2021-02-03T04:05:06-00:00")?, "2021-02-03T04:05:06-00:00");
+        assert_eq!(
+            f("2021-02-03T04:05:06.123-00:00")?,
+            "2021-02-03T04:05:06.123-00:00"
+        );
+        assert_eq!(f("2021-02-03T04:05:06+00:00")?, "2021-02-03T04:05:06Z");
+        assert_eq!(f("2021-02-03T04:05:06Z")?, "2021-02-03T04:05:06Z");
+        assert_eq!(f("2021-02-03T04:05:06+09:00")?, "2021-02-03T04:05:06+09:00");
+        assert!(matches!(
+            Rfc3339DateTime::from_str("2021-02-30T04:05:06-00:00"),
+            Err(ParseOffsetDateTimeError::ParseDateTime(_))
+        ));
+        assert_eq!(
+            Rfc3339DateTime::from_str("2021-02-03T04:05:06-00:0"),
+            Err(ParseOffsetDateTimeError::InvalidLength)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn accessors_test() -> anyhow::Result<()> {
+        let unknown = Rfc3339DateTime::from_str("2021-02-03T04:05:06-00:00")?;
+        assert_eq!(unknown.local_offset(), LocalOffset::Unknown);
+        assert_eq!(
+            unknown.instant(),
+            Instant::from_str("2021-02-03T04:05:06Z")?
+        );
+        assert_eq!(
+            unknown.date_time(),
+            DateTime::from_str("2021-02-03T04:05:06")?
+        );
+        assert_eq!(unknown.offset_date_time(), None);
+        assert_eq!(Instant::from(unknown), unknown.instant());
+
+        let known = Rfc3339DateTime::from_str("2021-02-03T04:05:06+09:00")?;
+        assert_eq!(
+            known.local_offset(),
+            LocalOffset::Known(TimeZoneOffset::from_h(9)?)
+        );
+        assert_eq!(known.instant(), Instant::from_str("2021-02-02T19:05:06Z")?);
+        assert_eq!(
+            known.date_time(),
+            DateTime::from_str("2021-02-03T04:05:06")?
+        );
+        assert_eq!(
+            known.offset_date_time(),
+            Some(OffsetDateTime::from_str("2021-02-03T04:05:06+09:00")?)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_instant_with_unknown_offset_test() -> anyhow::Result<()> {
+        let instant = Instant::from_str("2021-02-03T04:05:06Z")?;
+        let rfc3339_date_time = Rfc3339DateTime::from_instant_with_unknown_offset(instant);
+        assert_eq!(rfc3339_date_time.to_string(), "2021-02-03T04:05:06-00:00");
+        assert_eq!(rfc3339_date_time.instant(), instant);
+        Ok(())
+    }
+}
@@ -0,0 +1,433 @@
+// serde support (cargo feature "serde")
+// the types with FromStr use the strict string forms (e.g. "2021-02-03T04:05:06+09:00")
+// the durations without string forms use integers (e.g. Days as 3)
+// TaiInstant and GpsTime use tuples of their components (e.g. TaiInstant as (seconds, nanosecond))
+// ZonedDateTime and the time zone rules (TimeZone, Tzdata, ...) are not supported (see README)
+// the other encodings are selectable with #[serde(with = "limited_date_time::serde::...")]
+
+use std::{convert::TryFrom, fmt, marker::PhantomData, str::FromStr};
+
+use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    CalendarDate, DateTime, DateTimeOfDay, DayOfMonth, DayOfWeek, DayOfYear, Days,
+    ExtendedDateTime, Frequency, GpsTime, Hour, Instant, Interval, LeapSecondDateTime, LocalOffset,
+    Minute, Month, Months, Nanosecond, OffsetDateTime, OrdinalDate, Period, PosixTimeZone,
+    PreciseInstant, RecurrenceRule, RepeatingInterval, Rfc3339DateTime, Second, Seconds,
+    SignedDays, SignedMonths, SignedSeconds, TaiInstant, Time, TimeOfDay, TimeZoneAnnotation,
+    TimeZoneOffset, Until, WeekDate, WeekOfYear, WeekdayNum, Year, YearMonth, YearWeek,
+};
+
+struct FromStrVisitor<T>(PhantomData<T>);
+
+impl<'de, T> de::Visitor<'de> for FromStrVisitor<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        T::from_str(v).map_err(|e| E::custom(format!("{}: {:?}", e, v)))
+    }
+}
+
+macro_rules! impl_serde_str {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Serialize for $t {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $t {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserializer.deserialize_str(FromStrVisitor(PhantomData))
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_str!(
+    CalendarDate,
+    DateTime,
+    DateTimeOfDay,
+    DayOfMonth,
+    DayOfWeek,
+    DayOfYear,
+    ExtendedDateTime,
+    Frequency,
+    Hour,
+    Instant,
+    Interval,
+    LeapSecondDateTime,
+    Minute,
+    Month,
+    Nanosecond,
+    OffsetDateTime,
+    OrdinalDate,
+    Period,
+    PosixTimeZone,
+    PreciseInstant,
+    RecurrenceRule,
+    RepeatingInterval,
+    Rfc3339DateTime,
+    Second,
+    Time,
+    TimeOfDay,
+    TimeZoneAnnotation,
+    TimeZoneOffset,
+    Until,
+    WeekDate,
+    WeekOfYear,
+    WeekdayNum,
+    Year,
+    YearMonth,
+    YearWeek,
+);
+
+// ($t, the integer type)
+macro_rules! impl_serde_integer {
+    ($(($t:ty, $i:ty)),* $(,)?) => {
+        $(
+            impl Serialize for $t {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    <$i>::from(*self).serialize(serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $t {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let value = <$i>::deserialize(deserializer)?;
+                    <$t>::try_from(value).map_err(|_| out_of_range(stringify!($t)))
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_integer!(
+    (Days, u32),
+    (Months, u32),
+    (Seconds, u64),
+    (SignedDays, i32),
+    (SignedMonths, i32),
+    (SignedSeconds, i64),
+);
+
+// "+09:00" or "-00:00" (unknown)
+impl Serialize for LocalOffset {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            LocalOffset::Known(offset) => offset.serialize(serializer),
+            LocalOffset::Unknown => serializer.serialize_str("-00:00"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for LocalOffset {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        match s.as_str() {
+            "-00:00" => Ok(LocalOffset::Unknown),
+            _ => TimeZoneOffset::from_str(&s)
+                .map(LocalOffset::Known)
+                .map_err(|e| de::Error::custom(format!("{}: {:?}", e, s))),
+        }
+    }
+}
+
+// (seconds, nanosecond)
+impl Serialize for TaiInstant {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.seconds(), u32::from(self.nanosecond())).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TaiInstant {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (seconds, nanosecond) = <(u64, u32)>::deserialize(deserializer)?;
+        let nanosecond =
+            Nanosecond::try_from(nanosecond).map_err(|_| out_of_range("Nanosecond"))?;
+        TaiInstant::new(seconds, nanosecond).map_err(|_| out_of_range("TaiInstant"))
+    }
+}
+
+// (week, seconds of week, nanosecond)
+impl Serialize for GpsTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (
+            self.week(),
+            self.seconds_of_week(),
+            u32::from(self.nanosecond()),
+        )
+            .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GpsTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (week, seconds_of_week, nanosecond) = <(u32, u32, u32)>::deserialize(deserializer)?;
+        let nanosecond =
+            Nanosecond::try_from(nanosecond).map_err(|_| out_of_range("Nanosecond"))?;
+        GpsTime::new(week, seconds_of_week, nanosecond).map_err(|_| out_of_range("GpsTime"))
+    }
+}
+
+fn out_of_range<E: de::Error>(name: &str) -> E {
+    E::invalid_value(de::Unexpected::Other("out of range"), &name)
+}
+
+// CalendarDate as the days from 1970-01-01 (e.g. 2021-02-03 as 18661)
+pub mod calendar_date_as_days {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(date: &CalendarDate, serializer: S) -> Result<S::Ok, S::Error> {
+        u32::from(date.days_from_unix_epoch()).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<CalendarDate, D::Error> {
+        let days = Days::deserialize(deserializer)?;
+        Ok(CalendarDate::from_days_from_unix_epoch(days))
+    }
+}
+
+// Instant as the seconds from 1970-01-01T00:00:00Z (e.g. 1612325106)
+pub mod instant_as_seconds {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(instant: &Instant, serializer: S) -> Result<S::Ok, S::Error> {
+        u64::from(*instant).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Instant, D::Error> {
+        let seconds = u64::deserialize(deserializer)?;
+        Instant::try_from(seconds).map_err(|_| out_of_range("Instant"))
+    }
+}
+
+// PreciseInstant as the milliseconds from 1970-01-01T00:00:00Z (e.g. JavaScript Date#getTime)
+// the fraction of a millisecond is truncated
+pub mod precise_instant_as_millis {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        precise_instant: &PreciseInstant,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        precise_instant.unix_millis().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<PreciseInstant, D::Error> {
+        let millis = i64::deserialize(deserializer)?;
+        PreciseInstant::from_unix_millis(millis).map_err(|_| out_of_range("PreciseInstant"))
+    }
+}
+
+// TimeZoneOffset as the offset in minutes (e.g. +09:00 as 540)
+pub mod time_zone_offset_as_minutes {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        offset: &TimeZoneOffset,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        offset.offset_in_minutes().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<TimeZoneOffset, D::Error> {
+        let minutes = i16::deserialize(deserializer)?;
+        TimeZoneOffset::from_offset_in_minutes(minutes).map_err(|_| out_of_range("TimeZoneOffset"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    use super::*;
+
+    #[test]
+    fn str_test() -> anyhow::Result<()> {
+        assert_tokens(
+            &OffsetDateTime::from_str("2021-02-03T04:05:06+09:00")?,
+            &[Token::Str("2021-02-03T04:05:06+09:00")],
+        );
+        assert_tokens(
+            &CalendarDate::from_str("2021-02-03")?,
+            &[Token::Str("2021-02-03")],
+        );
+        assert_tokens(&YearMonth::from_str("2021-02")?, &[Token::Str("2021-02")]);
+        assert_tokens(
+            &OrdinalDate::from_str("2021-034")?,
+            &[Token::Str("2021-034")],
+        );
+        assert_tokens(
+            &Instant::from_str("2021-02-03T04:05:06Z")?,
+            &[Token::Str("2021-02-03T04:05:06Z")],
+        );
+        assert_tokens(&TimeZoneOffset::from_h(9)?, &[Token::Str("+09:00")]);
+        assert_tokens(
+            &Period::from_str("P1Y2M3DT4S")?,
+            &[Token::Str("P1Y2M3DT4S")],
+        );
+
+        // strict
+        assert_de_tokens_error::<OffsetDateTime>(
+            &[Token::Str("2021-02-03 04:05:06+09:00")],
+            "parse date time: \"2021-02-03 04:05:06+09:00\"",
+        );
+        assert_de_tokens_error::<CalendarDate>(
+            &[Token::U32(18661)],
+            "invalid type: integer `18661`, expected a string",
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn integer_test() {
+        assert_tokens(&Days::from(3_u8), &[Token::U32(3)]);
+        assert_tokens(&Months::from(12_u32), &[Token::U32(12)]);
+        assert_tokens(&Seconds::from(60_u64), &[Token::U64(60)]);
+        assert_tokens(&SignedDays::from(-3_i8), &[Token::I32(-3)]);
+        assert_tokens(&SignedMonths::from(-12_i32), &[Token::I32(-12)]);
+        assert_tokens(&SignedSeconds::from(-60_i64), &[Token::I64(-60)]);
+        assert_de_tokens_error::<Days>(
+            &[Token::U32(2_932_897)],
+            "invalid value: out of range, expected Days",
+        );
+        assert_de_tokens_error::<Days>(
+            &[Token::I64(-1)],
+            "invalid value: integer `-1`, expected u32",
+        );
+    }
+
+    #[test]
+    fn local_offset_test() -> anyhow::Result<()> {
+        assert_tokens(
+            &LocalOffset::Known(TimeZoneOffset::from_h(9)?),
+            &[Token::Str("+09:00")],
+        );
+        assert_tokens(&LocalOffset::Unknown, &[Token::Str("-00:00")]);
+        assert_de_tokens_error::<LocalOffset>(&[Token::Str("Z")], "invalid length: \"Z\"");
+        assert_tokens(
+            &TimeZoneAnnotation::from_str("Asia/Tokyo")?,
+            &[Token::Str("Asia/Tokyo")],
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn tuple_test() -> anyhow::Result<()> {
+        let tai_instant =
+            TaiInstant::try_from(PreciseInstant::from_str("2021-02-03T04:05:06.5Z")?)?;
+        assert_tokens(
+            &tai_instant,
+            &[
+                Token::Tuple { len: 2 },
+                Token::U64(tai_instant.seconds()),
+                Token::U32(500_000_000),
+                Token::TupleEnd,
+            ],
+        );
+        assert_de_tokens_error::<TaiInstant>(
+            &[
+                Token::Tuple { len: 2 },
+                Token::U64(0),
+                Token::U32(0),
+                Token::TupleEnd,
+            ],
+            "invalid value: out of range, expected TaiInstant",
+        );
+
+        let gps_time = GpsTime::new(2_143, 273_924, Nanosecond::try_from(5_u32)?)?;
+        assert_tokens(
+            &gps_time,
+            &[
+                Token::Tuple { len: 3 },
+                Token::U32(2_143),
+                Token::U32(273_924),
+                Token::U32(5),
+                Token::TupleEnd,
+            ],
+        );
+        assert_de_tokens_error::<GpsTime>(
+            &[
+                Token::Tuple { len: 3 },
+                Token::U32(0),
+                Token::U32(604_800),
+                Token::U32(0),
+                Token::TupleEnd,
+            ],
+            "invalid value: out of range, expected GpsTime",
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn with_test() -> anyhow::Result<()> {
+        #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+        struct Event {
+            #[serde(with = "calendar_date_as_days")]
+            date: CalendarDate,
+            #[serde(with = "instant_as_seconds")]
+            created_at: Instant,
+            #[serde(with = "precise_instant_as_millis")]
+            updated_at: PreciseInstant,
+            #[serde(with = "time_zone_offset_as_minutes")]
+            offset: TimeZoneOffset,
+        }
+
+        let tokens = |date: u32, created_at: u64, updated_at: i64, offset: i16| {
+            vec![
+                Token::Struct {
+                    name: "Event",
+                    len: 4,
+                },
+                Token::Str("date"),
+                Token::U32(date),
+                Token::Str("created_at"),
+                Token::U64(created_at),
+                Token::Str("updated_at"),
+                Token::I64(updated_at),
+                Token::Str("offset"),
+                Token::I16(offset),
+                Token::StructEnd,
+            ]
+        };
+        let event = Event {
+            date: CalendarDate::from_str("2021-02-03")?,
+            created_at: Instant::from_str("2021-02-03T04:05:06Z")?,
+            updated_at: PreciseInstant::from_str("2021-02-03T04:05:06.789Z")?,
+            offset: TimeZoneOffset::from_hm(-9, -30)?,
+        };
+        assert_tokens(
+            &event,
+            &tokens(18_661, 1_612_325_106, 1_612_325_106_789, -570),
+        );
+
+        // the tokens up to the invalid value
+        let message = |name: &str| format!("invalid value: out of range, expected {}", name);
+        assert_de_tokens_error::<Event>(&tokens(2_932_897, 0, 0, 0)[..3], &message("Days"));
+        assert_de_tokens_error::<Event>(
+            &tokens(0, 253_402_300_800, 0, 0)[..5],
+            &message("Instant"),
+        );
+        assert_de_tokens_error::<Event>(&tokens(0, 0, -1, 0)[..7], &message("PreciseInstant"));
+        assert_de_tokens_error::<Event>(&tokens(0, 0, 0, 1_440)[..9], &message("TimeZoneOffset"));
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+use std::convert::TryFrom;
+
+use thiserror::Error;
+
+use crate::{
+    leap_second_table::{TAI_MINUS_UTC_1972, UTC_1972},
+    Instant, LeapSecondDateTime, LeapSecondPolicy, LeapSecondTable, Nanosecond, OffsetDateTime,
+    PreciseInstant, TimeZoneOffset,
+};
+
+// International Atomic Time (seconds from 1970-01-01T00:00:00 TAI)
+// the range is from 1972-01-01T00:00:00Z to 9999-12-31T23:59:59.999999999Z
+// converted with LeapSecondTable::builtin
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct TaiInstant {
+    seconds: u64,
+    nanosecond: Nanosecond,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum TryFromTaiInstantError {
+    #[error("out of range")]
+    OutOfRange,
+}
+
+impl TaiInstant {
+    pub fn new(seconds: u64, nanosecond: Nanosecond) -> Result<Self, TryFromTaiInstantError> {
+        let tai_timestamp =
+            i64::try_from(seconds).map_err(|_| TryFromTaiInstantError::OutOfRange)?;
+        if tai_timestamp < UTC_1972 + TAI_MINUS_UTC_1972 {
+            return Err(TryFromTaiInstantError::OutOfRange);
+        }
+        let (timestamp, _) = LeapSecondTable::builtin().utc_from_tai(tai_timestamp);
+        Instant::try_from(timestamp).map_err(|_| TryFromTaiInstantError::OutOfRange)?;
+        Ok(Self {
+            seconds,
+            nanosecond,
+        })
+    }
+
+    pub fn nanosecond(&self) -> Nanosecond {
+        self.nanosecond
+    }
+
+    pub fn seconds(&self) -> u64 {
+        self.seconds
+    }
+
+    // UTC (e.g. 2016-12-31T23:59:60Z)
+    pub fn to_leap_second_date_time(&self) -> LeapSecondDateTime {
+        let tai_timestamp = i64::try_from(self.seconds).expect("TaiInstant seconds is in range");
+        let (timestamp, leap_second) = LeapSecondTable::builtin().utc_from_tai(tai_timestamp);
+        let instant = Instant::try_from(timestamp).expect("TaiInstant is in range");
+        let offset_date_time = OffsetDateTime::from_precise_instant(
+            PreciseInstant::new(instant, self.nanosecond),
+            TimeZoneOffset::utc(),
+        )
+        .expect("UTC offset date time is in range");
+        if leap_second {
+            LeapSecondDateTime::from_second_before_leap_second(offset_date_time)
+        } else {
+            LeapSecondDateTime::from(offset_date_time)
+        }
+    }
+
+    // the leap second is mapped by policy
+    pub fn to_precise_instant(&self, policy: LeapSecondPolicy) -> PreciseInstant {
+        self.to_leap_second_date_time().to_precise_instant(policy)
+    }
+}
+
+impl TryFrom<Instant> for TaiInstant {
+    type Error = TryFromTaiInstantError;
+
+    fn try_from(instant: Instant) -> Result<Self, Self::Error> {
+        Self::try_from(PreciseInstant::from(instant))
+    }
+}
+
+impl TryFrom<LeapSecondDateTime> for TaiInstant {
+    type Error = TryFromTaiInstantError;
+
+    fn try_from(leap_second_date_time: LeapSecondDateTime) -> Result<Self, Self::Error> {
+        let label = leap_second_date_time.to_precise_instant(LeapSecondPolicy::CollapseToPrevious);
+        let timestamp = i64::from(label.truncate());
+        if timestamp < UTC_1972 {
+            return Err(TryFromTaiInstantError::OutOfRange);
+        }
+        let mut tai_timestamp = timestamp + LeapSecondTable::builtin().tai_minus_utc(timestamp);
+        if leap_second_date_time.is_leap_second() {
+            tai_timestamp += 1;
+        }
+        let seconds = u64::try_from(tai_timestamp).expect("TAI timestamp is positive");
+        Ok(Self {
+            seconds,
+            nanosecond: label.nanosecond(),
+        })
+    }
+}
+
+impl TryFrom<PreciseInstant> for TaiInstant {
+    type Error = TryFromTaiInstantError;
+
+    fn try_from(precise_instant: PreciseInstant) -> Result<Self, Self::Error> {
+        let offset_date_time =
+            OffsetDateTime::from_precise_instant(precise_instant, TimeZoneOffset::utc())
+                .map_err(|_| TryFromTaiInstantError::OutOfRange)?;
+        Self::try_from(LeapSecondDateTime::from(offset_date_time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn new_test() -> anyhow::Result<()> {
+        // 1972-01-01T00:00:10 TAI = 1972-01-01T00:00:00Z
+        assert!(TaiInstant::new(63_072_010, Nanosecond::min()).is_ok());
+        assert_eq!(
+            TaiInstant::new(63_072_009, Nanosecond::max()),
+            Err(TryFromTaiInstantError::OutOfRange)
+        );
+        // 9999-12-31T23:59:59Z + 37 seconds
+        assert!(TaiInstant::new(253_402_300_836, Nanosecond::max()).is_ok());
+        assert_eq!(
+            TaiInstant::new(253_402_300_837, Nanosecond::min()),
+            Err(TryFromTaiInstantError::OutOfRange)
+        );
+        assert_eq!(
+            TaiInstant::new(u64::MAX, Nanosecond::min()),
+            Err(TryFromTaiInstantError::OutOfRange)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn instant_conversion_test() -> anyhow::Result<()> {
+        let f = |s: &str| -> anyhow::Result<u64> {
+            Ok(TaiInstant::try_from(Instant::from_str(s)?)?.seconds())
+        };
+        assert_eq!(f("1972-01-01T00:00:00Z")?, 63_072_010);
+        assert_eq!(f("1972-07-01T00:00:00Z")?, 78_796_811);
+        assert_eq!(f("2016-12-31T23:59:59Z")?, 1_483_228_835);
+        assert_eq!(f("2017-01-01T00:00:00Z")?, 1_483_228_837);
+        assert_eq!(
+            TaiInstant::try_from(Instant::from_str("1971-12-31T23:59:59Z")?),
+            Err(TryFromTaiInstantError::OutOfRange)
+        );
+
+        let precise_instant = PreciseInstant::from_str("2021-02-03T04:05:06.789Z")?;
+        let tai_instant = TaiInstant::try_from(precise_instant)?;
+        assert_eq!(tai_instant.seconds(), 1_612_325_143);
+        assert_eq!(tai_instant.nanosecond(), precise_instant.nanosecond());
+        assert_eq!(
+            tai_instant.to_precise_instant(LeapSecondPolicy::CollapseToPrevious),
+            precise_instant
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn leap_second_date_time_conversion_test() -> anyhow::Result<()> {
+        let f = |s: &str| -> anyhow::Result<u64> {
+            Ok(TaiInstant::try_from(LeapSecondDateTime::from_str(s)?)?.seconds())
+        };
+        assert_eq!(f("2016-12-31T23:59:59Z")?, 1_483_228_835);
+        assert_eq!(f("2016-12-31T23:59:60Z")?, 1_483_228_836);
+        assert_eq!(f("2016-12-31T15:59:60-08:00")?, 1_483_228_836);
+        assert_eq!(f("2017-01-01T00:00:00Z")?, 1_483_228_837);
+
+        let g = |seconds: u64| -> anyhow::Result<String> {
+            Ok(
+                TaiInstant::new(seconds, Nanosecond::try_from(500_000_000_u32)?)?
+                    .to_leap_second_date_time()
+                    .to_string(),
+            )
+        };
+        assert_eq!(g(1_483_228_835)?, "2016-12-31T23:59:59.500Z");
+        assert_eq!(g(1_483_228_836)?, "2016-12-31T23:59:60.500Z");
+        assert_eq!(g(1_483_228_837)?, "2017-01-01T00:00:00.500Z");
+        Ok(())
+    }
+
+    #[test]
+    fn to_precise_instant_test() -> anyhow::Result<()> {
+        let leap_second = TaiInstant::new(1_483_228_836, Nanosecond::min())?;
+        assert_eq!(
+            leap_second
+                .to_precise_instant(LeapSecondPolicy::CollapseToPrevious)
+                .to_string(),
+            "2016-12-31T23:59:59Z"
+        );
+        assert_eq!(
+            leap_second
+                .to_precise_instant(LeapSecondPolicy::CollapseToNext)
+                .to_string(),
+            "2017-01-01T00:00:00Z"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ord_test() -> anyhow::Result<()> {
+        let t = |s: &str| -> anyhow::Result<TaiInstant> {
+            Ok(TaiInstant::try_from(LeapSecondDateTime::from_str(s)?)?)
+        };
+        assert!(t("2016-12-31T23:59:59.9Z")? < t("2016-12-31T23:59:60Z")?);
+        assert!(t("2016-12-31T23:59:60.9Z")? < t("2017-01-01T00:00:00Z")?);
+        Ok(())
+    }
+}
@@ -1,15 +1,19 @@
 mod hour;
 mod minute;
+mod nanosecond;
 mod second;
+mod time_of_day;
 
-use crate::private::seconds_from_midnight_from_time;
+use crate::private::{seconds_from_midnight_from_time, time_from_seconds_from_midnight};
 use crate::Seconds;
 
 use std::convert::TryFrom;
 
 pub use self::hour::*;
 pub use self::minute::*;
+pub use self::nanosecond::*;
 pub use self::second::*;
+pub use self::time_of_day::*;
 
 use thiserror::Error;
 
@@ -18,18 +22,21 @@ pub struct Time {
     hour: Hour,
     minute: Minute,
     second: Second,
+    nanosecond: Nanosecond,
 }
 
 const MAX_TIME: Time = Time {
     hour: Hour::max(),
     minute: Minute::max(),
     second: Second::max(),
+    nanosecond: Nanosecond::min(),
 };
 
 const MIN_TIME: Time = Time {
     hour: Hour::min(),
     minute: Minute::min(),
     second: Second::min(),
+    nanosecond: Nanosecond::min(),
 };
 
 #[derive(Debug, Eq, Error, PartialEq)]
@@ -42,6 +49,8 @@ pub enum ParseTimeError {
     ParseHour(ParseHourError),
     #[error("parse minute")]
     ParseMinute(ParseMinuteError),
+    #[error("parse nanosecond")]
+    ParseNanosecond(ParseNanosecondError),
     #[error("parse second")]
     ParseSecond(ParseSecondError),
 }
@@ -50,12 +59,28 @@ pub enum ParseTimeError {
 #[error("invalid time error")]
 pub struct InvalidTimeError;
 
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum TryFromTimeError {
+    #[error("out of range")]
+    OutOfRange,
+}
+
 impl Time {
-    pub fn from_hms(hour: Hour, minute: Minute, second: Second) -> Self {
+    pub const fn from_hms(hour: Hour, minute: Minute, second: Second) -> Self {
+        Self::from_hms_nano(hour, minute, second, Nanosecond::min())
+    }
+
+    pub const fn from_hms_nano(
+        hour: Hour,
+        minute: Minute,
+        second: Second,
+        nanosecond: Nanosecond,
+    ) -> Self {
         Self {
             hour,
             minute,
             second,
+            nanosecond,
         }
     }
 
@@ -75,10 +100,33 @@ impl Time {
         self.minute
     }
 
+    pub fn nanosecond(&self) -> Nanosecond {
+        self.nanosecond
+    }
+
     pub fn second(&self) -> Second {
         self.second
     }
 
+    pub(crate) fn from_seconds_from_midnight(seconds_from_midnight: Seconds) -> Self {
+        let seconds_from_midnight = i64::try_from(u64::from(seconds_from_midnight))
+            .expect("Time seconds from midnight is [0, 86400)");
+        let (h, m, s) = time_from_seconds_from_midnight(seconds_from_midnight);
+        Self::from_hms(
+            Hour::try_from(h).expect("hour is [0, 23]"),
+            Minute::try_from(m).expect("minute is [0, 59]"),
+            Second::try_from(s).expect("second is [0, 59]"),
+        )
+    }
+
+    pub(crate) fn with_nanosecond(&self, nanosecond: Nanosecond) -> Self {
+        Self {
+            nanosecond,
+            ..*self
+        }
+    }
+
+    // the fraction of a second is truncated
     pub(crate) fn seconds_from_midnight(&self) -> Seconds {
         let seconds_from_midnight = seconds_from_midnight_from_time((
             i64::from(self.hour()),
@@ -93,7 +141,11 @@ impl Time {
 
 impl std::fmt::Display for Time {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}:{}:{}", self.hour, self.minute, self.second)
+        write!(f, "{}:{}:{}", self.hour, self.minute, self.second)?;
+        if let Some(fraction) = self.nanosecond.to_fraction_string() {
+            write!(f, ".{}", fraction)?;
+        }
+        Ok(())
     }
 }
 
@@ -101,9 +153,12 @@ impl std::str::FromStr for Time {
     type Err = ParseTimeError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() != 8 {
+        if s.len() != 8 && !(10..=18).contains(&s.len()) {
             return Err(Self::Err::InvalidLength);
         }
+        if !s.is_ascii() {
+            return Err(Self::Err::InvalidFormat);
+        }
         let hour = match Hour::from_str(&s[0..2]) {
             Ok(h) => h,
             Err(e) => match e {
@@ -134,10 +189,22 @@ impl std::str::FromStr for Time {
                 ParseSecondError::OutOfRange => return Err(Self::Err::ParseSecond(e)),
             },
         };
+        // time-secfrac
+        let nanosecond = match s.get(8..) {
+            Some("") => Nanosecond::min(),
+            Some(fraction) => match fraction.strip_prefix('.') {
+                Some(digits) => {
+                    Nanosecond::from_fraction_str(digits).map_err(Self::Err::ParseNanosecond)?
+                }
+                None => return Err(Self::Err::InvalidFormat),
+            },
+            None => return Err(Self::Err::InvalidFormat),
+        };
         Ok(Time {
             hour,
             minute,
             second,
+            nanosecond,
         })
     }
 }
@@ -178,10 +245,12 @@ mod tests {
         type E = ParseTimeError;
         let f = |s: &str| Time::from_str(s);
 
-        assert!(matches!(f("04:05:06"), Ok(_)));
+        assert!(f("04:05:06").is_ok());
         assert!(matches!(f("004:05:06"), Err(E::InvalidLength)));
         assert!(matches!(f("04-05:06"), Err(E::InvalidFormat)));
         assert!(matches!(f("04:05-06"), Err(E::InvalidFormat)));
+        assert!(matches!(f("04:0é06"), Err(E::InvalidFormat)));
+        assert!(matches!(f("04:05:06.é2"), Err(E::InvalidFormat)));
         assert!(matches!(f("+4:05:06"), Err(E::ParseHour(_))));
         assert!(matches!(f("04:+5:06"), Err(E::ParseMinute(_))));
         assert!(matches!(f("04:05:+6"), Err(E::ParseSecond(_))));
@@ -190,6 +259,32 @@ mod tests {
             f("04:05:06").map(|d| d.to_string()),
             Ok("04:05:06".to_string())
         );
+
+        // time-secfrac
+        assert!(matches!(f("04:05:06."), Err(E::InvalidLength)));
+        assert!(matches!(f("04:05:06.1234567890"), Err(E::InvalidLength)));
+        assert!(matches!(f("04:05:06,1"), Err(E::InvalidFormat)));
+        assert!(matches!(f("04:05:06.1a"), Err(E::ParseNanosecond(_))));
+        assert_eq!(
+            f("04:05:06.1").map(|d| d.to_string()),
+            Ok("04:05:06.100".to_string())
+        );
+        assert_eq!(
+            f("04:05:06.000").map(|d| d.to_string()),
+            Ok("04:05:06".to_string())
+        );
+        assert_eq!(
+            f("04:05:06.123").map(|d| d.to_string()),
+            Ok("04:05:06.123".to_string())
+        );
+        assert_eq!(
+            f("04:05:06.1234").map(|d| d.to_string()),
+            Ok("04:05:06.123400".to_string())
+        );
+        assert_eq!(
+            f("04:05:06.123456789").map(|d| d.to_string()),
+            Ok("04:05:06.123456789".to_string())
+        );
     }
 
     #[test]
@@ -206,6 +301,28 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn nanosecond_test() -> anyhow::Result<()> {
+        assert_eq!(Time::from_str("04:05:06")?.nanosecond(), Nanosecond::min());
+        let time = Time::from_str("04:05:06.123456789")?;
+        assert_eq!(time.nanosecond(), Nanosecond::try_from(123_456_789_u32)?);
+        assert_eq!(
+            time,
+            Time::from_hms_nano(
+                Hour::from_str("04")?,
+                Minute::from_str("05")?,
+                Second::from_str("06")?,
+                Nanosecond::try_from(123_456_789_u32)?
+            )
+        );
+        assert!(time > Time::from_str("04:05:06")?);
+        assert_eq!(
+            time.seconds_from_midnight(),
+            Time::from_str("04:05:06")?.seconds_from_midnight()
+        );
+        Ok(())
+    }
+
     #[test]
     fn second_test() -> anyhow::Result<()> {
         let time = Time::from_str("04:05:06")?;
@@ -222,9 +339,11 @@ mod tests {
                 for s in 0..60 {
                     let second = Second::try_from(s)?;
                     let time = Time::from_hms(hour, minute, second);
+                    let seconds_from_midnight = Seconds::from(h * 60 * 60 + m * 60 + s);
+                    assert_eq!(time.seconds_from_midnight(), seconds_from_midnight);
                     assert_eq!(
-                        time.seconds_from_midnight(),
-                        Seconds::from(h * 60 * 60 + m * 60 + s)
+                        Time::from_seconds_from_midnight(seconds_from_midnight),
+                        time
                     );
                 }
             }
@@ -1,6 +1,9 @@
 use std::convert::TryFrom;
+use std::ops::RangeBounds;
 use thiserror::Error;
 
+use crate::range_iter::{range_iter, RangeIter, Step};
+
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Hour(u8);
 
@@ -16,6 +19,55 @@ impl Hour {
     pub const fn min() -> Self {
         MIN_HOUR
     }
+
+    pub(crate) const fn from_u8(value: u8) -> Option<Self> {
+        if value > 23 {
+            return None;
+        }
+        Some(Self(value))
+    }
+
+    pub fn pred(&self) -> Option<Self> {
+        if self.0 > 0 {
+            Some(Self(self.0 - 1))
+        } else {
+            None
+        }
+    }
+
+    pub fn succ(&self) -> Option<Self> {
+        if self.0 < 23 {
+            Some(Self(self.0 + 1))
+        } else {
+            None
+        }
+    }
+
+    pub fn range<R: RangeBounds<Self>>(range: R) -> RangeIter<Self> {
+        range_iter(range)
+    }
+}
+
+impl Step for Hour {
+    fn first() -> Self {
+        Self::min()
+    }
+
+    fn last() -> Self {
+        Self::max()
+    }
+
+    fn position(&self) -> u32 {
+        u32::from(self.0)
+    }
+
+    fn pred(&self) -> Option<Self> {
+        Self::pred(self)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        Self::succ(self)
+    }
 }
 
 #[derive(Debug, Eq, Error, PartialEq)]
@@ -147,10 +199,7 @@ impl std::convert::TryFrom<u8> for Hour {
     type Error = TryFromHourError;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
-        if !(0..=23).contains(&value) {
-            return Err(Self::Error::OutOfRange);
-        }
-        Ok(Self(value))
+        Self::from_u8(value).ok_or(Self::Error::OutOfRange)
     }
 }
 
@@ -288,4 +337,39 @@ mod tests {
         assert_eq!(f(23_u64).map(u64::from), Ok(23_u64));
         assert_eq!(f(24_u64), Err(E::OutOfRange));
     }
+
+    #[test]
+    fn pred_test() -> anyhow::Result<()> {
+        assert_eq!(Hour::from_str("00")?.pred(), None);
+        assert_eq!(Hour::from_str("01")?.pred(), Some(Hour::from_str("00")?));
+        assert_eq!(Hour::from_str("23")?.pred(), Some(Hour::from_str("22")?));
+        Ok(())
+    }
+
+    #[test]
+    fn succ_test() -> anyhow::Result<()> {
+        assert_eq!(Hour::from_str("00")?.succ(), Some(Hour::from_str("01")?));
+        assert_eq!(Hour::from_str("22")?.succ(), Some(Hour::from_str("23")?));
+        assert_eq!(Hour::from_str("23")?.succ(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn range_test() -> anyhow::Result<()> {
+        let (a, b) = (Hour::from_str("03")?, Hour::from_str("05")?);
+        let f = |v: Vec<Hour>| v.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+        assert_eq!(f(Hour::range(a..=b).collect()), vec!["03", "04", "05"]);
+        assert_eq!(f(Hour::range(a..b).collect()), vec!["03", "04"]);
+        assert_eq!(
+            f(Hour::range(a..=b).rev().collect()),
+            vec!["05", "04", "03"]
+        );
+        assert_eq!(Hour::range(a..=b).len(), 3);
+        assert_eq!(Hour::range(b..a).len(), 0);
+        assert_eq!(Hour::range(a..a).len(), 0);
+        assert_eq!(Hour::range(..).len(), 24);
+        assert_eq!(Hour::range(..).next(), Some(Hour::first()));
+        assert_eq!(Hour::range(..).next_back(), Some(Hour::last()));
+        Ok(())
+    }
 }
@@ -0,0 +1,216 @@
+use std::str::FromStr;
+use thiserror::Error;
+
+// the fraction of a second in nanoseconds
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Nanosecond(u32);
+
+const MAX_NANOSECOND: Nanosecond = Nanosecond(999_999_999);
+
+const MIN_NANOSECOND: Nanosecond = Nanosecond(0);
+
+impl Nanosecond {
+    pub const fn max() -> Self {
+        MAX_NANOSECOND
+    }
+
+    pub const fn min() -> Self {
+        MIN_NANOSECOND
+    }
+
+    pub(crate) const fn from_u32(value: u32) -> Option<Self> {
+        if value > MAX_NANOSECOND.0 {
+            return None;
+        }
+        Some(Self(value))
+    }
+
+    // RFC 3339 time-secfrac without the leading "." (e.g. "5" or "123456")
+    pub(crate) fn from_fraction_str(s: &str) -> Result<Self, ParseNanosecondError> {
+        if s.is_empty() || s.len() > 9 {
+            return Err(ParseNanosecondError::InvalidLength);
+        }
+        Self::from_str(&format!("{:0<9}", s))
+    }
+
+    // 3, 6 or 9 digits without the leading "." (None if zero)
+    pub(crate) fn to_fraction_string(self) -> Option<String> {
+        match self.0 {
+            0 => None,
+            n if n % 1_000_000 == 0 => Some(format!("{:03}", n / 1_000_000)),
+            n if n % 1_000 == 0 => Some(format!("{:06}", n / 1_000)),
+            n => Some(format!("{:09}", n)),
+        }
+    }
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParseNanosecondError {
+    #[error("invalid digit")]
+    InvalidDigit,
+    #[error("invalid length")]
+    InvalidLength,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum TryFromNanosecondError {
+    #[error("out of range")]
+    OutOfRange,
+}
+
+impl std::fmt::Display for Nanosecond {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:09}", self.0)
+    }
+}
+
+impl std::str::FromStr for Nanosecond {
+    type Err = ParseNanosecondError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 9 {
+            return Err(Self::Err::InvalidLength);
+        }
+        let mut n = 0_u32;
+        for c in s.chars() {
+            let d = match c {
+                '0'..='9' => c as u32 - u32::from(b'0'),
+                _ => return Err(Self::Err::InvalidDigit),
+            };
+            n = n * 10 + d;
+        }
+        Ok(Self(n))
+    }
+}
+
+impl From<Nanosecond> for i64 {
+    fn from(nanosecond: Nanosecond) -> Self {
+        i64::from(nanosecond.0)
+    }
+}
+
+impl From<Nanosecond> for u32 {
+    fn from(nanosecond: Nanosecond) -> Self {
+        nanosecond.0
+    }
+}
+
+impl From<Nanosecond> for u64 {
+    fn from(nanosecond: Nanosecond) -> Self {
+        u64::from(nanosecond.0)
+    }
+}
+
+impl std::convert::TryFrom<i64> for Nanosecond {
+    type Error = TryFromNanosecondError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        let value_as_u32 = u32::try_from(value).map_err(|_| Self::Error::OutOfRange)?;
+        Self::try_from(value_as_u32)
+    }
+}
+
+impl std::convert::TryFrom<u32> for Nanosecond {
+    type Error = TryFromNanosecondError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Self::from_u32(value).ok_or(Self::Error::OutOfRange)
+    }
+}
+
+impl std::convert::TryFrom<u64> for Nanosecond {
+    type Error = TryFromNanosecondError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        let value_as_u32 = u32::try_from(value).map_err(|_| Self::Error::OutOfRange)?;
+        Self::try_from(value_as_u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::*;
+
+    #[test]
+    fn max_test() -> anyhow::Result<()> {
+        assert_eq!(Nanosecond::max(), Nanosecond::from_str("999999999")?);
+        Ok(())
+    }
+
+    #[test]
+    fn min_test() -> anyhow::Result<()> {
+        assert_eq!(Nanosecond::min(), Nanosecond::from_str("000000000")?);
+        Ok(())
+    }
+
+    #[test]
+    fn str_conversion_test() {
+        type E = ParseNanosecondError;
+        let f = |s: &str| s.parse::<Nanosecond>();
+        assert_eq!(
+            f("000000000").map(|d| d.to_string()),
+            Ok("000000000".to_string())
+        );
+        assert_eq!(
+            f("123456789").map(|d| d.to_string()),
+            Ok("123456789".to_string())
+        );
+        assert_eq!(f(""), Err(E::InvalidLength));
+        assert_eq!(f("12345678"), Err(E::InvalidLength));
+        assert_eq!(f("1234567890"), Err(E::InvalidLength));
+        assert_eq!(f("12345678a"), Err(E::InvalidDigit));
+        assert_eq!(f("+12345678"), Err(E::InvalidDigit));
+    }
+
+    #[test]
+    fn fraction_conversion_test() -> anyhow::Result<()> {
+        type E = ParseNanosecondError;
+        let f = |s: &str| Nanosecond::from_fraction_str(s).map(u32::from);
+        assert_eq!(f("5"), Ok(500_000_000));
+        assert_eq!(f("123"), Ok(123_000_000));
+        assert_eq!(f("000123"), Ok(123_000));
+        assert_eq!(f("123456789"), Ok(123_456_789));
+        assert_eq!(f(""), Err(E::InvalidLength));
+        assert_eq!(f("1234567890"), Err(E::InvalidLength));
+        assert_eq!(f("1a"), Err(E::InvalidDigit));
+
+        let g = |n: u32| -> anyhow::Result<Option<String>> {
+            Ok(Nanosecond::try_from(n)?.to_fraction_string())
+        };
+        assert_eq!(g(0)?, None);
+        assert_eq!(g(500_000_000)?, Some("500".to_string()));
+        assert_eq!(g(123_000)?, Some("000123".to_string()));
+        assert_eq!(g(1)?, Some("000000001".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn u32_conversion_test() {
+        type E = TryFromNanosecondError;
+        let f = |d: u32| Nanosecond::try_from(d);
+        assert_eq!(f(0_u32).map(u32::from), Ok(0_u32));
+        assert_eq!(f(999_999_999_u32).map(u32::from), Ok(999_999_999_u32));
+        assert_eq!(f(1_000_000_000_u32), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn i64_conversion_test() {
+        type E = TryFromNanosecondError;
+        let f = |d: i64| Nanosecond::try_from(d);
+        assert_eq!(f(-1_i64), Err(E::OutOfRange));
+        assert_eq!(f(0_i64).map(i64::from), Ok(0_i64));
+        assert_eq!(f(999_999_999_i64).map(i64::from), Ok(999_999_999_i64));
+        assert_eq!(f(1_000_000_000_i64), Err(E::OutOfRange));
+    }
+
+    #[test]
+    fn u64_conversion_test() {
+        type E = TryFromNanosecondError;
+        let f = |d: u64| Nanosecond::try_from(d);
+        assert_eq!(f(0_u64).map(u64::from), Ok(0_u64));
+        assert_eq!(f(999_999_999_u64).map(u64::from), Ok(999_999_999_u64));
+        assert_eq!(f(1_000_000_000_u64), Err(E::OutOfRange));
+    }
+}
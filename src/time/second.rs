@@ -1,6 +1,9 @@
 use std::convert::TryFrom;
+use std::ops::RangeBounds;
 use thiserror::Error;
 
+use crate::range_iter::{range_iter, RangeIter, Step};
+
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Second(u8);
 
@@ -16,6 +19,55 @@ impl Second {
     pub const fn min() -> Self {
         MIN_SECOND
     }
+
+    pub(crate) const fn from_u8(value: u8) -> Option<Self> {
+        if value > 59 {
+            return None;
+        }
+        Some(Self(value))
+    }
+
+    pub fn pred(&self) -> Option<Self> {
+        if self.0 > 0 {
+            Some(Self(self.0 - 1))
+        } else {
+            None
+        }
+    }
+
+    pub fn succ(&self) -> Option<Self> {
+        if self.0 < 59 {
+            Some(Self(self.0 + 1))
+        } else {
+            None
+        }
+    }
+
+    pub fn range<R: RangeBounds<Self>>(range: R) -> RangeIter<Self> {
+        range_iter(range)
+    }
+}
+
+impl Step for Second {
+    fn first() -> Self {
+        Self::min()
+    }
+
+    fn last() -> Self {
+        Self::max()
+    }
+
+    fn position(&self) -> u32 {
+        u32::from(self.0)
+    }
+
+    fn pred(&self) -> Option<Self> {
+        Self::pred(self)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        Self::succ(self)
+    }
 }
 
 #[derive(Debug, Eq, Error, PartialEq)]
@@ -147,10 +199,7 @@ impl std::convert::TryFrom<u8> for Second {
     type Error = TryFromSecondError;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
-        if !(0..=59).contains(&value) {
-            return Err(Self::Error::OutOfRange);
-        }
-        Ok(Self(value))
+        Self::from_u8(value).ok_or(Self::Error::OutOfRange)
     }
 }
 
@@ -288,4 +337,51 @@ mod tests {
         assert_eq!(f(59_u64).map(u64::from), Ok(59_u64));
         assert_eq!(f(60_u64), Err(E::OutOfRange));
     }
+
+    #[test]
+    fn pred_test() -> anyhow::Result<()> {
+        assert_eq!(Second::from_str("00")?.pred(), None);
+        assert_eq!(
+            Second::from_str("01")?.pred(),
+            Some(Second::from_str("00")?)
+        );
+        assert_eq!(
+            Second::from_str("59")?.pred(),
+            Some(Second::from_str("58")?)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn succ_test() -> anyhow::Result<()> {
+        assert_eq!(
+            Second::from_str("00")?.succ(),
+            Some(Second::from_str("01")?)
+        );
+        assert_eq!(
+            Second::from_str("58")?.succ(),
+            Some(Second::from_str("59")?)
+        );
+        assert_eq!(Second::from_str("59")?.succ(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn range_test() -> anyhow::Result<()> {
+        let (a, b) = (Second::from_str("03")?, Second::from_str("05")?);
+        let f = |v: Vec<Second>| v.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+        assert_eq!(f(Second::range(a..=b).collect()), vec!["03", "04", "05"]);
+        assert_eq!(f(Second::range(a..b).collect()), vec!["03", "04"]);
+        assert_eq!(
+            f(Second::range(a..=b).rev().collect()),
+            vec!["05", "04", "03"]
+        );
+        assert_eq!(Second::range(a..=b).len(), 3);
+        assert_eq!(Second::range(b..a).len(), 0);
+        assert_eq!(Second::range(a..a).len(), 0);
+        assert_eq!(Second::range(..).len(), 60);
+        assert_eq!(Second::range(..).next(), Some(Second::first()));
+        assert_eq!(Second::range(..).next_back(), Some(Second::last()));
+        Ok(())
+    }
 }
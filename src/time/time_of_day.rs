@@ -0,0 +1,102 @@
+use crate::{ParseHourError, ParseTimeError, Time};
+
+// Time that allows the end of the day ("24:00:00", ISO 8601)
+// EndOfDay is after any Time
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum TimeOfDay {
+    Time(Time),
+    EndOfDay,
+}
+
+impl TimeOfDay {
+    pub fn is_end_of_day(&self) -> bool {
+        matches!(self, TimeOfDay::EndOfDay)
+    }
+
+    // None if the end of the day
+    pub fn time(&self) -> Option<Time> {
+        match self {
+            TimeOfDay::Time(time) => Some(*time),
+            TimeOfDay::EndOfDay => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeOfDay::Time(time) => write!(f, "{}", time),
+            TimeOfDay::EndOfDay => write!(f, "24:00:00"),
+        }
+    }
+}
+
+// "24:00:00" (or with a zero fraction) is the end of the day
+impl std::str::FromStr for TimeOfDay {
+    type Err = ParseTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("24") {
+            Some(rest) => {
+                if Time::from_str(&format!("00{}", rest))? != Time::min() {
+                    return Err(ParseTimeError::ParseHour(ParseHourError::OutOfRange));
+                }
+                Ok(TimeOfDay::EndOfDay)
+            }
+            None => Time::from_str(s).map(TimeOfDay::Time),
+        }
+    }
+}
+
+impl From<Time> for TimeOfDay {
+    fn from(time: Time) -> Self {
+        TimeOfDay::Time(time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        type E = ParseTimeError;
+        let f = |s: &str| -> anyhow::Result<String> { Ok(TimeOfDay::from_str(s)?.to_string()) };
+        assert_eq!(f("24:00:00")?, "24:00:00");
+        assert_eq!(f("24:00:00.000")?, "24:00:00");
+        assert_eq!(f("00:00:00")?, "00:00:00");
+        assert_eq!(f("23:59:59.5")?, "23:59:59.500");
+        let f = TimeOfDay::from_str;
+        assert_eq!(f("24:00:01"), Err(E::ParseHour(ParseHourError::OutOfRange)));
+        assert_eq!(f("24:01:00"), Err(E::ParseHour(ParseHourError::OutOfRange)));
+        assert_eq!(f("24:0é:00.0"), Err(E::InvalidFormat));
+        assert_eq!(
+            f("24:00:00.001"),
+            Err(E::ParseHour(ParseHourError::OutOfRange))
+        );
+        assert_eq!(f("25:00:00"), Err(E::ParseHour(ParseHourError::OutOfRange)));
+        assert_eq!(f("24:00"), Err(E::InvalidLength));
+        Ok(())
+    }
+
+    #[test]
+    fn accessors_test() -> anyhow::Result<()> {
+        let end_of_day = TimeOfDay::from_str("24:00:00")?;
+        assert!(end_of_day.is_end_of_day());
+        assert_eq!(end_of_day.time(), None);
+        let time = Time::from_str("23:59:59")?;
+        assert!(!TimeOfDay::from(time).is_end_of_day());
+        assert_eq!(TimeOfDay::from(time).time(), Some(time));
+        Ok(())
+    }
+
+    #[test]
+    fn ord_test() -> anyhow::Result<()> {
+        let t = |s: &str| TimeOfDay::from_str(s);
+        assert!(t("00:00:00")? < t("23:59:59.999999999")?);
+        assert!(t("23:59:59.999999999")? < t("24:00:00")?);
+        Ok(())
+    }
+}
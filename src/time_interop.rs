@@ -0,0 +1,235 @@
+// time support (cargo feature "time")
+// the conversions from time fail if the value is out of range (1970-01-01 to 9999-12-31)
+// or not representable (e.g. an offset with seconds)
+
+use std::convert::TryFrom;
+
+use ::time::{PrimitiveDateTime, UtcOffset};
+
+use crate::{
+    CalendarDate, DateTime, DayOfMonth, Hour, Instant, Minute, Month, Nanosecond, OffsetDateTime,
+    PreciseInstant, Second, Time, TimeZoneOffset, TryFromCalendarDateError, TryFromDateTimeError,
+    TryFromOffsetDateTimeError, TryFromTimeZoneOffsetError, Year,
+};
+
+impl From<CalendarDate> for ::time::Date {
+    fn from(date: CalendarDate) -> Self {
+        let month = ::time::Month::try_from(u8::from(date.month())).expect("month is [1, 12]");
+        ::time::Date::from_calendar_date(
+            i32::from(date.year()),
+            month,
+            u8::from(date.day_of_month()),
+        )
+        .expect("CalendarDate is a valid time::Date")
+    }
+}
+
+impl TryFrom<::time::Date> for CalendarDate {
+    type Error = TryFromCalendarDateError;
+
+    fn try_from(date: ::time::Date) -> Result<Self, Self::Error> {
+        let year = Year::try_from(date.year()).map_err(|_| TryFromCalendarDateError::OutOfRange)?;
+        let month = Month::try_from(u8::from(date.month())).expect("month is [1, 12]");
+        let day_of_month = DayOfMonth::try_from(date.day()).expect("day of month is [1, 31]");
+        Ok(CalendarDate::from_ymd(year, month, day_of_month).expect("time::Date is a valid date"))
+    }
+}
+
+impl From<Time> for ::time::Time {
+    fn from(time: Time) -> Self {
+        ::time::Time::from_hms_nano(
+            u8::from(time.hour()),
+            u8::from(time.minute()),
+            u8::from(time.second()),
+            u32::from(time.nanosecond()),
+        )
+        .expect("Time is a valid time::Time")
+    }
+}
+
+impl From<::time::Time> for Time {
+    fn from(time: ::time::Time) -> Self {
+        Time::from_hms_nano(
+            Hour::try_from(time.hour()).expect("hour is [0, 23]"),
+            Minute::try_from(time.minute()).expect("minute is [0, 59]"),
+            Second::try_from(time.second()).expect("second is [0, 59]"),
+            Nanosecond::try_from(time.nanosecond()).expect("nanosecond is [0, 999_999_999]"),
+        )
+    }
+}
+
+impl From<DateTime> for PrimitiveDateTime {
+    fn from(date_time: DateTime) -> Self {
+        PrimitiveDateTime::new(
+            ::time::Date::from(date_time.date()),
+            ::time::Time::from(date_time.time()),
+        )
+    }
+}
+
+impl TryFrom<PrimitiveDateTime> for DateTime {
+    type Error = TryFromDateTimeError;
+
+    fn try_from(date_time: PrimitiveDateTime) -> Result<Self, Self::Error> {
+        let date = CalendarDate::try_from(date_time.date())
+            .map_err(|_| TryFromDateTimeError::OutOfRange)?;
+        Ok(DateTime::new(date, Time::from(date_time.time())))
+    }
+}
+
+impl From<TimeZoneOffset> for UtcOffset {
+    fn from(offset: TimeZoneOffset) -> Self {
+        UtcOffset::from_whole_seconds(i32::from(offset.offset_in_minutes()) * 60)
+            .expect("TimeZoneOffset is a valid UtcOffset")
+    }
+}
+
+// an offset with seconds (e.g. +09:18:59) is out of range
+impl TryFrom<UtcOffset> for TimeZoneOffset {
+    type Error = TryFromTimeZoneOffsetError;
+
+    fn try_from(offset: UtcOffset) -> Result<Self, Self::Error> {
+        let seconds = offset.whole_seconds();
+        if seconds % 60 != 0 {
+            return Err(TryFromTimeZoneOffsetError::OutOfRange);
+        }
+        TimeZoneOffset::from_offset_in_minutes((seconds / 60) as i16)
+    }
+}
+
+impl From<OffsetDateTime> for ::time::OffsetDateTime {
+    fn from(offset_date_time: OffsetDateTime) -> Self {
+        PrimitiveDateTime::from(offset_date_time.date_time())
+            .assume_offset(UtcOffset::from(offset_date_time.offset()))
+    }
+}
+
+impl TryFrom<::time::OffsetDateTime> for OffsetDateTime {
+    type Error = TryFromOffsetDateTimeError;
+
+    fn try_from(date_time: ::time::OffsetDateTime) -> Result<Self, Self::Error> {
+        let offset = TimeZoneOffset::try_from(date_time.offset())
+            .map_err(|_| TryFromOffsetDateTimeError::OutOfRange)?;
+        let instant = Instant::try_from(date_time.unix_timestamp())
+            .map_err(|_| TryFromOffsetDateTimeError::OutOfRange)?;
+        let nanosecond =
+            Nanosecond::try_from(date_time.nanosecond()).expect("nanosecond is [0, 999_999_999]");
+        OffsetDateTime::from_precise_instant(PreciseInstant::new(instant, nanosecond), offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn date(y: i32, m: u8, d: u8) -> anyhow::Result<::time::Date> {
+        Ok(::time::Date::from_calendar_date(
+            y,
+            ::time::Month::try_from(m)?,
+            d,
+        )?)
+    }
+
+    fn offset_date_time(
+        (y, mo, d): (i32, u8, u8),
+        (h, mi, s, ns): (u8, u8, u8, u32),
+        offset_in_seconds: i32,
+    ) -> anyhow::Result<::time::OffsetDateTime> {
+        Ok(
+            PrimitiveDateTime::new(date(y, mo, d)?, ::time::Time::from_hms_nano(h, mi, s, ns)?)
+                .assume_offset(UtcOffset::from_whole_seconds(offset_in_seconds)?),
+        )
+    }
+
+    #[test]
+    fn calendar_date_test() -> anyhow::Result<()> {
+        let calendar_date = CalendarDate::from_str("2021-02-03")?;
+        assert_eq!(::time::Date::from(calendar_date), date(2021, 2, 3)?);
+        assert_eq!(CalendarDate::try_from(date(2021, 2, 3)?)?, calendar_date);
+        let f = |y: i32, m: u8, d: u8| -> anyhow::Result<_> {
+            Ok(CalendarDate::try_from(date(y, m, d)?))
+        };
+        assert_eq!(f(1970, 1, 1)??.to_string(), "1970-01-01");
+        assert_eq!(f(9999, 12, 31)??.to_string(), "9999-12-31");
+        assert_eq!(f(1969, 12, 31)?, Err(TryFromCalendarDateError::OutOfRange));
+        Ok(())
+    }
+
+    #[test]
+    fn time_test() -> anyhow::Result<()> {
+        let time = Time::from_str("04:05:06.789")?;
+        let time_time = ::time::Time::from_hms_nano(4, 5, 6, 789_000_000)?;
+        assert_eq!(::time::Time::from(time), time_time);
+        assert_eq!(Time::from(time_time), time);
+        Ok(())
+    }
+
+    #[test]
+    fn date_time_test() -> anyhow::Result<()> {
+        let date_time = DateTime::from_str("2021-02-03T04:05:06.789")?;
+        let primitive_date_time = PrimitiveDateTime::new(
+            date(2021, 2, 3)?,
+            ::time::Time::from_hms_nano(4, 5, 6, 789_000_000)?,
+        );
+        assert_eq!(PrimitiveDateTime::from(date_time), primitive_date_time);
+        assert_eq!(DateTime::try_from(primitive_date_time)?, date_time);
+        assert_eq!(
+            DateTime::try_from(PrimitiveDateTime::new(
+                date(1969, 12, 31)?,
+                ::time::Time::MIDNIGHT
+            )),
+            Err(TryFromDateTimeError::OutOfRange)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn time_zone_offset_test() -> anyhow::Result<()> {
+        let offset = TimeZoneOffset::from_hm(-9, -30)?;
+        let utc_offset = UtcOffset::from_hms(-9, -30, 0)?;
+        assert_eq!(UtcOffset::from(offset), utc_offset);
+        assert_eq!(TimeZoneOffset::try_from(utc_offset)?, offset);
+        assert_eq!(
+            TimeZoneOffset::try_from(UtcOffset::from_hms(9, 18, 59)?),
+            Err(TryFromTimeZoneOffsetError::OutOfRange)
+        );
+        assert_eq!(
+            TimeZoneOffset::try_from(UtcOffset::from_hms(24, 0, 0)?),
+            Err(TryFromTimeZoneOffsetError::OutOfRange)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn offset_date_time_test() -> anyhow::Result<()> {
+        let ours = OffsetDateTime::from_str("2021-02-03T04:05:06.789+09:00")?;
+        let theirs = offset_date_time((2021, 2, 3), (4, 5, 6, 789_000_000), 9 * 3_600)?;
+        assert_eq!(::time::OffsetDateTime::from(ours), theirs);
+        assert_eq!(OffsetDateTime::try_from(theirs)?, ours);
+
+        let f = |date: (i32, u8, u8), time: (u8, u8, u8, u32), offset_in_seconds: i32| {
+            offset_date_time(date, time, offset_in_seconds).map(OffsetDateTime::try_from)
+        };
+        assert_eq!(
+            f((1970, 1, 1), (0, 0, 0, 0), -60)?,
+            Ok(OffsetDateTime::from_str("1970-01-01T00:00:00-00:01")?)
+        );
+        // the instant is out of range
+        assert_eq!(
+            f((1970, 1, 1), (0, 0, 0, 0), 60)?,
+            Err(TryFromOffsetDateTimeError::OutOfRange)
+        );
+        // the local date time is out of range
+        assert_eq!(
+            f((1969, 12, 31), (23, 59, 59, 0), -60)?,
+            Err(TryFromOffsetDateTimeError::OutOfRange)
+        );
+        assert_eq!(
+            f((9999, 12, 31), (23, 59, 59, 0), -60)?,
+            Err(TryFromOffsetDateTimeError::OutOfRange)
+        );
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::{Instant, LoadTimeZoneError, LocalTimeZoneConfig};
+
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct TimeZoneOffset(i16);
 
@@ -22,11 +24,14 @@ pub enum TryFromTimeZoneOffsetError {
 }
 
 impl TimeZoneOffset {
-    pub fn from_h(hours: i8) -> Result<TimeZoneOffset, TryFromTimeZoneOffsetError> {
+    pub const fn from_h(hours: i8) -> Result<TimeZoneOffset, TryFromTimeZoneOffsetError> {
         Self::from_hm(hours, 0)
     }
 
-    pub fn from_hm(hours: i8, minutes: i8) -> Result<TimeZoneOffset, TryFromTimeZoneOffsetError> {
+    pub const fn from_hm(
+        hours: i8,
+        minutes: i8,
+    ) -> Result<TimeZoneOffset, TryFromTimeZoneOffsetError> {
         if hours <= -24 || 24 <= hours {
             return Err(TryFromTimeZoneOffsetError::OutOfRange);
         }
@@ -38,7 +43,7 @@ impl TimeZoneOffset {
         Self::from_offset_in_minutes(hours as i16 * 60 + minutes as i16)
     }
 
-    pub fn from_offset_in_minutes(
+    pub const fn from_offset_in_minutes(
         offset_in_minutes: i16,
     ) -> Result<TimeZoneOffset, TryFromTimeZoneOffsetError> {
         if offset_in_minutes <= -1_440_i16 || 1_440_i16 <= offset_in_minutes {
@@ -48,19 +53,31 @@ impl TimeZoneOffset {
         Ok(Self(offset_in_minutes))
     }
 
-    pub fn utc() -> TimeZoneOffset {
+    // the offset of the system local time zone (see TimeZone::local)
+    pub fn local_at(instant: Instant) -> Result<TimeZoneOffset, LoadTimeZoneError> {
+        Self::local_at_with(&LocalTimeZoneConfig::from_env(), instant)
+    }
+
+    pub(crate) fn local_at_with(
+        config: &LocalTimeZoneConfig,
+        instant: Instant,
+    ) -> Result<TimeZoneOffset, LoadTimeZoneError> {
+        Ok(config.load()?.offset_at(instant))
+    }
+
+    pub const fn utc() -> TimeZoneOffset {
         TimeZoneOffset(0)
     }
 
-    pub fn hour(&self) -> i8 {
+    pub const fn hour(&self) -> i8 {
         (self.0 / 60_i16) as i8
     }
 
-    pub fn minute(&self) -> i8 {
+    pub const fn minute(&self) -> i8 {
         (self.0 % 60_i16) as i8
     }
 
-    pub fn offset_in_minutes(&self) -> i16 {
+    pub const fn offset_in_minutes(&self) -> i16 {
         self.0
     }
 }
@@ -84,6 +101,9 @@ impl std::str::FromStr for TimeZoneOffset {
         if s.len() != 6 {
             return Err(Self::Err::InvalidLength);
         }
+        if !s.is_ascii() {
+            return Err(Self::Err::InvalidFormat);
+        }
         let chars = s.chars().collect::<Vec<char>>();
         let signed = match chars[0] {
             '+' => 1,
@@ -120,7 +140,7 @@ impl std::str::FromStr for TimeZoneOffset {
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
+    use std::{path::PathBuf, str::FromStr};
 
     use super::*;
 
@@ -136,6 +156,7 @@ mod tests {
         assert_eq!(g("+09:00")?.to_string(), "+09:00");
         assert_eq!(g("+00:00")?.to_string(), "+00:00");
         assert_eq!(g("-23:59")?.to_string(), "-23:59");
+        assert_eq!(g("+09:é"), Err(ParseTimeZoneOffsetError::InvalidFormat));
         Ok(())
     }
 
@@ -166,6 +187,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn local_at_test() -> anyhow::Result<()> {
+        let zoneinfo_dir = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/zoneinfo"));
+        let missing = zoneinfo_dir.join("missing");
+        let f = |tz: Option<&str>| {
+            let config = LocalTimeZoneConfig::new(
+                tz.map(str::to_string),
+                missing.clone(),
+                zoneinfo_dir.clone(),
+            );
+            TimeZoneOffset::local_at_with(&config, Instant::from(1_612_310_400_u32))
+        };
+        assert_eq!(f(Some("Asia/Tokyo"))?, TimeZoneOffset::from_h(9)?);
+        assert_eq!(f(Some(""))?, TimeZoneOffset::utc());
+        assert!(matches!(f(None), Err(LoadTimeZoneError::Io(_))));
+        Ok(())
+    }
+
     #[test]
     fn minute_test() -> anyhow::Result<()> {
         assert_eq!(TimeZoneOffset::from_str("+23:59")?.minute(), 59);
@@ -0,0 +1,25 @@
+mod dst_rule;
+mod local_result;
+mod local_time_type;
+mod local_time_zone_config;
+mod offset_history;
+mod offset_rule;
+mod posix_time_zone;
+mod time_zone;
+mod transition;
+mod transition_rule;
+mod tzdata;
+mod tzif;
+
+pub use self::dst_rule::*;
+pub use self::local_result::*;
+pub use self::local_time_type::*;
+pub use self::local_time_zone_config::*;
+pub use self::offset_history::*;
+pub use self::offset_rule::*;
+pub use self::posix_time_zone::*;
+pub use self::time_zone::*;
+pub use self::transition::*;
+pub use self::transition_rule::*;
+pub use self::tzdata::*;
+pub use self::tzif::*;
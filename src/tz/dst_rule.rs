@@ -0,0 +1,283 @@
+use std::convert::TryFrom;
+
+use crate::private::date_from_days_from_unix_epoch;
+use crate::{Instant, TimeZoneOffset, Year};
+
+use super::offset_rule::OffsetRule;
+use super::transition::Transition;
+use super::transition_rule::TransitionRule;
+
+// standard time and daylight saving time (e.g. EST5EDT,M3.2.0,M11.1.0)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DstRule {
+    std_offset: TimeZoneOffset,
+    dst_offset: TimeZoneOffset,
+    start: TransitionRule,
+    end: TransitionRule,
+}
+
+impl DstRule {
+    // start: wall clock time in standard time
+    // end: wall clock time in daylight saving time
+    pub fn new(
+        std_offset: TimeZoneOffset,
+        dst_offset: TimeZoneOffset,
+        start: TransitionRule,
+        end: TransitionRule,
+    ) -> Self {
+        Self {
+            std_offset,
+            dst_offset,
+            start,
+            end,
+        }
+    }
+
+    pub fn dst_offset(&self) -> TimeZoneOffset {
+        self.dst_offset
+    }
+
+    pub fn end(&self) -> TransitionRule {
+        self.end
+    }
+
+    pub fn is_dst_at(&self, instant: Instant) -> bool {
+        self.is_dst_at_timestamp(i64::from(instant))
+    }
+
+    pub fn start(&self) -> TransitionRule {
+        self.start
+    }
+
+    pub fn std_offset(&self) -> TimeZoneOffset {
+        self.std_offset
+    }
+
+    // the transitions of the rule for the year (sorted by instant)
+    pub fn transitions(&self, year: Year) -> Vec<Transition> {
+        let (start, end) = self.transition_timestamps(i64::from(year));
+        let mut transitions = Vec::new();
+        for timestamp in [start, end] {
+            let instant = match Instant::try_from(timestamp) {
+                Ok(instant) => instant,
+                Err(_) => continue,
+            };
+            let before = self.offset_at_timestamp(timestamp - 1);
+            let after = self.offset_at_timestamp(timestamp);
+            // e.g. permanent daylight saving time (J0/0,J365/25)
+            if before != after {
+                transitions.push(Transition::new(instant, before, after));
+            }
+        }
+        transitions.sort_by_key(|transition| transition.instant());
+        transitions
+    }
+
+    pub(crate) fn is_dst_at_timestamp(&self, timestamp: i64) -> bool {
+        let std_offset = i64::from(self.std_offset.offset_in_minutes()) * 60;
+        let (year, _, _) =
+            date_from_days_from_unix_epoch((timestamp + std_offset).div_euclid(86400));
+        let (start, end) = self.transition_timestamps(year);
+        if start < end {
+            start <= timestamp && timestamp < end
+        } else {
+            // southern hemisphere
+            !(end <= timestamp && timestamp < start)
+        }
+    }
+
+    fn offset_at_timestamp(&self, timestamp: i64) -> TimeZoneOffset {
+        if self.is_dst_at_timestamp(timestamp) {
+            self.dst_offset
+        } else {
+            self.std_offset
+        }
+    }
+
+    // (start, end) as seconds from unix epoch
+    fn transition_timestamps(&self, year: i64) -> (i64, i64) {
+        let std_offset = i64::from(self.std_offset.offset_in_minutes()) * 60;
+        let dst_offset = i64::from(self.dst_offset.offset_in_minutes()) * 60;
+        (
+            self.start.local_seconds_in_year(year) - std_offset,
+            self.end.local_seconds_in_year(year) - dst_offset,
+        )
+    }
+}
+
+impl OffsetRule for DstRule {
+    fn offset_at(&self, instant: Instant) -> TimeZoneOffset {
+        self.offset_at_timestamp(i64::from(instant))
+    }
+
+    fn next_transition(&self, instant: Instant) -> Option<Transition> {
+        let (year, _, _) = date_from_days_from_unix_epoch(i64::from(instant).div_euclid(86400));
+        // the local year may differ from the UTC year
+        (year - 1..=year + 1)
+            .filter_map(|year| Year::try_from(year).ok())
+            .flat_map(|year| self.transitions(year))
+            .find(|transition| transition.instant() > instant)
+    }
+}
+
+// EST5EDT,M3.2.0,M11.1.0 for tests
+#[cfg(test)]
+pub(crate) fn new_york_rule() -> anyhow::Result<DstRule> {
+    use crate::{DayOfWeek, Month, SignedSeconds};
+
+    let rule = |month: Month, week: u8| {
+        TransitionRule::new(month, week, DayOfWeek::sunday(), SignedSeconds::from(7200))
+    };
+    Ok(DstRule::new(
+        TimeZoneOffset::from_h(-5)?,
+        TimeZoneOffset::from_h(-4)?,
+        rule(Month::march(), 2)?,
+        rule(Month::november(), 1)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{DayOfWeek, Month, SignedSeconds, TransitionDate};
+
+    use super::super::transition::transition_string;
+    use super::*;
+
+    // AEST-10AEDT,M10.1.0,M4.1.0/3
+    fn sydney_rule() -> anyhow::Result<DstRule> {
+        Ok(DstRule::new(
+            TimeZoneOffset::from_h(10)?,
+            TimeZoneOffset::from_h(11)?,
+            TransitionRule::new(
+                Month::october(),
+                1,
+                DayOfWeek::sunday(),
+                SignedSeconds::from(7200),
+            )?,
+            TransitionRule::new(
+                Month::april(),
+                1,
+                DayOfWeek::sunday(),
+                SignedSeconds::from(10800),
+            )?,
+        ))
+    }
+
+    #[test]
+    fn offset_at_test() -> anyhow::Result<()> {
+        let rule = new_york_rule()?;
+        let f = |s: &str| -> anyhow::Result<TimeZoneOffset> {
+            Ok(rule.offset_at(Instant::from_str(s)?))
+        };
+        assert_eq!(f("2021-01-01T00:00:00Z")?, TimeZoneOffset::from_h(-5)?);
+        assert_eq!(f("2021-03-14T06:59:59Z")?, TimeZoneOffset::from_h(-5)?);
+        assert_eq!(f("2021-03-14T07:00:00Z")?, TimeZoneOffset::from_h(-4)?);
+        assert_eq!(f("2021-11-07T05:59:59Z")?, TimeZoneOffset::from_h(-4)?);
+        assert_eq!(f("2021-11-07T06:00:00Z")?, TimeZoneOffset::from_h(-5)?);
+        assert_eq!(f("1970-01-01T00:00:00Z")?, TimeZoneOffset::from_h(-5)?);
+        assert_eq!(f("9999-12-31T23:59:59Z")?, TimeZoneOffset::from_h(-5)?);
+
+        let rule = sydney_rule()?;
+        let f = |s: &str| -> anyhow::Result<TimeZoneOffset> {
+            Ok(rule.offset_at(Instant::from_str(s)?))
+        };
+        assert_eq!(f("2021-01-01T00:00:00Z")?, TimeZoneOffset::from_h(11)?);
+        // 2021-04-04T03:00:00+11:00
+        assert_eq!(f("2021-04-03T15:59:59Z")?, TimeZoneOffset::from_h(11)?);
+        assert_eq!(f("2021-04-03T16:00:00Z")?, TimeZoneOffset::from_h(10)?);
+        // 2021-10-03T02:00:00+10:00
+        assert_eq!(f("2021-10-02T15:59:59Z")?, TimeZoneOffset::from_h(10)?);
+        assert_eq!(f("2021-10-02T16:00:00Z")?, TimeZoneOffset::from_h(11)?);
+        assert_eq!(f("2021-12-31T23:59:59Z")?, TimeZoneOffset::from_h(11)?);
+        Ok(())
+    }
+
+    #[test]
+    fn next_transition_test() -> anyhow::Result<()> {
+        let f = |rule: &DstRule, s: &str| -> anyhow::Result<Option<String>> {
+            Ok(rule
+                .next_transition(Instant::from_str(s)?)
+                .as_ref()
+                .map(transition_string))
+        };
+        let rule = new_york_rule()?;
+        let spring_forward = "2021-03-14T07:00:00Z -05:00 -04:00".to_string();
+        let fall_back = "2021-11-07T06:00:00Z -04:00 -05:00".to_string();
+        assert_eq!(
+            f(&rule, "2021-01-01T00:00:00Z")?,
+            Some(spring_forward.clone())
+        );
+        assert_eq!(f(&rule, "2021-03-14T06:59:59Z")?, Some(spring_forward));
+        assert_eq!(f(&rule, "2021-03-14T07:00:00Z")?, Some(fall_back.clone()));
+        assert_eq!(f(&rule, "2021-11-07T05:59:59Z")?, Some(fall_back));
+        assert_eq!(
+            f(&rule, "2021-11-07T06:00:00Z")?,
+            Some("2022-03-13T07:00:00Z -05:00 -04:00".to_string())
+        );
+        assert_eq!(f(&rule, "9999-11-07T06:00:00Z")?, None);
+
+        let rule = sydney_rule()?;
+        assert_eq!(
+            f(&rule, "2021-12-31T23:59:59Z")?,
+            Some("2022-04-02T16:00:00Z +11:00 +10:00".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn transitions_test() -> anyhow::Result<()> {
+        let f = |rule: &DstRule, year: &str| -> anyhow::Result<Vec<String>> {
+            Ok(rule
+                .transitions(Year::from_str(year)?)
+                .iter()
+                .map(transition_string)
+                .collect())
+        };
+        assert_eq!(
+            f(&new_york_rule()?, "2021")?,
+            vec![
+                "2021-03-14T07:00:00Z -05:00 -04:00",
+                "2021-11-07T06:00:00Z -04:00 -05:00"
+            ]
+        );
+        assert_eq!(
+            f(&sydney_rule()?, "2021")?,
+            vec![
+                "2021-04-03T16:00:00Z +11:00 +10:00",
+                "2021-10-02T16:00:00Z +10:00 +11:00"
+            ]
+        );
+        // out of range (1969-12-31T14:00:00Z)
+        let rule = DstRule::new(
+            TimeZoneOffset::from_h(10)?,
+            TimeZoneOffset::from_h(11)?,
+            TransitionRule::julian(1, SignedSeconds::from(0))?,
+            TransitionRule::julian(180, SignedSeconds::from(0))?,
+        );
+        assert_eq!(
+            f(&rule, "1970")?,
+            vec!["1970-06-28T13:00:00Z +11:00 +10:00"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn accessors_test() -> anyhow::Result<()> {
+        let rule = new_york_rule()?;
+        assert_eq!(rule.std_offset(), TimeZoneOffset::from_h(-5)?);
+        assert_eq!(rule.dst_offset(), TimeZoneOffset::from_h(-4)?);
+        assert_eq!(
+            rule.start().date(),
+            TransitionDate::MonthWeekDay(Month::march(), 2, DayOfWeek::sunday())
+        );
+        assert_eq!(
+            rule.end().date(),
+            TransitionDate::MonthWeekDay(Month::november(), 1, DayOfWeek::sunday())
+        );
+        assert!(!rule.is_dst_at(Instant::from_str("2021-01-01T00:00:00Z")?));
+        assert!(rule.is_dst_at(Instant::from_str("2021-07-01T00:00:00Z")?));
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use crate::OffsetDateTime;
+
+// the result of resolving a local date time
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LocalResult {
+    // skipped (e.g. 02:30 at the start of DST)
+    None,
+    Single(OffsetDateTime),
+    // repeated (e.g. 01:30 at the end of DST). (earlier, later)
+    Ambiguous(OffsetDateTime, OffsetDateTime),
+}
+
+impl LocalResult {
+    pub fn earliest(&self) -> Option<OffsetDateTime> {
+        match self {
+            LocalResult::None => None,
+            LocalResult::Single(t) | LocalResult::Ambiguous(t, _) => Some(*t),
+        }
+    }
+
+    pub fn latest(&self) -> Option<OffsetDateTime> {
+        match self {
+            LocalResult::None => None,
+            LocalResult::Single(t) | LocalResult::Ambiguous(_, t) => Some(*t),
+        }
+    }
+
+    pub fn single(&self) -> Option<OffsetDateTime> {
+        match self {
+            LocalResult::Single(t) => Some(*t),
+            LocalResult::None | LocalResult::Ambiguous(_, _) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn earliest_latest_single_test() -> anyhow::Result<()> {
+        let t1 = OffsetDateTime::from_str("2021-11-07T01:30:00-04:00")?;
+        let t2 = OffsetDateTime::from_str("2021-11-07T01:30:00-05:00")?;
+
+        let r = LocalResult::None;
+        assert_eq!(r.earliest(), None);
+        assert_eq!(r.latest(), None);
+        assert_eq!(r.single(), None);
+
+        let r = LocalResult::Single(t1);
+        assert_eq!(r.earliest(), Some(t1));
+        assert_eq!(r.latest(), Some(t1));
+        assert_eq!(r.single(), Some(t1));
+
+        let r = LocalResult::Ambiguous(t1, t2);
+        assert_eq!(r.earliest(), Some(t1));
+        assert_eq!(r.latest(), Some(t2));
+        assert_eq!(r.single(), None);
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use std::convert::TryFrom;
+
+use crate::TimeZoneOffset;
+
+// TZif local time type record (ttinfo)
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LocalTimeType {
+    utoff: i32,
+    is_dst: bool,
+    designation: String,
+}
+
+impl LocalTimeType {
+    // utoff: UT offset in seconds
+    pub(crate) fn new(utoff: i32, is_dst: bool, designation: String) -> Option<Self> {
+        let local_time_type = Self {
+            utoff,
+            is_dst,
+            designation,
+        };
+        local_time_type.offset_in_minutes()?;
+        Some(local_time_type)
+    }
+
+    // e.g. "JST", "EDT", "LMT"
+    pub fn designation(&self) -> &str {
+        &self.designation
+    }
+
+    pub fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+
+    // TimeZoneOffset has minute precision (e.g. LMT -00:44:30 -> -00:45)
+    pub fn offset(&self) -> TimeZoneOffset {
+        self.offset_in_minutes().expect("utoff is checked by new")
+    }
+
+    pub fn utoff(&self) -> i32 {
+        self.utoff
+    }
+
+    fn offset_in_minutes(&self) -> Option<TimeZoneOffset> {
+        // round half away from zero
+        let minutes = (i64::from(self.utoff).abs() + 30) / 60 * i64::from(self.utoff.signum());
+        let minutes = i16::try_from(minutes).ok()?;
+        TimeZoneOffset::from_offset_in_minutes(minutes).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_test() -> anyhow::Result<()> {
+        let f = |utoff: i32| -> anyhow::Result<TimeZoneOffset> {
+            Ok(LocalTimeType::new(utoff, false, "LMT".to_string())
+                .ok_or_else(|| anyhow::anyhow!("out of range"))?
+                .offset())
+        };
+        assert_eq!(f(0)?, TimeZoneOffset::utc());
+        assert_eq!(f(32400)?, TimeZoneOffset::from_h(9)?);
+        assert_eq!(f(-18000)?, TimeZoneOffset::from_h(-5)?);
+        assert_eq!(f(33539)?, TimeZoneOffset::from_hm(9, 19)?);
+        assert_eq!(f(-2670)?, TimeZoneOffset::from_hm(0, -45)?);
+        assert_eq!(f(-2640)?, TimeZoneOffset::from_hm(0, -44)?);
+        assert_eq!(f(86369)?, TimeZoneOffset::from_hm(23, 59)?);
+        assert!(f(86370).is_err());
+        assert!(f(-86370).is_err());
+        assert!(f(i32::MIN).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn accessors_test() {
+        let t = LocalTimeType::new(-14400, true, "EDT".to_string()).expect("valid utoff");
+        assert_eq!(t.utoff(), -14400);
+        assert!(t.is_dst());
+        assert_eq!(t.designation(), "EDT");
+    }
+}
@@ -0,0 +1,215 @@
+use std::path::{Path, PathBuf};
+
+use super::time_zone::{LoadTimeZoneError, TimeZone};
+
+// where to find the local time zone (Linux)
+//
+// - TZ is unset: the localtime file (/etc/localtime)
+// - TZ is empty: UTC
+// - TZ is ":name" or "name": zoneinfo_dir/name
+// - TZ is ":/path" or "/path": the file
+// - otherwise: POSIX TZ string (e.g. "EST5EDT,M3.2.0,M11.1.0")
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LocalTimeZoneConfig {
+    tz: Option<String>,
+    localtime: PathBuf,
+    zoneinfo_dir: PathBuf,
+}
+
+impl LocalTimeZoneConfig {
+    pub fn new(tz: Option<String>, localtime: PathBuf, zoneinfo_dir: PathBuf) -> Self {
+        Self {
+            tz,
+            localtime,
+            zoneinfo_dir,
+        }
+    }
+
+    // TZ, /etc/localtime and TZDIR (or /usr/share/zoneinfo)
+    pub fn from_env() -> Self {
+        Self::new(
+            std::env::var("TZ").ok(),
+            PathBuf::from("/etc/localtime"),
+            std::env::var_os("TZDIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from("/usr/share/zoneinfo")),
+        )
+    }
+
+    pub fn load(&self) -> Result<TimeZone, LoadTimeZoneError> {
+        match self.tz.as_deref() {
+            None => self.load_file(&self.localtime),
+            Some("") => Ok(TimeZone::utc()),
+            Some(tz) => {
+                let name = tz.strip_prefix(':').unwrap_or(tz);
+                let loaded = if name.starts_with('/') {
+                    self.load_file(Path::new(name))
+                } else {
+                    TimeZone::load(&self.zoneinfo_dir, name)
+                };
+                loaded.or_else(|e| TimeZone::from_posix_tz(tz).map_err(|_| e))
+            }
+        }
+    }
+
+    pub fn localtime(&self) -> &Path {
+        &self.localtime
+    }
+
+    pub fn tz(&self) -> Option<&str> {
+        self.tz.as_deref()
+    }
+
+    pub fn zoneinfo_dir(&self) -> &Path {
+        &self.zoneinfo_dir
+    }
+
+    fn load_file(&self, path: &Path) -> Result<TimeZone, LoadTimeZoneError> {
+        let bytes = std::fs::read(path).map_err(LoadTimeZoneError::Io)?;
+        let name = self.name_from_path(path);
+        TimeZone::from_tzif_bytes(&name, &bytes).map_err(LoadTimeZoneError::ParseTzif)
+    }
+
+    // "/etc/localtime" -> "../usr/share/zoneinfo/Asia/Tokyo" -> "Asia/Tokyo"
+    fn name_from_path(&self, path: &Path) -> String {
+        let target = std::fs::read_link(path).unwrap_or_else(|_| path.to_path_buf());
+        if let Ok(name) = target.strip_prefix(&self.zoneinfo_dir) {
+            return name.to_string_lossy().into_owned();
+        }
+        let target = target.to_string_lossy();
+        match target.rfind("/zoneinfo/") {
+            Some(index) => target[index + "/zoneinfo/".len()..].to_string(),
+            None => target.into_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tz::tzif_bytes;
+    use crate::{Instant, TimeZoneOffset};
+
+    use super::*;
+
+    // a unique directory with zoneinfo/Asia/Tokyo
+    fn fixture_dir(test_name: &str) -> anyhow::Result<PathBuf> {
+        let dir = std::env::temp_dir().join(format!(
+            "limited-date-time-{}-{}",
+            std::process::id(),
+            test_name
+        ));
+        let zoneinfo_dir = dir.join("zoneinfo");
+        std::fs::create_dir_all(zoneinfo_dir.join("Asia"))?;
+        let bytes = tzif_bytes(
+            2,
+            &[(-2_587_712_400, 1)],
+            &[(33539, false, "LMT"), (32400, false, "JST")],
+            "JST-9",
+        );
+        std::fs::write(zoneinfo_dir.join("Asia/Tokyo"), &bytes)?;
+        Ok(dir)
+    }
+
+    #[test]
+    fn load_test() -> anyhow::Result<()> {
+        let dir = fixture_dir("load")?;
+        let zoneinfo_dir = dir.join("zoneinfo");
+        let tokyo = zoneinfo_dir.join("Asia/Tokyo");
+        let f = |tz: Option<&str>, localtime: &Path| {
+            LocalTimeZoneConfig::new(
+                tz.map(str::to_string),
+                localtime.to_path_buf(),
+                zoneinfo_dir.clone(),
+            )
+            .load()
+        };
+        let jst = TimeZoneOffset::from_h(9)?;
+        let instant = Instant::from(1_612_310_400_u32);
+
+        // TZ is unset
+        let time_zone = f(None, &tokyo)?;
+        assert_eq!(time_zone.name(), "Asia/Tokyo");
+        assert_eq!(time_zone.offset_at(instant), jst);
+        // TZ is empty
+        let time_zone = f(Some(""), &tokyo)?;
+        assert_eq!(time_zone.name(), "UTC");
+        assert_eq!(time_zone.offset_at(instant), TimeZoneOffset::utc());
+        // TZ is a name
+        let missing = dir.join("missing");
+        assert_eq!(f(Some("Asia/Tokyo"), &missing)?.name(), "Asia/Tokyo");
+        assert_eq!(f(Some(":Asia/Tokyo"), &missing)?.name(), "Asia/Tokyo");
+        // TZ is a path
+        let path = tokyo.to_string_lossy().into_owned();
+        assert_eq!(f(Some(&path), &missing)?.name(), "Asia/Tokyo");
+        assert_eq!(
+            f(Some(&format!(":{}", path)), &missing)?.name(),
+            "Asia/Tokyo"
+        );
+
+        assert!(matches!(f(None, &missing), Err(LoadTimeZoneError::Io(_))));
+        assert!(matches!(
+            f(Some("Asia/Osaka"), &missing),
+            Err(LoadTimeZoneError::Io(_))
+        ));
+        assert!(matches!(
+            f(Some("../zoneinfo/Asia/Tokyo"), &missing),
+            Err(LoadTimeZoneError::InvalidName)
+        ));
+        // TZ is a POSIX TZ string
+        let time_zone = f(Some("JST-9"), &missing)?;
+        assert_eq!(time_zone.name(), "JST-9");
+        assert_eq!(time_zone.offset_at(instant), jst);
+        let time_zone = f(Some("EST5EDT,M3.2.0,M11.1.0"), &missing)?;
+        assert_eq!(time_zone.offset_at(instant), TimeZoneOffset::from_h(-5)?);
+        std::fs::write(dir.join("broken"), b"TZif")?;
+        assert!(matches!(
+            f(None, &dir.join("broken")),
+            Err(LoadTimeZoneError::ParseTzif(_))
+        ));
+
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn load_symlink_test() -> anyhow::Result<()> {
+        let dir = fixture_dir("load_symlink")?;
+        let localtime = dir.join("localtime");
+        std::os::unix::fs::symlink(dir.join("zoneinfo/Asia/Tokyo"), &localtime)?;
+        let config = LocalTimeZoneConfig::new(None, localtime.clone(), dir.join("zoneinfo"));
+        assert_eq!(config.load()?.name(), "Asia/Tokyo");
+
+        // a copy (not a symlink) has no name
+        let copy = dir.join("copy");
+        std::fs::copy(&localtime, &copy)?;
+        let config = LocalTimeZoneConfig::new(None, copy.clone(), dir.join("zoneinfo"));
+        assert_eq!(config.load()?.name(), copy.to_string_lossy());
+
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn accessors_test() {
+        let config = LocalTimeZoneConfig::new(
+            Some("Asia/Tokyo".to_string()),
+            PathBuf::from("/etc/localtime"),
+            PathBuf::from("/usr/share/zoneinfo"),
+        );
+        assert_eq!(config.tz(), Some("Asia/Tokyo"));
+        assert_eq!(config.localtime(), Path::new("/etc/localtime"));
+        assert_eq!(config.zoneinfo_dir(), Path::new("/usr/share/zoneinfo"));
+    }
+
+    #[test]
+    fn zoneinfo_fixture_test() -> anyhow::Result<()> {
+        // tests/zoneinfo (for the local time zone tests) has the same Asia/Tokyo
+        let dir = fixture_dir("zoneinfo_fixture")?;
+        let expected = std::fs::read(dir.join("zoneinfo/Asia/Tokyo"))?;
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/zoneinfo/Asia/Tokyo");
+        assert_eq!(std::fs::read(path)?, expected);
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use thiserror::Error;
+
+use crate::{Instant, TimeZoneOffset};
+
+use super::offset_rule::OffsetRule;
+use super::transition::Transition;
+
+// a fixed list of offset changes (e.g. supplied by the caller)
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OffsetHistory {
+    initial_offset: TimeZoneOffset,
+    changes: Vec<(Instant, TimeZoneOffset)>,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+#[error("invalid offset history error")]
+pub struct InvalidOffsetHistoryError;
+
+impl OffsetHistory {
+    // changes: (the first instant with the offset, the offset) in ascending order of instant
+    pub fn new(
+        initial_offset: TimeZoneOffset,
+        changes: Vec<(Instant, TimeZoneOffset)>,
+    ) -> Result<Self, InvalidOffsetHistoryError> {
+        if changes.windows(2).any(|w| w[0].0 >= w[1].0) {
+            return Err(InvalidOffsetHistoryError);
+        }
+        Ok(Self {
+            initial_offset,
+            changes,
+        })
+    }
+
+    pub fn changes(&self) -> &[(Instant, TimeZoneOffset)] {
+        &self.changes
+    }
+
+    // the offset before the first change
+    pub fn initial_offset(&self) -> TimeZoneOffset {
+        self.initial_offset
+    }
+}
+
+impl OffsetRule for OffsetHistory {
+    fn offset_at(&self, instant: Instant) -> TimeZoneOffset {
+        match self.changes.partition_point(|(t, _)| *t <= instant) {
+            0 => self.initial_offset,
+            index => self.changes[index - 1].1,
+        }
+    }
+
+    // changes to the same offset are skipped
+    fn next_transition(&self, instant: Instant) -> Option<Transition> {
+        let index = self.changes.partition_point(|(t, _)| *t <= instant);
+        let mut before = self.offset_at(instant);
+        for (t, offset) in &self.changes[index..] {
+            if *offset != before {
+                return Some(Transition::new(*t, before, *offset));
+            }
+            before = *offset;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::super::transition::transition_string;
+    use super::*;
+
+    fn history() -> anyhow::Result<OffsetHistory> {
+        Ok(OffsetHistory::new(
+            TimeZoneOffset::from_h(-5)?,
+            vec![
+                (
+                    Instant::from_str("2021-03-14T07:00:00Z")?,
+                    TimeZoneOffset::from_h(-4)?,
+                ),
+                (
+                    Instant::from_str("2021-06-01T00:00:00Z")?,
+                    TimeZoneOffset::from_h(-4)?,
+                ),
+                (
+                    Instant::from_str("2021-11-07T06:00:00Z")?,
+                    TimeZoneOffset::from_h(-5)?,
+                ),
+            ],
+        )?)
+    }
+
+    #[test]
+    fn new_test() -> anyhow::Result<()> {
+        let offset = TimeZoneOffset::utc();
+        let instant = Instant::from_str("2021-01-01T00:00:00Z")?;
+        assert!(OffsetHistory::new(offset, vec![]).is_ok());
+        assert_eq!(
+            OffsetHistory::new(offset, vec![(instant, offset), (instant, offset)]),
+            Err(InvalidOffsetHistoryError)
+        );
+        assert_eq!(
+            OffsetHistory::new(offset, vec![(instant, offset), (Instant::min(), offset)]),
+            Err(InvalidOffsetHistoryError)
+        );
+
+        let history = history()?;
+        assert_eq!(history.initial_offset(), TimeZoneOffset::from_h(-5)?);
+        assert_eq!(history.changes().len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn offset_at_test() -> anyhow::Result<()> {
+        let history = history()?;
+        let f = |s: &str| -> anyhow::Result<String> {
+            Ok(history.offset_at(Instant::from_str(s)?).to_string())
+        };
+        assert_eq!(f("1970-01-01T00:00:00Z")?, "-05:00");
+        assert_eq!(f("2021-03-14T06:59:59Z")?, "-05:00");
+        assert_eq!(f("2021-03-14T07:00:00Z")?, "-04:00");
+        assert_eq!(f("2021-11-07T05:59:59Z")?, "-04:00");
+        assert_eq!(f("2021-11-07T06:00:00Z")?, "-05:00");
+        assert_eq!(f("9999-12-31T23:59:59Z")?, "-05:00");
+        Ok(())
+    }
+
+    #[test]
+    fn next_transition_test() -> anyhow::Result<()> {
+        let history = history()?;
+        let f = |s: &str| -> anyhow::Result<Option<String>> {
+            Ok(history
+                .next_transition(Instant::from_str(s)?)
+                .as_ref()
+                .map(transition_string))
+        };
+        assert_eq!(
+            f("1970-01-01T00:00:00Z")?,
+            Some("2021-03-14T07:00:00Z -05:00 -04:00".to_string())
+        );
+        // the change to the same offset is skipped
+        assert_eq!(
+            f("2021-03-14T07:00:00Z")?,
+            Some("2021-11-07T06:00:00Z -04:00 -05:00".to_string())
+        );
+        assert_eq!(f("2021-11-07T06:00:00Z")?, None);
+        Ok(())
+    }
+}
@@ -0,0 +1,307 @@
+use std::convert::TryFrom;
+use thiserror::Error;
+
+use crate::private::days_from_unix_epoch_from_date;
+use crate::{DateTime, Instant, OffsetDateTime, TimeZoneOffset, YearMonth};
+
+use super::local_result::LocalResult;
+use super::transition::Transition;
+
+// the offset in effect at an instant
+pub trait OffsetRule {
+    fn offset_at(&self, instant: Instant) -> TimeZoneOffset;
+
+    // the first offset change after the instant
+    fn next_transition(&self, instant: Instant) -> Option<Transition>;
+
+    // the offset changes in the month (UTC)
+    fn transitions_in(&self, year_month: YearMonth) -> Vec<Transition> {
+        let first_timestamp = |year_month: YearMonth| {
+            let (year, month) = (year_month.year(), year_month.month());
+            days_from_unix_epoch_from_date((i64::from(year), i64::from(month), 1)) * 86400
+        };
+        let start = first_timestamp(year_month);
+        let end = year_month
+            .succ()
+            .map_or(i64::from(Instant::max()) + 1, first_timestamp);
+        let mut transitions = vec![];
+        let mut instant = Instant::try_from(start - 1).unwrap_or_else(|_| Instant::min());
+        while let Some(transition) = self.next_transition(instant) {
+            if i64::from(transition.instant()) >= end {
+                break;
+            }
+            instant = transition.instant();
+            transitions.push(transition);
+        }
+        transitions
+    }
+
+    fn resolve(&self, date_time: DateTime) -> LocalResult {
+        let (before, after) = offsets_around(self, date_time);
+        let single = |offset: TimeZoneOffset| -> Option<OffsetDateTime> {
+            // check range
+            Instant::try_from(timestamp_with_offset(date_time, offset)).ok()?;
+            let offset_date_time = OffsetDateTime::new(date_time, offset);
+            if self.offset_at(Instant::from(offset_date_time)) != offset {
+                return None;
+            }
+            Some(offset_date_time)
+        };
+        if before == after {
+            return single(before).map_or(LocalResult::None, LocalResult::Single);
+        }
+        match (single(before), single(after)) {
+            (None, None) => LocalResult::None,
+            (Some(t), None) | (None, Some(t)) => LocalResult::Single(t),
+            (Some(t1), Some(t2)) => {
+                if t1.instant() <= t2.instant() {
+                    LocalResult::Ambiguous(t1, t2)
+                } else {
+                    LocalResult::Ambiguous(t2, t1)
+                }
+            }
+        }
+    }
+
+    fn resolve_with_policy(
+        &self,
+        date_time: DateTime,
+        policy: DisambiguationPolicy,
+    ) -> Result<OffsetDateTime, ResolveLocalDateTimeError> {
+        match self.resolve(date_time) {
+            LocalResult::Single(t) => Ok(t),
+            LocalResult::Ambiguous(earlier, later) => match policy {
+                DisambiguationPolicy::Earliest | DisambiguationPolicy::ShiftForward => Ok(earlier),
+                DisambiguationPolicy::Latest => Ok(later),
+                DisambiguationPolicy::Reject => Err(ResolveLocalDateTimeError::Ambiguous),
+            },
+            LocalResult::None => {
+                let (before, after) = offsets_around(self, date_time);
+                if before == after {
+                    return Err(ResolveLocalDateTimeError::OutOfRange);
+                }
+                let offset = match policy {
+                    // 02:30 -> 01:30 (the offset after the gap)
+                    DisambiguationPolicy::Earliest => after,
+                    // 02:30 -> 03:30 (the offset before the gap)
+                    DisambiguationPolicy::Latest | DisambiguationPolicy::ShiftForward => before,
+                    DisambiguationPolicy::Reject => {
+                        return Err(ResolveLocalDateTimeError::Nonexistent)
+                    }
+                };
+                let instant = Instant::try_from(timestamp_with_offset(date_time, offset))
+                    .map_err(|_| ResolveLocalDateTimeError::OutOfRange)?;
+                OffsetDateTime::from_instant(instant, self.offset_at(instant))
+                    .map(|t| t.with_nanosecond(date_time.time().nanosecond()))
+                    .map_err(|_| ResolveLocalDateTimeError::OutOfRange)
+            }
+        }
+    }
+}
+
+// how to resolve a skipped or repeated local date time
+//
+// | policy       | repeated | skipped (e.g. 02:30) |
+// |--------------|----------|----------------------|
+// | Earliest     | earlier  | 01:30                |
+// | Latest       | later    | 03:30                |
+// | ShiftForward | earlier  | 03:30                |
+// | Reject       | error    | error                |
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DisambiguationPolicy {
+    Earliest,
+    Latest,
+    ShiftForward,
+    Reject,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ResolveLocalDateTimeError {
+    #[error("ambiguous")]
+    Ambiguous,
+    #[error("nonexistent")]
+    Nonexistent,
+    #[error("out of range")]
+    OutOfRange,
+}
+
+impl OffsetRule for TimeZoneOffset {
+    fn offset_at(&self, _: Instant) -> TimeZoneOffset {
+        *self
+    }
+
+    fn next_transition(&self, _: Instant) -> Option<Transition> {
+        None
+    }
+}
+
+// assumes that offset changes are at least a day apart
+fn offsets_around<R: OffsetRule + ?Sized>(
+    rule: &R,
+    date_time: DateTime,
+) -> (TimeZoneOffset, TimeZoneOffset) {
+    let timestamp = date_time.seconds_from_unix_epoch();
+    let offset_at = |timestamp: i64| {
+        let timestamp = timestamp.clamp(i64::from(Instant::min()), i64::from(Instant::max()));
+        rule.offset_at(Instant::try_from(timestamp).expect("timestamp is clamped"))
+    };
+    (offset_at(timestamp - 86400), offset_at(timestamp + 86400))
+}
+
+fn timestamp_with_offset(date_time: DateTime, offset: TimeZoneOffset) -> i64 {
+    date_time.seconds_from_unix_epoch() - i64::from(offset.offset_in_minutes()) * 60
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::super::dst_rule::new_york_rule;
+    use super::super::transition::transition_string;
+    use super::*;
+
+    #[test]
+    fn resolve_test() -> anyhow::Result<()> {
+        let rule = new_york_rule()?;
+        let f =
+            |s: &str| -> anyhow::Result<LocalResult> { Ok(rule.resolve(DateTime::from_str(s)?)) };
+        let odt = |s: &str| OffsetDateTime::from_str(s);
+
+        assert_eq!(
+            f("2021-01-01T00:00:00")?,
+            LocalResult::Single(odt("2021-01-01T00:00:00-05:00")?)
+        );
+        // spring forward
+        assert_eq!(
+            f("2021-03-14T01:59:59")?,
+            LocalResult::Single(odt("2021-03-14T01:59:59-05:00")?)
+        );
+        assert_eq!(f("2021-03-14T02:00:00")?, LocalResult::None);
+        assert_eq!(f("2021-03-14T02:59:59")?, LocalResult::None);
+        assert_eq!(
+            f("2021-03-14T03:00:00")?,
+            LocalResult::Single(odt("2021-03-14T03:00:00-04:00")?)
+        );
+        // fall back
+        assert_eq!(
+            f("2021-11-07T00:59:59")?,
+            LocalResult::Single(odt("2021-11-07T00:59:59-04:00")?)
+        );
+        assert_eq!(
+            f("2021-11-07T01:00:00")?,
+            LocalResult::Ambiguous(
+                odt("2021-11-07T01:00:00-04:00")?,
+                odt("2021-11-07T01:00:00-05:00")?
+            )
+        );
+        assert_eq!(
+            f("2021-11-07T01:59:59")?,
+            LocalResult::Ambiguous(
+                odt("2021-11-07T01:59:59-04:00")?,
+                odt("2021-11-07T01:59:59-05:00")?
+            )
+        );
+        assert_eq!(
+            f("2021-11-07T02:00:00")?,
+            LocalResult::Single(odt("2021-11-07T02:00:00-05:00")?)
+        );
+        // out of range
+        assert_eq!(
+            f("1970-01-01T00:00:00")?,
+            LocalResult::Single(odt("1970-01-01T00:00:00-05:00")?)
+        );
+        assert_eq!(f("9999-12-31T23:59:59")?, LocalResult::None);
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_with_policy_test() -> anyhow::Result<()> {
+        type E = ResolveLocalDateTimeError;
+        type P = DisambiguationPolicy;
+        let rule = new_york_rule()?;
+        let f = |s: &str, policy: P| -> anyhow::Result<Result<String, E>> {
+            Ok(rule
+                .resolve_with_policy(DateTime::from_str(s)?, policy)
+                .map(|t| t.to_string()))
+        };
+
+        let s = "2021-07-01T12:00:00";
+        for policy in &[P::Earliest, P::Latest, P::ShiftForward, P::Reject] {
+            assert_eq!(f(s, *policy)?, Ok("2021-07-01T12:00:00-04:00".to_string()));
+        }
+
+        // skipped
+        let s = "2021-03-14T02:30:00";
+        assert_eq!(
+            f(s, P::Earliest)?,
+            Ok("2021-03-14T01:30:00-05:00".to_string())
+        );
+        assert_eq!(
+            f(s, P::Latest)?,
+            Ok("2021-03-14T03:30:00-04:00".to_string())
+        );
+        assert_eq!(
+            f(s, P::ShiftForward)?,
+            Ok("2021-03-14T03:30:00-04:00".to_string())
+        );
+        assert_eq!(f(s, P::Reject)?, Err(E::Nonexistent));
+
+        // repeated
+        let s = "2021-11-07T01:30:00";
+        assert_eq!(
+            f(s, P::Earliest)?,
+            Ok("2021-11-07T01:30:00-04:00".to_string())
+        );
+        assert_eq!(
+            f(s, P::Latest)?,
+            Ok("2021-11-07T01:30:00-05:00".to_string())
+        );
+        assert_eq!(
+            f(s, P::ShiftForward)?,
+            Ok("2021-11-07T01:30:00-04:00".to_string())
+        );
+        assert_eq!(f(s, P::Reject)?, Err(E::Ambiguous));
+
+        // out of range
+        assert_eq!(f("9999-12-31T23:59:59", P::Latest)?, Err(E::OutOfRange));
+        Ok(())
+    }
+
+    #[test]
+    fn transitions_in_test() -> anyhow::Result<()> {
+        let rule = new_york_rule()?;
+        let f = |s: &str| -> anyhow::Result<Vec<String>> {
+            Ok(rule
+                .transitions_in(YearMonth::from_str(s)?)
+                .iter()
+                .map(transition_string)
+                .collect())
+        };
+        assert_eq!(f("2021-03")?, vec!["2021-03-14T07:00:00Z -05:00 -04:00"]);
+        assert_eq!(f("2021-11")?, vec!["2021-11-07T06:00:00Z -04:00 -05:00"]);
+        assert_eq!(f("2021-07")?, Vec::<String>::new());
+        assert_eq!(f("1970-01")?, Vec::<String>::new());
+        assert_eq!(f("9999-12")?, Vec::<String>::new());
+        Ok(())
+    }
+
+    #[test]
+    fn time_zone_offset_test() -> anyhow::Result<()> {
+        let offset = TimeZoneOffset::from_h(9)?;
+        assert_eq!(offset.offset_at(Instant::min()), offset);
+        assert_eq!(offset.next_transition(Instant::min()), None);
+        assert_eq!(
+            offset.transitions_in(YearMonth::from_str("2021-03")?),
+            vec![]
+        );
+        assert_eq!(
+            offset.resolve(DateTime::from_str("2021-02-03T04:05:06")?),
+            LocalResult::Single(OffsetDateTime::from_str("2021-02-03T04:05:06+09:00")?)
+        );
+        assert_eq!(
+            offset.resolve(DateTime::from_str("1970-01-01T08:59:59")?),
+            LocalResult::None
+        );
+        Ok(())
+    }
+}
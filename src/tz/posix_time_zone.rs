@@ -0,0 +1,483 @@
+use std::convert::TryFrom;
+use thiserror::Error;
+
+use crate::{DayOfWeek, Instant, Month, SignedSeconds, TimeZoneOffset, Year};
+
+use super::dst_rule::DstRule;
+use super::local_time_type::LocalTimeType;
+use super::offset_rule::OffsetRule;
+use super::transition::Transition;
+use super::transition_rule::{TransitionDate, TransitionRule};
+
+// POSIX TZ string (e.g. "EST5EDT,M3.2.0,M11.1.0")
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PosixTimeZone {
+    std: LocalTimeType,
+    dst: Option<(LocalTimeType, DstRule)>,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParsePosixTimeZoneError {
+    #[error("invalid format")]
+    InvalidFormat,
+    #[error("invalid name")]
+    InvalidName,
+    #[error("invalid offset")]
+    InvalidOffset,
+    #[error("invalid transition rule")]
+    InvalidTransitionRule,
+}
+
+impl PosixTimeZone {
+    pub fn dst(&self) -> Option<&LocalTimeType> {
+        self.dst.as_ref().map(|(dst, _)| dst)
+    }
+
+    pub fn dst_rule(&self) -> Option<&DstRule> {
+        self.dst.as_ref().map(|(_, rule)| rule)
+    }
+
+    pub fn local_time_type_at(&self, instant: Instant) -> &LocalTimeType {
+        self.local_time_type_at_timestamp(i64::from(instant))
+    }
+
+    pub fn std(&self) -> &LocalTimeType {
+        &self.std
+    }
+
+    // the DST transitions for the year (sorted by instant)
+    pub fn transitions(&self, year: Year) -> Vec<Transition> {
+        self.dst_rule()
+            .map(|rule| rule.transitions(year))
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn local_time_type_at_timestamp(&self, timestamp: i64) -> &LocalTimeType {
+        match &self.dst {
+            Some((dst, rule)) if rule.is_dst_at_timestamp(timestamp) => dst,
+            _ => &self.std,
+        }
+    }
+}
+
+impl OffsetRule for PosixTimeZone {
+    fn offset_at(&self, instant: Instant) -> TimeZoneOffset {
+        self.local_time_type_at(instant).offset()
+    }
+
+    fn next_transition(&self, instant: Instant) -> Option<Transition> {
+        self.dst_rule()?.next_transition(instant)
+    }
+}
+
+impl std::fmt::Display for PosixTimeZone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_name(f, self.std.designation())?;
+        write_hms(f, -i64::from(self.std.utoff()))?;
+        if let Some((dst, rule)) = &self.dst {
+            write_name(f, dst.designation())?;
+            if dst.utoff() != self.std.utoff() + 3600 {
+                write_hms(f, -i64::from(dst.utoff()))?;
+            }
+            for transition_rule in &[rule.start(), rule.end()] {
+                write!(f, ",")?;
+                match transition_rule.date() {
+                    TransitionDate::Julian(day) => write!(f, "J{}", day)?,
+                    TransitionDate::ZeroBasedJulian(day) => write!(f, "{}", day)?,
+                    TransitionDate::MonthWeekDay(month, week, day_of_week) => write!(
+                        f,
+                        "M{}.{}.{}",
+                        u8::from(month),
+                        week,
+                        u8::from(day_of_week) % 7
+                    )?,
+                }
+                let time = i64::from(transition_rule.time());
+                if time != DEFAULT_TIME {
+                    write!(f, "/")?;
+                    write_hms(f, time)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for PosixTimeZone {
+    type Err = ParsePosixTimeZoneError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let std_name = parser.name()?;
+        let std_utoff = -parser.hms(24).ok_or(Self::Err::InvalidOffset)?;
+        let std = local_time_type(std_utoff, false, std_name)?;
+        if parser.is_empty() {
+            return Ok(Self { std, dst: None });
+        }
+
+        let dst_name = parser.name()?;
+        let dst_utoff = if parser.is_empty() || parser.peek() == Some(b',') {
+            std_utoff + 3600
+        } else {
+            -parser.hms(24).ok_or(Self::Err::InvalidOffset)?
+        };
+        let dst = local_time_type(dst_utoff, true, dst_name)?;
+        let (start, end) = if parser.is_empty() {
+            default_rules()
+        } else {
+            let start = parser.rule()?;
+            let end = parser.rule()?;
+            (start, end)
+        };
+        if !parser.is_empty() {
+            return Err(Self::Err::InvalidFormat);
+        }
+        let rule = DstRule::new(std.offset(), dst.offset(), start, end);
+        Ok(Self {
+            std,
+            dst: Some((dst, rule)),
+        })
+    }
+}
+
+// 02:00:00
+const DEFAULT_TIME: i64 = 7200;
+
+// M3.2.0,M11.1.0 (the same as glibc posixrules)
+fn default_rules() -> (TransitionRule, TransitionRule) {
+    let rule = |month: Month, week: u8| {
+        TransitionRule::new(
+            month,
+            week,
+            DayOfWeek::sunday(),
+            SignedSeconds::from(DEFAULT_TIME),
+        )
+        .expect("valid rule")
+    };
+    (rule(Month::march(), 2), rule(Month::november(), 1))
+}
+
+fn local_time_type(
+    utoff: i64,
+    is_dst: bool,
+    designation: String,
+) -> Result<LocalTimeType, ParsePosixTimeZoneError> {
+    let utoff = i32::try_from(utoff).map_err(|_| ParsePosixTimeZoneError::InvalidOffset)?;
+    LocalTimeType::new(utoff, is_dst, designation).ok_or(ParsePosixTimeZoneError::InvalidOffset)
+}
+
+fn write_hms(f: &mut std::fmt::Formatter<'_>, seconds: i64) -> std::fmt::Result {
+    if seconds < 0 {
+        write!(f, "-")?;
+    }
+    let seconds = seconds.abs();
+    let (h, m, s) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    write!(f, "{}", h)?;
+    if m != 0 || s != 0 {
+        write!(f, ":{:02}", m)?;
+    }
+    if s != 0 {
+        write!(f, ":{:02}", s)?;
+    }
+    Ok(())
+}
+
+fn write_name(f: &mut std::fmt::Formatter<'_>, name: &str) -> std::fmt::Result {
+    if name.bytes().all(|b| b.is_ascii_alphabetic()) {
+        write!(f, "{}", name)
+    } else {
+        write!(f, "<{}>", name)
+    }
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(s: &'a str) -> Self {
+        Self {
+            bytes: s.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn take_while<F: Fn(u8) -> bool>(&mut self, f: F) -> &'a [u8] {
+        let start = self.pos;
+        while self.peek().is_some_and(&f) {
+            self.pos += 1;
+        }
+        &self.bytes[start..self.pos]
+    }
+
+    fn expect(&mut self, b: u8) -> bool {
+        if self.peek() == Some(b) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    // "EST" or "<+09>"
+    fn name(&mut self) -> Result<String, ParsePosixTimeZoneError> {
+        let quoted = self.expect(b'<');
+        let name = if quoted {
+            self.take_while(|b| b.is_ascii_alphanumeric() || b == b'+' || b == b'-')
+        } else {
+            self.take_while(|b| b.is_ascii_alphabetic())
+        };
+        if (quoted && !self.expect(b'>')) || name.len() < 3 {
+            return Err(ParsePosixTimeZoneError::InvalidName);
+        }
+        Ok(String::from_utf8(name.to_vec()).expect("name is ascii"))
+    }
+
+    fn number(&mut self, max_len: usize) -> Option<i64> {
+        let digits = self.take_while(|b| b.is_ascii_digit());
+        if digits.is_empty() || digits.len() > max_len {
+            return None;
+        }
+        Some(
+            digits
+                .iter()
+                .fold(0_i64, |acc, b| acc * 10 + i64::from(b - b'0')),
+        )
+    }
+
+    // [+-]hh[:mm[:ss]] in seconds
+    fn hms(&mut self, max_hours: i64) -> Option<i64> {
+        let sign = if self.expect(b'-') {
+            -1
+        } else {
+            self.expect(b'+');
+            1
+        };
+        let h = self.number(3).filter(|h| *h <= max_hours)?;
+        let mut m = 0;
+        let mut s = 0;
+        if self.expect(b':') {
+            m = self.number(2).filter(|m| *m <= 59)?;
+            if self.expect(b':') {
+                s = self.number(2).filter(|s| *s <= 59)?;
+            }
+        }
+        Some(sign * (h * 3600 + m * 60 + s))
+    }
+
+    // ",Mm.w.d[/time]", ",Jn[/time]" or ",n[/time]"
+    fn rule(&mut self) -> Result<TransitionRule, ParsePosixTimeZoneError> {
+        type E = ParsePosixTimeZoneError;
+        if !self.expect(b',') {
+            return Err(E::InvalidFormat);
+        }
+        let date = self.date().ok_or(E::InvalidTransitionRule)?;
+        let time = if self.expect(b'/') {
+            self.hms(167).ok_or(E::InvalidTransitionRule)?
+        } else {
+            DEFAULT_TIME
+        };
+        let time = SignedSeconds::from(time);
+        match date {
+            TransitionDate::Julian(day) => TransitionRule::julian(day, time),
+            TransitionDate::ZeroBasedJulian(day) => TransitionRule::zero_based_julian(day, time),
+            TransitionDate::MonthWeekDay(month, week, day_of_week) => {
+                TransitionRule::new(month, week, day_of_week, time)
+            }
+        }
+        .map_err(|_| E::InvalidTransitionRule)
+    }
+
+    // the range is checked by TransitionRule
+    fn date(&mut self) -> Option<TransitionDate> {
+        if self.expect(b'M') {
+            let month = self.number(2)?;
+            let week = self.expect(b'.').then(|| self.number(1)).flatten()?;
+            let day_of_week = self.expect(b'.').then(|| self.number(1)).flatten()?;
+            // d: [0, 6] (0: Sunday)
+            if day_of_week > 6 {
+                return None;
+            }
+            Some(TransitionDate::MonthWeekDay(
+                Month::try_from(month).ok()?,
+                u8::try_from(week).ok()?,
+                DayOfWeek::try_from(if day_of_week == 0 { 7 } else { day_of_week }).ok()?,
+            ))
+        } else if self.expect(b'J') {
+            Some(TransitionDate::Julian(u16::try_from(self.number(3)?).ok()?))
+        } else {
+            Some(TransitionDate::ZeroBasedJulian(
+                u16::try_from(self.number(3)?).ok()?,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::super::transition::transition_string;
+    use super::*;
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        let f = |s: &str| -> anyhow::Result<String> { Ok(PosixTimeZone::from_str(s)?.to_string()) };
+        for s in &[
+            "UTC0",
+            "JST-9",
+            "<+0545>-5:45",
+            "<-0330>3:30",
+            "<+1245>-12:45<+1345>,M9.5.0/2:45,M4.1.0/3:45",
+            "EST5EDT,M3.2.0,M11.1.0",
+            "CST6CDT,M3.2.0,M11.1.0",
+            "AEST-10AEDT,M10.1.0,M4.1.0/3",
+            "CET-1CEST,M3.5.0,M10.5.0/3",
+            "IST-1GMT0,M10.5.0,M3.5.0/1",
+            "<-02>2<-01>,M3.5.0/-1,M10.5.0/0",
+            "EST5EDT,0/0,J365/25",
+            "<+10>-10<+11>,J60/2:30:15,300/-167",
+        ] {
+            assert_eq!(f(s)?, *s);
+        }
+        // canonical format
+        assert_eq!(f("EST+5EDT")?, "EST5EDT,M3.2.0,M11.1.0");
+        assert_eq!(
+            f("EST05:00EDT04,M3.2.0/02:00:00,M11.1.0/2")?,
+            "EST5EDT,M3.2.0,M11.1.0"
+        );
+        assert_eq!(f("<JST>-9")?, "JST-9");
+        assert_eq!(f("EST5EDT4,0/0,J365/25")?, "EST5EDT,0/0,J365/25");
+        Ok(())
+    }
+
+    #[test]
+    fn str_conversion_error_test() {
+        type E = ParsePosixTimeZoneError;
+        let f = |s: &str| PosixTimeZone::from_str(s);
+        assert_eq!(f(""), Err(E::InvalidName));
+        assert_eq!(f("ES5"), Err(E::InvalidName));
+        assert_eq!(f("<+09-9"), Err(E::InvalidName));
+        assert_eq!(f("<+0>-9"), Err(E::InvalidName));
+        assert_eq!(f("EST"), Err(E::InvalidOffset));
+        assert_eq!(f("EST25"), Err(E::InvalidOffset));
+        assert_eq!(f("EST5:60"), Err(E::InvalidOffset));
+        assert_eq!(f("EST24"), Err(E::InvalidOffset));
+        assert_eq!(f("EST5ED"), Err(E::InvalidName));
+        assert_eq!(f("EST5EDT,M3.2.0"), Err(E::InvalidFormat));
+        assert_eq!(f("EST5EDT,M3.2.0,M11.1.0,"), Err(E::InvalidFormat));
+        assert_eq!(f("EST5EDT;M3.2.0,M11.1.0"), Err(E::InvalidOffset));
+        assert_eq!(f("EST5x"), Err(E::InvalidName));
+        assert_eq!(f("EST5EDT,M13.2.0,M11.1.0"), Err(E::InvalidTransitionRule));
+        assert_eq!(f("EST5EDT,M3.6.0,M11.1.0"), Err(E::InvalidTransitionRule));
+        assert_eq!(f("EST5EDT,M3.2.7,M11.1.0"), Err(E::InvalidTransitionRule));
+        assert_eq!(f("EST5EDT,M3.2,M11.1.0"), Err(E::InvalidTransitionRule));
+        assert_eq!(f("EST5EDT,J0,J365"), Err(E::InvalidTransitionRule));
+        assert_eq!(f("EST5EDT,0,366"), Err(E::InvalidTransitionRule));
+        assert_eq!(f("EST5EDT,0/168,365"), Err(E::InvalidTransitionRule));
+    }
+
+    #[test]
+    fn accessors_test() -> anyhow::Result<()> {
+        let tz = PosixTimeZone::from_str("EST5EDT,M3.2.0,M11.1.0")?;
+        assert_eq!(tz.std().designation(), "EST");
+        assert_eq!(tz.std().utoff(), -18000);
+        assert!(!tz.std().is_dst());
+        let dst = tz.dst().ok_or_else(|| anyhow::anyhow!("no dst"))?;
+        assert_eq!(dst.designation(), "EDT");
+        assert_eq!(dst.utoff(), -14400);
+        assert!(dst.is_dst());
+        let rule = tz.dst_rule().ok_or_else(|| anyhow::anyhow!("no dst"))?;
+        assert_eq!(rule.std_offset(), TimeZoneOffset::from_h(-5)?);
+        assert_eq!(rule.dst_offset(), TimeZoneOffset::from_h(-4)?);
+
+        let tz = PosixTimeZone::from_str("JST-9")?;
+        assert_eq!(tz.dst(), None);
+        assert_eq!(tz.dst_rule(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn offset_at_test() -> anyhow::Result<()> {
+        let f = |s: &str, instant: &str| -> anyhow::Result<String> {
+            let tz = PosixTimeZone::from_str(s)?;
+            let instant = Instant::from_str(instant)?;
+            Ok(format!(
+                "{} {}",
+                tz.offset_at(instant),
+                tz.local_time_type_at(instant).designation()
+            ))
+        };
+        let ny = "EST5EDT,M3.2.0,M11.1.0";
+        assert_eq!(f(ny, "2021-03-14T06:59:59Z")?, "-05:00 EST");
+        assert_eq!(f(ny, "2021-03-14T07:00:00Z")?, "-04:00 EDT");
+        assert_eq!(f(ny, "2021-11-07T05:59:59Z")?, "-04:00 EDT");
+        assert_eq!(f(ny, "2021-11-07T06:00:00Z")?, "-05:00 EST");
+        // the default rules
+        assert_eq!(f("EST5EDT", "2021-03-14T07:00:00Z")?, "-04:00 EDT");
+        assert_eq!(f("JST-9", "2021-07-01T00:00:00Z")?, "+09:00 JST");
+        // negative DST (Europe/Dublin)
+        let dublin = "IST-1GMT0,M10.5.0,M3.5.0/1";
+        assert_eq!(f(dublin, "2021-01-01T00:00:00Z")?, "+00:00 GMT");
+        assert_eq!(f(dublin, "2021-07-01T00:00:00Z")?, "+01:00 IST");
+        // permanent DST
+        let permanent = "EST5EDT4,0/0,J365/25";
+        assert_eq!(f(permanent, "2021-01-01T00:00:00Z")?, "-04:00 EDT");
+        assert_eq!(f(permanent, "2021-12-31T23:59:59Z")?, "-04:00 EDT");
+        Ok(())
+    }
+
+    #[test]
+    fn next_transition_test() -> anyhow::Result<()> {
+        let f = |s: &str, instant: &str| -> anyhow::Result<Option<String>> {
+            Ok(PosixTimeZone::from_str(s)?
+                .next_transition(Instant::from_str(instant)?)
+                .as_ref()
+                .map(transition_string))
+        };
+        assert_eq!(
+            f("CET-1CEST,M3.5.0,M10.5.0/3", "2021-07-01T00:00:00Z")?,
+            Some("2021-10-31T01:00:00Z +02:00 +01:00".to_string())
+        );
+        assert_eq!(f("JST-9", "2021-07-01T00:00:00Z")?, None);
+        assert_eq!(f("EST5EDT4,0/0,J365/25", "2021-07-01T00:00:00Z")?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn transitions_test() -> anyhow::Result<()> {
+        let f = |s: &str, year: &str| -> anyhow::Result<Vec<String>> {
+            Ok(PosixTimeZone::from_str(s)?
+                .transitions(Year::from_str(year)?)
+                .iter()
+                .map(transition_string)
+                .collect())
+        };
+        assert_eq!(
+            f("EST5EDT,M3.2.0,M11.1.0", "2021")?,
+            vec![
+                "2021-03-14T07:00:00Z -05:00 -04:00",
+                "2021-11-07T06:00:00Z -04:00 -05:00"
+            ]
+        );
+        assert_eq!(
+            f("CET-1CEST,M3.5.0,M10.5.0/3", "2021")?,
+            vec![
+                "2021-03-28T01:00:00Z +01:00 +02:00",
+                "2021-10-31T01:00:00Z +02:00 +01:00"
+            ]
+        );
+        assert_eq!(f("JST-9", "2021")?, Vec::<String>::new());
+        assert_eq!(f("EST5EDT4,0/0,J365/25", "2021")?, Vec::<String>::new());
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+use std::convert::TryFrom;
+use std::path::Path;
+use thiserror::Error;
+
+use crate::{Instant, TimeZoneOffset};
+
+use super::local_time_type::LocalTimeType;
+use super::local_time_zone_config::LocalTimeZoneConfig;
+use super::offset_rule::OffsetRule;
+use super::posix_time_zone::ParsePosixTimeZoneError;
+use super::transition::Transition;
+use super::tzdata::{CompileTzdataError, Tzdata};
+use super::tzif::{ParseTzifError, Tzif};
+
+// region-based time zone (e.g. "Asia/Tokyo")
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimeZone {
+    name: String,
+    tzif: Tzif,
+}
+
+#[derive(Debug, Error)]
+pub enum LoadTimeZoneError {
+    #[error("invalid name")]
+    InvalidName,
+    #[error("io")]
+    Io(std::io::Error),
+    #[error("parse tzif")]
+    ParseTzif(ParseTzifError),
+}
+
+impl TimeZone {
+    pub fn from_tzif_bytes(name: &str, bytes: &[u8]) -> Result<Self, ParseTzifError> {
+        Ok(Self {
+            name: name.to_string(),
+            tzif: Tzif::try_from(bytes)?,
+        })
+    }
+
+    // e.g. TimeZone::from_posix_tz("EST5EDT,M3.2.0,M11.1.0")
+    pub fn from_posix_tz(s: &str) -> Result<Self, ParsePosixTimeZoneError> {
+        Ok(Self {
+            name: s.to_string(),
+            tzif: Tzif::from_posix_time_zone(s)?,
+        })
+    }
+
+    // e.g. TimeZone::from_tzdata(&Tzdata::from_str(northamerica)?, "America/New_York")
+    pub fn from_tzdata(tzdata: &Tzdata, name: &str) -> Result<Self, CompileTzdataError> {
+        Ok(Self {
+            name: name.to_string(),
+            tzif: tzdata.compile(name)?,
+        })
+    }
+
+    // e.g. TimeZone::load("/usr/share/zoneinfo", "America/New_York")
+    pub fn load<P: AsRef<Path>>(dir: P, name: &str) -> Result<Self, LoadTimeZoneError> {
+        if !is_valid_name(name) {
+            return Err(LoadTimeZoneError::InvalidName);
+        }
+        let bytes = std::fs::read(dir.as_ref().join(name)).map_err(LoadTimeZoneError::Io)?;
+        Self::from_tzif_bytes(name, &bytes).map_err(LoadTimeZoneError::ParseTzif)
+    }
+
+    // see LocalTimeZoneConfig::from_env
+    pub fn local() -> Result<Self, LoadTimeZoneError> {
+        LocalTimeZoneConfig::from_env().load()
+    }
+
+    pub fn utc() -> Self {
+        Self {
+            name: "UTC".to_string(),
+            tzif: Tzif::utc(),
+        }
+    }
+
+    pub fn local_time_type_at(&self, instant: Instant) -> &LocalTimeType {
+        self.tzif.local_time_type_at(i64::from(instant))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn offset_at(&self, instant: Instant) -> TimeZoneOffset {
+        self.local_time_type_at(instant).offset()
+    }
+
+    pub fn tzif(&self) -> &Tzif {
+        &self.tzif
+    }
+}
+
+impl OffsetRule for TimeZone {
+    fn offset_at(&self, instant: Instant) -> TimeZoneOffset {
+        TimeZone::offset_at(self, instant)
+    }
+
+    fn next_transition(&self, instant: Instant) -> Option<Transition> {
+        self.tzif.next_transition(i64::from(instant))
+    }
+}
+
+// relative path without "." and ".." (e.g. "America/Argentina/Buenos_Aires")
+fn is_valid_name(name: &str) -> bool {
+    name.split('/').all(|part| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '+'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::YearMonth;
+
+    use super::super::tzif::new_york_tzif_bytes;
+    use super::*;
+
+    fn new_york() -> anyhow::Result<TimeZone> {
+        let bytes = new_york_tzif_bytes(2);
+        Ok(TimeZone::from_tzif_bytes("America/New_York", &bytes)?)
+    }
+
+    #[test]
+    fn offset_at_test() -> anyhow::Result<()> {
+        let time_zone = new_york()?;
+        assert_eq!(time_zone.name(), "America/New_York");
+        let f = |timestamp: u32| time_zone.offset_at(Instant::from(timestamp));
+        assert_eq!(f(1_615_705_199), TimeZoneOffset::from_h(-5)?);
+        assert_eq!(f(1_615_705_200), TimeZoneOffset::from_h(-4)?);
+        assert_eq!(f(1_636_264_799), TimeZoneOffset::from_h(-4)?);
+        assert_eq!(f(1_636_264_800), TimeZoneOffset::from_h(-5)?);
+
+        let local_time_type = time_zone.local_time_type_at(Instant::from(1_615_705_200_u32));
+        assert_eq!(local_time_type.designation(), "EDT");
+        assert!(local_time_type.is_dst());
+        Ok(())
+    }
+
+    #[test]
+    fn next_transition_test() -> anyhow::Result<()> {
+        let time_zone = new_york()?;
+        let transition = time_zone
+            .next_transition(Instant::from(0_u32))
+            .ok_or_else(|| anyhow::anyhow!("no transition"))?;
+        assert_eq!(transition.instant(), Instant::from(1_615_705_200_u32));
+        assert_eq!(transition.offset_before(), TimeZoneOffset::from_h(-5)?);
+        assert_eq!(transition.offset_after(), TimeZoneOffset::from_h(-4)?);
+        let transitions = time_zone.transitions_in(YearMonth::from_str("2022-03")?);
+        assert_eq!(transitions.len(), 1);
+        assert_eq!(
+            transitions[0].instant(),
+            Instant::from_str("2022-03-13T07:00:00Z")?
+        );
+        assert_eq!(TimeZone::utc().next_transition(Instant::min()), None);
+        Ok(())
+    }
+
+    #[test]
+    fn from_posix_tz_test() -> anyhow::Result<()> {
+        let time_zone = TimeZone::from_posix_tz("EST5EDT,M3.2.0,M11.1.0")?;
+        assert_eq!(time_zone.name(), "EST5EDT,M3.2.0,M11.1.0");
+        let f = |timestamp: u32| time_zone.local_time_type_at(Instant::from(timestamp));
+        assert_eq!(f(1_615_705_199).designation(), "EST");
+        assert_eq!(f(1_615_705_200).designation(), "EDT");
+        assert_eq!(f(1_636_264_800).designation(), "EST");
+        assert_eq!(time_zone.tzif().local_time_types().len(), 2);
+        assert_eq!(
+            TimeZone::from_posix_tz("EST"),
+            Err(ParsePosixTimeZoneError::InvalidOffset)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_tzdata_test() -> anyhow::Result<()> {
+        let tzdata = Tzdata::from_str(
+            "
+Rule US 2007 max - Mar Sun>=8 2:00 1:00 D
+Rule US 2007 max - Nov Sun>=1 2:00 0    S
+Zone America/New_York -5:00 US E%sT
+Link America/New_York US/Eastern
+",
+        )?;
+        let time_zone = TimeZone::from_tzdata(&tzdata, "US/Eastern")?;
+        assert_eq!(time_zone.name(), "US/Eastern");
+        let f = |timestamp: u32| time_zone.local_time_type_at(Instant::from(timestamp));
+        assert_eq!(f(1_615_705_199).designation(), "EST");
+        assert_eq!(f(1_615_705_200).designation(), "EDT");
+        assert_eq!(time_zone.tzif().footer(), Some("EST5EDT,M3.2.0,M11.1.0"));
+        assert!(matches!(
+            TimeZone::from_tzdata(&tzdata, "Asia/Tokyo"),
+            Err(CompileTzdataError::UnknownZone)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn utc_test() {
+        let time_zone = TimeZone::utc();
+        assert_eq!(time_zone.name(), "UTC");
+        assert_eq!(time_zone.offset_at(Instant::min()), TimeZoneOffset::utc());
+        assert_eq!(time_zone.offset_at(Instant::max()), TimeZoneOffset::utc());
+        assert_eq!(time_zone.tzif().footer(), Some("UTC0"));
+    }
+
+    #[test]
+    fn load_test() -> anyhow::Result<()> {
+        let dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/zoneinfo"));
+        assert!(matches!(
+            TimeZone::load(dir, "../zoneinfo/UTC"),
+            Err(LoadTimeZoneError::InvalidName)
+        ));
+        assert!(matches!(
+            TimeZone::load(dir, "/etc/localtime"),
+            Err(LoadTimeZoneError::InvalidName)
+        ));
+        assert!(matches!(
+            TimeZone::load(dir, ""),
+            Err(LoadTimeZoneError::InvalidName)
+        ));
+        assert!(matches!(
+            TimeZone::load("/nonexistent", "Asia/Tokyo"),
+            Err(LoadTimeZoneError::Io(_))
+        ));
+
+        let time_zone = TimeZone::load(dir, "Asia/Tokyo")?;
+        assert_eq!(time_zone.name(), "Asia/Tokyo");
+        assert_eq!(
+            time_zone.offset_at(Instant::from(1_612_310_400_u32)),
+            TimeZoneOffset::from_h(9)?
+        );
+        assert_eq!(time_zone.tzif().footer(), Some("JST-9"));
+        Ok(())
+    }
+
+    #[test]
+    fn is_valid_name_test() {
+        assert!(is_valid_name("UTC"));
+        assert!(is_valid_name("Asia/Tokyo"));
+        assert!(is_valid_name("America/Argentina/Buenos_Aires"));
+        assert!(is_valid_name("Etc/GMT+9"));
+        assert!(is_valid_name("America/Port-au-Prince"));
+        assert!(!is_valid_name(""));
+        assert!(!is_valid_name("/UTC"));
+        assert!(!is_valid_name("../UTC"));
+        assert!(!is_valid_name("Asia/./Tokyo"));
+        assert!(!is_valid_name("Asia//Tokyo"));
+        assert!(!is_valid_name("Asia/Tokyo/"));
+        assert!(!is_valid_name("Asia/Tokyo "));
+    }
+}
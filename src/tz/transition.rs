@@ -0,0 +1,70 @@
+use crate::{Instant, TimeZoneOffset};
+
+// an offset change at an instant
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Transition {
+    instant: Instant,
+    offset_before: TimeZoneOffset,
+    offset_after: TimeZoneOffset,
+}
+
+impl Transition {
+    pub fn new(
+        instant: Instant,
+        offset_before: TimeZoneOffset,
+        offset_after: TimeZoneOffset,
+    ) -> Self {
+        Self {
+            instant,
+            offset_before,
+            offset_after,
+        }
+    }
+
+    // the first instant with offset_after
+    pub fn instant(&self) -> Instant {
+        self.instant
+    }
+
+    pub fn offset_after(&self) -> TimeZoneOffset {
+        self.offset_after
+    }
+
+    pub fn offset_before(&self) -> TimeZoneOffset {
+        self.offset_before
+    }
+}
+
+// "2021-03-14T07:00:00Z -05:00 -04:00" for tests
+#[cfg(test)]
+pub(crate) fn transition_string(transition: &Transition) -> String {
+    format!(
+        "{} {} {}",
+        transition.instant(),
+        transition.offset_before(),
+        transition.offset_after()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accessors_test() -> anyhow::Result<()> {
+        let instant = Instant::from(1_615_705_200_u32);
+        let transition = Transition::new(
+            instant,
+            TimeZoneOffset::from_h(-5)?,
+            TimeZoneOffset::from_h(-4)?,
+        );
+        assert_eq!(transition.instant(), instant);
+        assert_eq!(transition.offset_before(), TimeZoneOffset::from_h(-5)?);
+        assert_eq!(transition.offset_after(), TimeZoneOffset::from_h(-4)?);
+        assert_eq!(
+            transition_string(&transition),
+            "2021-03-14T07:00:00Z -05:00 -04:00"
+        );
+        Ok(())
+    }
+}
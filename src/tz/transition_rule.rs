@@ -0,0 +1,244 @@
+use thiserror::Error;
+
+use crate::private::{
+    day_of_week_from_days_from_unix_epoch, days_from_unix_epoch_from_date, is_leap_year,
+};
+use crate::{DayOfWeek, Month, SignedSeconds};
+
+// e.g. the second sunday of march at 02:00:00 (local wall clock time)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TransitionRule {
+    date: TransitionDate,
+    time: SignedSeconds,
+}
+
+// POSIX TZ rule date
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TransitionDate {
+    // Jn: [1, 365] (February 29 is never counted)
+    Julian(u16),
+    // n: [0, 365] (February 29 is counted)
+    ZeroBasedJulian(u16),
+    // Mm.w.d: week is [1, 5] (5: last)
+    MonthWeekDay(Month, u8, DayOfWeek),
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+#[error("invalid transition rule error")]
+pub struct InvalidTransitionRuleError;
+
+impl TransitionRule {
+    // week: [1, 5] (5: last)
+    // time: [-167:00:00, 167:00:00]
+    pub fn new(
+        month: Month,
+        week: u8,
+        day_of_week: DayOfWeek,
+        time: SignedSeconds,
+    ) -> Result<Self, InvalidTransitionRuleError> {
+        if !(1..=5).contains(&week) {
+            return Err(InvalidTransitionRuleError);
+        }
+        Self::from_date(TransitionDate::MonthWeekDay(month, week, day_of_week), time)
+    }
+
+    // day: [1, 365]
+    pub fn julian(day: u16, time: SignedSeconds) -> Result<Self, InvalidTransitionRuleError> {
+        if !(1..=365).contains(&day) {
+            return Err(InvalidTransitionRuleError);
+        }
+        Self::from_date(TransitionDate::Julian(day), time)
+    }
+
+    // day: [0, 365]
+    pub fn zero_based_julian(
+        day: u16,
+        time: SignedSeconds,
+    ) -> Result<Self, InvalidTransitionRuleError> {
+        if day > 365 {
+            return Err(InvalidTransitionRuleError);
+        }
+        Self::from_date(TransitionDate::ZeroBasedJulian(day), time)
+    }
+
+    pub fn date(&self) -> TransitionDate {
+        self.date
+    }
+
+    pub fn time(&self) -> SignedSeconds {
+        self.time
+    }
+
+    // local wall clock time in the year as seconds from unix epoch
+    pub(crate) fn local_seconds_in_year(&self, year: i64) -> i64 {
+        let days = match self.date {
+            TransitionDate::Julian(day) => {
+                let first = days_from_unix_epoch_from_date((year, 1, 1));
+                let day = i64::from(day);
+                // skip February 29
+                first + day - 1
+                    + if is_leap_year(year) && day >= 60 {
+                        1
+                    } else {
+                        0
+                    }
+            }
+            TransitionDate::ZeroBasedJulian(day) => {
+                days_from_unix_epoch_from_date((year, 1, 1)) + i64::from(day)
+            }
+            TransitionDate::MonthWeekDay(month, week, day_of_week) => {
+                let month = i64::from(month);
+                let first = days_from_unix_epoch_from_date((year, month, 1));
+                let next_first = if month == 12 {
+                    days_from_unix_epoch_from_date((year + 1, 1, 1))
+                } else {
+                    days_from_unix_epoch_from_date((year, month + 1, 1))
+                };
+                let offset = (i64::from(day_of_week)
+                    - day_of_week_from_days_from_unix_epoch(first))
+                .rem_euclid(7);
+                let mut days = first + offset + (i64::from(week) - 1) * 7;
+                // the fifth week means the last week
+                while days >= next_first {
+                    days -= 7;
+                }
+                days
+            }
+        };
+        days * 86400 + i64::from(self.time)
+    }
+
+    fn from_date(
+        date: TransitionDate,
+        time: SignedSeconds,
+    ) -> Result<Self, InvalidTransitionRuleError> {
+        if !(-167 * 3600..=167 * 3600).contains(&i64::from(time)) {
+            return Err(InvalidTransitionRuleError);
+        }
+        Ok(Self { date, time })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::DateTime;
+
+    use super::*;
+
+    #[test]
+    fn new_test() -> anyhow::Result<()> {
+        let f = |week: u8, time: i64| {
+            TransitionRule::new(
+                Month::march(),
+                week,
+                DayOfWeek::sunday(),
+                SignedSeconds::from(time),
+            )
+        };
+        let rule = f(2, 7200)?;
+        assert_eq!(
+            rule.date(),
+            TransitionDate::MonthWeekDay(Month::march(), 2, DayOfWeek::sunday())
+        );
+        assert_eq!(rule.time(), SignedSeconds::from(7200));
+        assert!(f(1, 0).is_ok());
+        assert!(f(5, 0).is_ok());
+        assert!(f(1, 167 * 3600).is_ok());
+        assert!(f(1, -167 * 3600).is_ok());
+        assert_eq!(f(0, 0), Err(InvalidTransitionRuleError));
+        assert_eq!(f(6, 0), Err(InvalidTransitionRuleError));
+        assert_eq!(f(1, 167 * 3600 + 1), Err(InvalidTransitionRuleError));
+        assert_eq!(f(1, -167 * 3600 - 1), Err(InvalidTransitionRuleError));
+        Ok(())
+    }
+
+    #[test]
+    fn julian_test() -> anyhow::Result<()> {
+        let f = |day: u16| TransitionRule::julian(day, SignedSeconds::from(0));
+        assert_eq!(f(1)?.date(), TransitionDate::Julian(1));
+        assert!(f(365).is_ok());
+        assert_eq!(f(0), Err(InvalidTransitionRuleError));
+        assert_eq!(f(366), Err(InvalidTransitionRuleError));
+        assert_eq!(
+            TransitionRule::julian(1, SignedSeconds::from(168 * 3600)),
+            Err(InvalidTransitionRuleError)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn zero_based_julian_test() -> anyhow::Result<()> {
+        let f = |day: u16| TransitionRule::zero_based_julian(day, SignedSeconds::from(0));
+        assert_eq!(f(0)?.date(), TransitionDate::ZeroBasedJulian(0));
+        assert!(f(365).is_ok());
+        assert_eq!(f(366), Err(InvalidTransitionRuleError));
+        Ok(())
+    }
+
+    #[test]
+    fn local_seconds_in_year_test() -> anyhow::Result<()> {
+        let f = |rule: TransitionRule, year: i64| {
+            DateTime::from_seconds_from_unix_epoch(rule.local_seconds_in_year(year))
+                .ok_or_else(|| anyhow::anyhow!("out of range"))
+        };
+        let m = |month: Month, week: u8, day_of_week: DayOfWeek, time: i64| {
+            TransitionRule::new(month, week, day_of_week, SignedSeconds::from(time))
+        };
+        let dt = |s: &str| DateTime::from_str(s);
+        // US: second sunday of march, first sunday of november
+        assert_eq!(
+            f(m(Month::march(), 2, DayOfWeek::sunday(), 7200)?, 2021)?,
+            dt("2021-03-14T02:00:00")?
+        );
+        assert_eq!(
+            f(m(Month::november(), 1, DayOfWeek::sunday(), 7200)?, 2021)?,
+            dt("2021-11-07T02:00:00")?
+        );
+        // EU: last sunday of march, last sunday of october
+        assert_eq!(
+            f(m(Month::march(), 5, DayOfWeek::sunday(), 3600)?, 2021)?,
+            dt("2021-03-28T01:00:00")?
+        );
+        assert_eq!(
+            f(m(Month::october(), 5, DayOfWeek::sunday(), 3600)?, 2021)?,
+            dt("2021-10-31T01:00:00")?
+        );
+        assert_eq!(
+            f(m(Month::december(), 5, DayOfWeek::friday(), 0)?, 2021)?,
+            dt("2021-12-31T00:00:00")?
+        );
+        assert_eq!(
+            f(m(Month::february(), 5, DayOfWeek::monday(), 0)?, 2021)?,
+            dt("2021-02-22T00:00:00")?
+        );
+        // the time may be out of the day
+        assert_eq!(
+            f(m(Month::march(), 5, DayOfWeek::saturday(), -3600)?, 2021)?,
+            dt("2021-03-26T23:00:00")?
+        );
+        assert_eq!(
+            f(
+                m(Month::march(), 1, DayOfWeek::thursday(), 25 * 3600)?,
+                2021
+            )?,
+            dt("2021-03-05T01:00:00")?
+        );
+
+        let j = |day: u16| TransitionRule::julian(day, SignedSeconds::from(0));
+        assert_eq!(f(j(1)?, 2020)?, dt("2020-01-01T00:00:00")?);
+        assert_eq!(f(j(59)?, 2020)?, dt("2020-02-28T00:00:00")?);
+        assert_eq!(f(j(60)?, 2020)?, dt("2020-03-01T00:00:00")?);
+        assert_eq!(f(j(60)?, 2021)?, dt("2021-03-01T00:00:00")?);
+        assert_eq!(f(j(365)?, 2020)?, dt("2020-12-31T00:00:00")?);
+
+        let n = |day: u16| TransitionRule::zero_based_julian(day, SignedSeconds::from(0));
+        assert_eq!(f(n(0)?, 2020)?, dt("2020-01-01T00:00:00")?);
+        assert_eq!(f(n(59)?, 2020)?, dt("2020-02-29T00:00:00")?);
+        assert_eq!(f(n(59)?, 2021)?, dt("2021-03-01T00:00:00")?);
+        assert_eq!(f(n(365)?, 2020)?, dt("2020-12-31T00:00:00")?);
+        assert_eq!(f(n(365)?, 2021)?, dt("2022-01-01T00:00:00")?);
+        Ok(())
+    }
+}
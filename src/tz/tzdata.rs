@@ -0,0 +1,1146 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::str::FromStr;
+use thiserror::Error;
+
+use crate::private::{
+    date_from_days_from_unix_epoch, day_of_week_from_days_from_unix_epoch,
+    days_from_unix_epoch_from_date, is_leap_year,
+};
+use crate::Instant;
+
+use super::local_time_type::LocalTimeType;
+use super::posix_time_zone::PosixTimeZone;
+use super::tzif::Tzif;
+
+// tz database source files (zic input format: Rule, Zone and Link lines)
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Tzdata {
+    rules: BTreeMap<String, Vec<RuleLine>>,
+    zones: BTreeMap<String, Vec<ZoneLine>>,
+    links: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParseTzdataError {
+    #[error("duplicate name")]
+    DuplicateName,
+    #[error("invalid date")]
+    InvalidDate,
+    #[error("invalid format")]
+    InvalidFormat,
+    #[error("invalid offset")]
+    InvalidOffset,
+    #[error("invalid time")]
+    InvalidTime,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum CompileTzdataError {
+    #[error("invalid local time type")]
+    InvalidLocalTimeType,
+    #[error("unknown rule")]
+    UnknownRule,
+    #[error("unknown zone")]
+    UnknownZone,
+}
+
+impl Tzdata {
+    pub fn link_names(&self) -> impl Iterator<Item = &str> {
+        self.links.keys().map(String::as_str)
+    }
+
+    pub fn zone_names(&self) -> impl Iterator<Item = &str> {
+        self.zones.keys().map(String::as_str)
+    }
+
+    // the zone compiled as TZif (transitions in the range of Instant)
+    pub(crate) fn compile(&self, name: &str) -> Result<Tzif, CompileTzdataError> {
+        let lines = self.zone_lines(name)?;
+        let mut compiler = Compiler::default();
+        let mut start = i64::MIN;
+        // (stdoff, save) at the end of the previous line
+        let mut previous = (lines[0].stdoff, 0);
+        let mut footer = None;
+        for (index, line) in lines.iter().enumerate() {
+            let is_last = index + 1 == lines.len();
+            let until = match &line.rules {
+                ZoneRules::Fixed(save, is_dst) => {
+                    let state = State::new(*save, *is_dst, "");
+                    compiler.push(start, line.local_time_type(&state)?);
+                    if is_last && *save == 0 {
+                        footer = posix_time_zone_std(line, &state);
+                    }
+                    previous = (line.stdoff, *save);
+                    line.until_timestamp(*save)
+                }
+                ZoneRules::Named(name) => {
+                    let rules = self
+                        .rules
+                        .get(name)
+                        .ok_or(CompileTzdataError::UnknownRule)?;
+                    let ongoing = rules
+                        .iter()
+                        .filter(|rule| rule.to == i64::MAX)
+                        .collect::<Vec<&RuleLine>>();
+                    let dst_footer = if is_last {
+                        posix_time_zone_dst(line, &ongoing)
+                    } else {
+                        None
+                    };
+                    let last_year = match &line.until {
+                        Some(until) => until.year,
+                        None if dst_footer.is_some() || ongoing.is_empty() => {
+                            // the last year without the ongoing rules (the footer takes over)
+                            rules
+                                .iter()
+                                .map(|rule| {
+                                    if rule.to == i64::MAX {
+                                        rule.from
+                                    } else {
+                                        rule.to
+                                    }
+                                })
+                                .chain(year_of_timestamp(start))
+                                .max()
+                                .unwrap_or(MIN_YEAR)
+                                + 1
+                        }
+                        None => MAX_YEAR,
+                    };
+                    let state = compiler.push_rules(start, previous, line, rules, last_year)?;
+                    if is_last {
+                        footer = if ongoing.is_empty() && state.save == 0 {
+                            posix_time_zone_std(line, &state)
+                        } else {
+                            dst_footer
+                        };
+                    }
+                    previous = (line.stdoff, state.save);
+                    line.until_timestamp(state.save)
+                }
+            };
+            match until {
+                Some(until) => start = until,
+                None => break,
+            }
+        }
+        Ok(compiler.into_tzif(footer))
+    }
+
+    fn contains_name(&self, name: &str) -> bool {
+        self.zones.contains_key(name) || self.links.contains_key(name)
+    }
+
+    fn zone_lines(&self, name: &str) -> Result<&[ZoneLine], CompileTzdataError> {
+        let mut name = name;
+        // links may point to links
+        for _ in 0..=self.links.len() {
+            if let Some(lines) = self.zones.get(name) {
+                return Ok(lines);
+            }
+            name = self
+                .links
+                .get(name)
+                .ok_or(CompileTzdataError::UnknownZone)?;
+        }
+        Err(CompileTzdataError::UnknownZone)
+    }
+}
+
+impl FromStr for Tzdata {
+    type Err = ParseTzdataError;
+
+    // multiple files can be concatenated
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        type E = ParseTzdataError;
+        let mut tzdata = Self::default();
+        // the name of the zone that expects a continuation line
+        let mut continued: Option<String> = None;
+        for line in s.lines() {
+            let line = line.split('#').next().unwrap_or_default();
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+            if fields.is_empty() {
+                continue;
+            }
+            let (name, zone_line) = match continued.take() {
+                Some(name) => (name, ZoneLine::parse(&fields)?),
+                None => match keyword(fields[0]).ok_or(E::InvalidFormat)? {
+                    Keyword::Rule => {
+                        if fields.len() != 10 {
+                            return Err(E::InvalidFormat);
+                        }
+                        let rule = RuleLine::parse(&fields[2..])?;
+                        tzdata
+                            .rules
+                            .entry(fields[1].to_string())
+                            .or_default()
+                            .push(rule);
+                        continue;
+                    }
+                    Keyword::Zone => {
+                        let name = fields.get(1).ok_or(E::InvalidFormat)?;
+                        if tzdata.contains_name(name) {
+                            return Err(E::DuplicateName);
+                        }
+                        (name.to_string(), ZoneLine::parse(&fields[2..])?)
+                    }
+                    Keyword::Link => {
+                        if fields.len() != 3 {
+                            return Err(E::InvalidFormat);
+                        }
+                        let (target, name) = (fields[1], fields[2]);
+                        if tzdata.contains_name(name) {
+                            return Err(E::DuplicateName);
+                        }
+                        tzdata.links.insert(name.to_string(), target.to_string());
+                        continue;
+                    }
+                },
+            };
+            // the next line continues the zone
+            if zone_line.until.is_some() {
+                continued = Some(name.clone());
+            }
+            tzdata.zones.entry(name).or_default().push(zone_line);
+        }
+        if continued.is_some() {
+            return Err(E::InvalidFormat);
+        }
+        Ok(tzdata)
+    }
+}
+
+// the year before Instant::min (for empty rule sets)
+const MIN_YEAR: i64 = 1969;
+const MAX_YEAR: i64 = 9999;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Keyword {
+    Rule,
+    Zone,
+    Link,
+}
+
+// e.g. "Rule", "R" (the compact format)
+fn keyword(s: &str) -> Option<Keyword> {
+    match lookup(s, &["Rule", "Zone", "Link"])? {
+        0 => Some(Keyword::Rule),
+        1 => Some(Keyword::Zone),
+        _ => Some(Keyword::Link),
+    }
+}
+
+// case-insensitive unambiguous prefix (e.g. "Ja" -> "January")
+fn lookup(s: &str, words: &[&str]) -> Option<usize> {
+    if s.is_empty() {
+        return None;
+    }
+    let s = s.to_ascii_lowercase();
+    let matches = words
+        .iter()
+        .enumerate()
+        .filter(|(_, word)| word.to_ascii_lowercase().starts_with(&s))
+        .map(|(index, _)| index)
+        .collect::<Vec<usize>>();
+    match matches.as_slice() {
+        [index] => Some(*index),
+        _ => words.iter().position(|word| word.eq_ignore_ascii_case(&s)),
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum TimeKind {
+    // local wall clock time ("w" or no suffix)
+    Wall,
+    // local standard time ("s")
+    Standard,
+    // universal time ("u", "g" or "z")
+    Universal,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum DaySpec {
+    // e.g. "5"
+    Day(i64),
+    // e.g. "lastSun"
+    Last(i64),
+    // e.g. "Sun>=8"
+    OnOrAfter(i64, i64),
+    // e.g. "Sun<=25"
+    OnOrBefore(i64, i64),
+}
+
+impl DaySpec {
+    // e.g. "Sun>=8"
+    fn parse(s: &str, month: i64) -> Option<Self> {
+        let day_spec = if let Some(day_of_week) = s.strip_prefix("last") {
+            Self::Last(day_of_week_from_str(day_of_week)?)
+        } else if let Some((day_of_week, day)) = s.split_once(">=") {
+            Self::OnOrAfter(day_of_week_from_str(day_of_week)?, day.parse().ok()?)
+        } else if let Some((day_of_week, day)) = s.split_once("<=") {
+            Self::OnOrBefore(day_of_week_from_str(day_of_week)?, day.parse().ok()?)
+        } else {
+            Self::Day(s.parse().ok()?)
+        };
+        match day_spec {
+            // 2000 is a leap year
+            Self::Day(day) | Self::OnOrAfter(_, day) | Self::OnOrBefore(_, day)
+                if !(1..=days_in_month(2000, month)).contains(&day) =>
+            {
+                None
+            }
+            _ => Some(day_spec),
+        }
+    }
+
+    fn days_from_unix_epoch(&self, year: i64, month: i64) -> i64 {
+        let first = days_from_unix_epoch_from_date((year, month, 1));
+        let on_or_after = |day_of_week: i64, days: i64| {
+            days + (day_of_week - day_of_week_from_days_from_unix_epoch(days)).rem_euclid(7)
+        };
+        let on_or_before = |day_of_week: i64, days: i64| {
+            days - (day_of_week_from_days_from_unix_epoch(days) - day_of_week).rem_euclid(7)
+        };
+        match *self {
+            Self::Day(day) => first + day - 1,
+            Self::Last(day_of_week) => {
+                on_or_before(day_of_week, first + days_in_month(year, month) - 1)
+            }
+            Self::OnOrAfter(day_of_week, day) => on_or_after(day_of_week, first + day - 1),
+            Self::OnOrBefore(day_of_week, day) => on_or_before(day_of_week, first + day - 1),
+        }
+    }
+
+    // POSIX TZ rule date (e.g. "M3.2.0") and the days to add to the time
+    // e.g. "Sun>=9" -> "Sat>=8" + 1 day -> ("M3.2.6", 1)
+    fn to_posix(self, month: i64) -> Option<(String, i64)> {
+        let month_week_day =
+            |week: i64, day_of_week: i64| format!("M{}.{}.{}", month, week, day_of_week % 7);
+        let (day_of_week, day) = match self {
+            // Jn (February 29 is never counted)
+            Self::Day(day) if month != 2 || day != 29 => {
+                let days = days_from_unix_epoch_from_date((1970, month, day))
+                    - days_from_unix_epoch_from_date((1970, 1, 1));
+                return Some((format!("J{}", days + 1), 0));
+            }
+            Self::Day(_) => return None,
+            Self::Last(day_of_week) => return Some((month_week_day(5, day_of_week), 0)),
+            Self::OnOrBefore(day_of_week, day)
+                if month != 2 && day == days_in_month(1970, month) =>
+            {
+                return Some((month_week_day(5, day_of_week), 0));
+            }
+            Self::OnOrBefore(day_of_week, day) if day >= 7 => (day_of_week, day - 6),
+            Self::OnOrBefore(..) => return None,
+            Self::OnOrAfter(day_of_week, day) => (day_of_week, day),
+        };
+        let shift = (day - 1) % 7;
+        if day - shift > 22 {
+            return None;
+        }
+        let day_of_week = (day_of_week - 1 - shift).rem_euclid(7) + 1;
+        Some((month_week_day((day - shift) / 7 + 1, day_of_week), shift))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct RuleLine {
+    from: i64,
+    // i64::MAX: "max"
+    to: i64,
+    month: i64,
+    day: DaySpec,
+    at: (i64, TimeKind),
+    save: i64,
+    is_dst: bool,
+    letters: String,
+}
+
+impl RuleLine {
+    // FROM TO - IN ON AT SAVE LETTER/S
+    fn parse(fields: &[&str]) -> Result<Self, ParseTzdataError> {
+        type E = ParseTzdataError;
+        let from = match lookup(fields[0], &["minimum", "maximum"]) {
+            Some(0) => 1,
+            Some(_) => return Err(E::InvalidDate),
+            None => fields[0].parse().map_err(|_| E::InvalidDate)?,
+        };
+        let to = match lookup(fields[1], &["minimum", "maximum", "only"]) {
+            Some(0) => return Err(E::InvalidDate),
+            Some(1) => i64::MAX,
+            Some(_) => from,
+            None => fields[1].parse().map_err(|_| E::InvalidDate)?,
+        };
+        if from > to || fields[2] != "-" {
+            return Err(E::InvalidDate);
+        }
+        let month = month_from_str(fields[3]).ok_or(E::InvalidDate)?;
+        let day = DaySpec::parse(fields[4], month).ok_or(E::InvalidDate)?;
+        let at = time_from_str(fields[5]).ok_or(E::InvalidTime)?;
+        let (save, is_dst) = save_from_str(fields[6]).ok_or(E::InvalidOffset)?;
+        let letters = match fields[7] {
+            "-" => String::new(),
+            letters => letters.to_string(),
+        };
+        Ok(Self {
+            from,
+            to,
+            month,
+            day,
+            at,
+            save,
+            is_dst,
+            letters,
+        })
+    }
+
+    fn local_timestamp(&self, year: i64) -> i64 {
+        self.day.days_from_unix_epoch(year, self.month) * 86400 + self.at.0
+    }
+
+    // POSIX TZ rule (e.g. "M3.2.0/2") with the save before the rule
+    fn to_posix(&self, stdoff: i64, save_before: i64) -> Option<String> {
+        let time = self.at.0
+            + match self.at.1 {
+                TimeKind::Wall => 0,
+                TimeKind::Standard => save_before,
+                TimeKind::Universal => stdoff + save_before,
+            };
+        let (date, days) = self.day.to_posix(self.month)?;
+        Some(format!("{}/{}", date, hms(time + days * 86400)))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum ZoneRules {
+    // "-" or e.g. "1:00"
+    Fixed(i64, bool),
+    // e.g. "US"
+    Named(String),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Until {
+    year: i64,
+    month: i64,
+    day: DaySpec,
+    at: (i64, TimeKind),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct ZoneLine {
+    stdoff: i64,
+    rules: ZoneRules,
+    format: String,
+    until: Option<Until>,
+}
+
+impl ZoneLine {
+    // STDOFF RULES FORMAT [UNTIL]
+    fn parse(fields: &[&str]) -> Result<Self, ParseTzdataError> {
+        type E = ParseTzdataError;
+        if !(3..=7).contains(&fields.len()) {
+            return Err(E::InvalidFormat);
+        }
+        let stdoff = time_from_str(fields[0])
+            .filter(|(_, kind)| *kind == TimeKind::Wall)
+            .ok_or(E::InvalidOffset)?
+            .0;
+        let rules = match fields[1] {
+            "-" => ZoneRules::Fixed(0, false),
+            s if s.starts_with(|c: char| c.is_ascii_digit() || c == '-') => {
+                let (save, is_dst) = save_from_str(s).ok_or(E::InvalidOffset)?;
+                ZoneRules::Fixed(save, is_dst)
+            }
+            s => ZoneRules::Named(s.to_string()),
+        };
+        let until = match fields.get(3) {
+            None => None,
+            Some(year) => {
+                let year = year.parse().map_err(|_| E::InvalidDate)?;
+                let month = match fields.get(4) {
+                    None => 1,
+                    Some(month) => month_from_str(month).ok_or(E::InvalidDate)?,
+                };
+                let day = match fields.get(5) {
+                    None => DaySpec::Day(1),
+                    Some(day) => DaySpec::parse(day, month).ok_or(E::InvalidDate)?,
+                };
+                let at = match fields.get(6) {
+                    None => (0, TimeKind::Wall),
+                    Some(at) => time_from_str(at).ok_or(E::InvalidTime)?,
+                };
+                Some(Until {
+                    year,
+                    month,
+                    day,
+                    at,
+                })
+            }
+        };
+        Ok(Self {
+            stdoff,
+            rules,
+            format: fields[2].to_string(),
+            until,
+        })
+    }
+
+    // e.g. "E%sT" -> "EDT", "GMT/BST" -> "BST", "%z" -> "+0530"
+    fn designation(&self, state: &State) -> String {
+        let utoff = self.stdoff + state.save;
+        match self.format.split_once('/') {
+            Some((std, dst)) => {
+                if state.is_dst {
+                    dst.to_string()
+                } else {
+                    std.to_string()
+                }
+            }
+            None => self
+                .format
+                .replace("%s", &state.letters)
+                .replace("%z", &numeric_designation(utoff)),
+        }
+    }
+
+    fn local_time_type(&self, state: &State) -> Result<LocalTimeType, CompileTzdataError> {
+        let utoff = i32::try_from(self.stdoff + state.save)
+            .map_err(|_| CompileTzdataError::InvalidLocalTimeType)?;
+        LocalTimeType::new(utoff, state.is_dst, self.designation(state))
+            .ok_or(CompileTzdataError::InvalidLocalTimeType)
+    }
+
+    fn until_timestamp(&self, save: i64) -> Option<i64> {
+        let until = self.until.as_ref()?;
+        let year = until.year.clamp(1, MAX_YEAR + 1);
+        let local = until.day.days_from_unix_epoch(year, until.month) * 86400 + until.at.0;
+        Some(utc_timestamp(local, until.at.1, self.stdoff, save))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct State {
+    save: i64,
+    is_dst: bool,
+    letters: String,
+}
+
+impl State {
+    fn new(save: i64, is_dst: bool, letters: &str) -> Self {
+        Self {
+            save,
+            is_dst,
+            letters: letters.to_string(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Compiler {
+    // (seconds from unix epoch, local time type) (i64::MIN: the first)
+    transitions: Vec<(i64, LocalTimeType)>,
+}
+
+impl Compiler {
+    fn push(&mut self, timestamp: i64, local_time_type: LocalTimeType) {
+        // the later one wins
+        while self
+            .transitions
+            .last()
+            .is_some_and(|(t, _)| *t >= timestamp)
+        {
+            self.transitions.pop();
+        }
+        if self
+            .transitions
+            .last()
+            .is_some_and(|(_, last)| *last == local_time_type)
+        {
+            return;
+        }
+        self.transitions.push((timestamp, local_time_type));
+    }
+
+    // returns the state at the end of the line
+    fn push_rules(
+        &mut self,
+        start: i64,
+        previous: (i64, i64),
+        line: &ZoneLine,
+        rules: &[RuleLine],
+        last_year: i64,
+    ) -> Result<State, CompileTzdataError> {
+        let first_year = rules.iter().map(|rule| rule.from).min().unwrap_or(MIN_YEAR);
+        let last_year = last_year.min(MAX_YEAR);
+        let mut occurrences = (first_year.max(1)..=last_year)
+            .flat_map(|year| {
+                rules
+                    .iter()
+                    .filter(move |rule| (rule.from..=rule.to).contains(&year))
+                    .map(move |rule| (rule.local_timestamp(year), rule))
+            })
+            .collect::<Vec<(i64, &RuleLine)>>();
+        occurrences.sort_by_key(|(local, _)| *local);
+
+        // the letters of the earliest standard time rule before any rule applies
+        let letters = rules
+            .iter()
+            .filter(|rule| rule.save == 0)
+            .min_by_key(|rule| rule.from)
+            .map_or("", |rule| rule.letters.as_str());
+        let mut state = State::new(0, false, letters);
+        let mut started = false;
+        for (local, rule) in occurrences {
+            let timestamp = utc_timestamp(local, rule.at.1, line.stdoff, state.save);
+            if line
+                .until_timestamp(state.save)
+                .is_some_and(|until| timestamp >= until)
+            {
+                break;
+            }
+            let next = State::new(rule.save, rule.is_dst, &rule.letters);
+            // a rule at the same local time as the start of the line (e.g. "Mar lastSun 2s")
+            let (stdoff, save) = previous;
+            if timestamp <= start || utc_timestamp(local, rule.at.1, stdoff, save) == start {
+                state = next;
+                continue;
+            }
+            if !started {
+                self.push(start, line.local_time_type(&state)?);
+                started = true;
+            }
+            self.push(timestamp, line.local_time_type(&next)?);
+            state = next;
+        }
+        if !started {
+            self.push(start, line.local_time_type(&state)?);
+        }
+        Ok(state)
+    }
+
+    fn into_tzif(self, footer: Option<PosixTimeZone>) -> Tzif {
+        let min = i64::from(Instant::min());
+        let max = i64::from(Instant::max());
+        let initial = self
+            .transitions
+            .iter()
+            .take_while(|(timestamp, _)| *timestamp <= min)
+            .last()
+            .or_else(|| self.transitions.first())
+            .map(|(_, local_time_type)| local_time_type.clone())
+            .expect("at least one zone line");
+        let mut local_time_types = vec![initial];
+        let mut transition_times = vec![];
+        let mut transition_types = vec![];
+        for (timestamp, local_time_type) in self.transitions {
+            if timestamp <= min || timestamp > max {
+                continue;
+            }
+            let index = match local_time_types.iter().position(|t| *t == local_time_type) {
+                Some(index) => index,
+                None => {
+                    local_time_types.push(local_time_type);
+                    local_time_types.len() - 1
+                }
+            };
+            transition_times.push(timestamp);
+            transition_types.push(index);
+        }
+        Tzif::new(transition_times, transition_types, local_time_types, footer)
+    }
+}
+
+// std only (e.g. "JST-9")
+fn posix_time_zone_std(line: &ZoneLine, state: &State) -> Option<PosixTimeZone> {
+    let name = line.designation(state);
+    PosixTimeZone::from_str(&format!("<{}>{}", name, hms(-(line.stdoff + state.save)))).ok()
+}
+
+// e.g. "EST5EDT,M3.2.0,M11.1.0"
+fn posix_time_zone_dst(line: &ZoneLine, ongoing: &[&RuleLine]) -> Option<PosixTimeZone> {
+    let (std, dst) = match ongoing {
+        [a, b] if a.save == 0 && b.save != 0 => (a, b),
+        [a, b] if a.save != 0 && b.save == 0 => (b, a),
+        _ => return None,
+    };
+    let std_name = line.designation(&State::new(std.save, std.is_dst, &std.letters));
+    let dst_name = line.designation(&State::new(dst.save, dst.is_dst, &dst.letters));
+    let s = format!(
+        "<{}>{}<{}>{},{},{}",
+        std_name,
+        hms(-line.stdoff),
+        dst_name,
+        hms(-(line.stdoff + dst.save)),
+        dst.to_posix(line.stdoff, 0)?,
+        std.to_posix(line.stdoff, dst.save)?
+    );
+    PosixTimeZone::from_str(&s).ok()
+}
+
+fn utc_timestamp(local: i64, kind: TimeKind, stdoff: i64, save: i64) -> i64 {
+    match kind {
+        TimeKind::Wall => local - stdoff - save,
+        TimeKind::Standard => local - stdoff,
+        TimeKind::Universal => local,
+    }
+}
+
+fn year_of_timestamp(timestamp: i64) -> Option<i64> {
+    let min = i64::from(Instant::min());
+    let max = i64::from(Instant::max());
+    if timestamp <= min {
+        return None;
+    }
+    let days = timestamp.min(max).div_euclid(86400);
+    Some(date_from_days_from_unix_epoch(days).0)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// [1, 7] (1: Monday, 7: Sunday)
+fn day_of_week_from_str(s: &str) -> Option<i64> {
+    let words = [
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+    ];
+    lookup(s, &words).map(|index| index as i64 + 1)
+}
+
+fn month_from_str(s: &str) -> Option<i64> {
+    let words = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+    lookup(s, &words).map(|index| index as i64 + 1)
+}
+
+// e.g. "+09", "-0330", "+053028"
+fn numeric_designation(utoff: i64) -> String {
+    let sign = if utoff < 0 { '-' } else { '+' };
+    let utoff = utoff.abs();
+    let (h, m, s) = (utoff / 3600, utoff / 60 % 60, utoff % 60);
+    if s != 0 {
+        format!("{}{:02}{:02}{:02}", sign, h, m, s)
+    } else if m != 0 {
+        format!("{}{:02}{:02}", sign, h, m)
+    } else {
+        format!("{}{:02}", sign, h)
+    }
+}
+
+// [-]h[:mm[:ss]] for POSIX TZ strings
+fn hms(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    format!(
+        "{}{}:{:02}:{:02}",
+        sign,
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+// e.g. "1:00" -> (3600, true), "0" -> (0, false), "1:00s" -> (3600, false)
+fn save_from_str(s: &str) -> Option<(i64, bool)> {
+    let (s, is_dst) = match s.strip_suffix('d') {
+        Some(s) => (s, Some(true)),
+        None => match s.strip_suffix('s') {
+            Some(s) => (s, Some(false)),
+            None => (s, None),
+        },
+    };
+    let (save, kind) = time_from_str(s)?;
+    if kind != TimeKind::Wall {
+        return None;
+    }
+    Some((save, is_dst.unwrap_or(save != 0)))
+}
+
+// e.g. "2:00", "2:00s", "-", "24:00", "-0:25:21"
+fn time_from_str(s: &str) -> Option<(i64, TimeKind)> {
+    let (s, kind) = match s.char_indices().last()? {
+        (i, 'w') => (&s[..i], TimeKind::Wall),
+        (i, 's') => (&s[..i], TimeKind::Standard),
+        (i, 'u') | (i, 'g') | (i, 'z') => (&s[..i], TimeKind::Universal),
+        _ => (s, TimeKind::Wall),
+    };
+    if s == "-" {
+        return Some((0, kind));
+    }
+    let (sign, s) = match s.strip_prefix('-') {
+        Some(s) => (-1, s),
+        None => (1, s),
+    };
+    let mut seconds = 0_i64;
+    let mut parts = 0;
+    for (index, part) in s.split(':').enumerate() {
+        if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) || part.len() > 3 {
+            return None;
+        }
+        let value = part.parse::<i64>().ok()?;
+        if index > 0 && (part.len() > 2 || value > 59) {
+            return None;
+        }
+        seconds = seconds * 60 + value;
+        parts = index + 1;
+    }
+    if parts > 3 {
+        return None;
+    }
+    for _ in parts..3 {
+        seconds *= 60;
+    }
+    Some((sign * seconds, kind))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::path::Path;
+
+    use super::*;
+
+    const NORTHAMERICA: &str = "
+# Rule  NAME  FROM  TO    -  IN   ON       AT    SAVE  LETTER/S
+Rule    US    1967  2006  -  Oct  lastSun  2:00  0     S
+Rule    US    1967  1973  -  Apr  lastSun  2:00  1:00  D
+Rule    US    1974  only  -  Jan  6        2:00  1:00  D
+Rule    US    1975  only  -  Feb  lastSun  2:00  1:00  D
+Rule    US    1976  1986  -  Apr  lastSun  2:00  1:00  D
+Rule    US    1987  2006  -  Apr  Sun>=1   2:00  1:00  D
+Rule    US    2007  max   -  Mar  Sun>=8   2:00  1:00  D
+Rule    US    2007  max   -  Nov  Sun>=1   2:00  0     S
+
+# Zone  NAME              STDOFF    RULES  FORMAT  [UNTIL]
+Zone    America/New_York  -4:56:02  -      LMT     1883 Nov 18 17:00u
+                          -5:00     US     E%sT
+Link    America/New_York  US/Eastern
+";
+
+    const EUROPE: &str = "
+R IE 1971 o - O 31 2u -1 -
+R IE 1972 1980 - Mar Su>=16 2u 0 -
+R IE 1972 1980 - O Su>=23 2u -1 -
+R IE 1981 ma - Mar lastSu 1u 0 -
+R IE 1981 1989 - O Su>=23 1u -1 -
+R IE 1990 1995 - O Su>=22 1u -1 -
+R IE 1996 ma - O lastSu 1u -1 -
+Z Europe/Dublin -0:25:21 - LMT 1880 Au 2
+-0:25:21 - DMT 1916 May 21 2s
+0 - GMT 1968 O 27
+1 IE IST/GMT
+";
+
+    const ASIA: &str = "
+Zone Asia/Tokyo 9:18:59 - LMT 1887 Dec 31 15:00u
+                9:00    - JST
+Zone Asia/Kolkata 5:53:28 - LMT 1854 Jun 28
+                  5:30    - %z
+";
+
+    const SOUTHAMERICA: &str = "
+Rule Chile 2023 max - Apr Sun>=2 3:00u 0 -
+Rule Chile 2023 max - Sep Sun>=2 4:00u 1:00 -
+Zone America/Santiago -4:42:45 - LMT 1890
+                      -4:00 Chile %z
+";
+
+    fn compile(source: &str, name: &str) -> anyhow::Result<Tzif> {
+        Ok(Tzdata::from_str(source)?.compile(name)?)
+    }
+
+    fn local_time_type_at(tzif: &Tzif, instant: &str) -> anyhow::Result<String> {
+        let local_time_type = tzif.local_time_type_at(i64::from(Instant::from_str(instant)?));
+        Ok(format!(
+            "{} {} {}",
+            local_time_type.utoff(),
+            local_time_type.is_dst(),
+            local_time_type.designation()
+        ))
+    }
+
+    #[test]
+    fn from_str_test() -> anyhow::Result<()> {
+        let tzdata = Tzdata::from_str(&[NORTHAMERICA, EUROPE, ASIA].concat())?;
+        assert_eq!(
+            tzdata.zone_names().collect::<Vec<&str>>(),
+            vec![
+                "America/New_York",
+                "Asia/Kolkata",
+                "Asia/Tokyo",
+                "Europe/Dublin"
+            ]
+        );
+        assert_eq!(
+            tzdata.link_names().collect::<Vec<&str>>(),
+            vec!["US/Eastern"]
+        );
+        assert_eq!(Tzdata::from_str("")?, Tzdata::default());
+        Ok(())
+    }
+
+    #[test]
+    fn from_str_error_test() {
+        type E = ParseTzdataError;
+        let f = |s: &str| Tzdata::from_str(s).map(|_| ());
+        assert_eq!(
+            f("Foo US 1967 2006 - Oct lastSun 2:00 0 S"),
+            Err(E::InvalidFormat)
+        );
+        assert_eq!(
+            f("Rule US 1967 2006 - Oct lastSun 2:00 0"),
+            Err(E::InvalidFormat)
+        );
+        assert_eq!(
+            f("Rule US 2006 1967 - Oct lastSun 2:00 0 S"),
+            Err(E::InvalidDate)
+        );
+        assert_eq!(
+            f("Rule US 1967 max - Ju lastSun 2:00 0 S"),
+            Err(E::InvalidDate)
+        );
+        assert_eq!(
+            f("Rule US 1967 max - Oct lastSon 2:00 0 S"),
+            Err(E::InvalidDate)
+        );
+        assert_eq!(f("Rule US 1967 max - Feb 30 2:00 0 S"), Err(E::InvalidDate));
+        assert_eq!(
+            f("Rule US 1967 max - Oct Sun>=32 2:00 0 S"),
+            Err(E::InvalidDate)
+        );
+        assert_eq!(
+            f("Rule US 1967 max - Oct lastSun 2:60 0 S"),
+            Err(E::InvalidTime)
+        );
+        assert_eq!(
+            f("Rule US 1967 max - Oct lastSun 2:00x 0 S"),
+            Err(E::InvalidTime)
+        );
+        assert_eq!(
+            f("Rule US 1967 max - Oct lastSun 2:00 1:00u S"),
+            Err(E::InvalidOffset)
+        );
+        assert_eq!(f("Zone Asia/Tokyo 9:00"), Err(E::InvalidFormat));
+        assert_eq!(f("Zone Asia/Tokyo 9:00s - JST"), Err(E::InvalidOffset));
+        assert_eq!(
+            f("Zone Asia/Tokyo 9:00 - JST 1887 Dez"),
+            Err(E::InvalidDate)
+        );
+        // a continuation line is expected
+        assert_eq!(f("Zone Asia/Tokyo 9:00 - JST 1887"), Err(E::InvalidFormat));
+        assert_eq!(f("Link Asia/Tokyo"), Err(E::InvalidFormat));
+        assert_eq!(
+            f("Zone Asia/Tokyo 9:00 - JST\nLink Etc/UTC Asia/Tokyo"),
+            Err(E::DuplicateName)
+        );
+        assert_eq!(
+            f("Zone Asia/Tokyo 9:00 - JST\nZone Asia/Tokyo 9:00 - JST"),
+            Err(E::DuplicateName)
+        );
+    }
+
+    #[test]
+    fn compile_test() -> anyhow::Result<()> {
+        let tzif = compile(NORTHAMERICA, "America/New_York")?;
+        assert_eq!(tzif.footer(), Some("EST5EDT,M3.2.0,M11.1.0"));
+        assert_eq!(tzif.version(), 2);
+        let f = |instant: &str| local_time_type_at(&tzif, instant);
+        assert_eq!(f("1970-01-01T00:00:00Z")?, "-18000 false EST");
+        assert_eq!(f("1974-01-06T06:59:59Z")?, "-18000 false EST");
+        assert_eq!(f("1974-01-06T07:00:00Z")?, "-14400 true EDT");
+        assert_eq!(f("2006-04-02T07:00:00Z")?, "-14400 true EDT");
+        assert_eq!(f("2021-03-14T06:59:59Z")?, "-18000 false EST");
+        assert_eq!(f("2021-03-14T07:00:00Z")?, "-14400 true EDT");
+        assert_eq!(f("2021-11-07T06:00:00Z")?, "-18000 false EST");
+        assert_eq!(f("9999-07-01T00:00:00Z")?, "-14400 true EDT");
+        // the footer takes over after 2008
+        assert_eq!(tzif.transition_times().last(), Some(&1_225_605_600));
+
+        // a link
+        let tzif = compile(NORTHAMERICA, "US/Eastern")?;
+        assert_eq!(tzif.footer(), Some("EST5EDT,M3.2.0,M11.1.0"));
+
+        // negative DST
+        let tzif = compile(EUROPE, "Europe/Dublin")?;
+        assert_eq!(tzif.footer(), Some("IST-1GMT0,M10.5.0,M3.5.0/1"));
+        let f = |instant: &str| local_time_type_at(&tzif, instant);
+        assert_eq!(f("1970-01-01T00:00:00Z")?, "3600 false IST");
+        assert_eq!(f("1971-10-31T01:59:59Z")?, "3600 false IST");
+        assert_eq!(f("1971-10-31T02:00:00Z")?, "0 true GMT");
+        assert_eq!(f("2021-07-01T00:00:00Z")?, "3600 false IST");
+
+        // without rules
+        let tzif = compile(ASIA, "Asia/Tokyo")?;
+        assert_eq!(tzif.footer(), Some("JST-9"));
+        assert_eq!(tzif.transition_times(), &[]);
+        assert_eq!(
+            local_time_type_at(&tzif, "1970-01-01T00:00:00Z")?,
+            "32400 false JST"
+        );
+        let tzif = compile(ASIA, "Asia/Kolkata")?;
+        assert_eq!(tzif.footer(), Some("<+0530>-5:30"));
+        assert_eq!(
+            local_time_type_at(&tzif, "2021-01-01T00:00:00Z")?,
+            "19800 false +0530"
+        );
+
+        // "Sun>=2" -> "Sat>=1" + 24:00
+        let tzif = compile(SOUTHAMERICA, "America/Santiago")?;
+        assert_eq!(tzif.footer(), Some("<-04>4<-03>,M9.1.6/24,M4.1.6/24"));
+        let f = |instant: &str| local_time_type_at(&tzif, instant);
+        assert_eq!(f("2023-09-03T03:59:59Z")?, "-14400 false -04");
+        assert_eq!(f("2023-09-03T04:00:00Z")?, "-10800 true -03");
+        assert_eq!(f("2024-04-07T02:59:59Z")?, "-10800 true -03");
+        assert_eq!(f("2024-04-07T03:00:00Z")?, "-14400 false -04");
+        Ok(())
+    }
+
+    #[test]
+    fn compile_line_start_test() -> anyhow::Result<()> {
+        // the rule takes effect at the start of the second line
+        let source = "
+R A 1999 o - O Su>=1 0 1 -
+R A 2000 o - Mar 3 0 0 -
+Z America/Argentina/Buenos_Aires -3 - %z 1999 O 3
+-4 A %z 2000 Mar 3
+-3 - %z
+";
+        let tzif = compile(source, "America/Argentina/Buenos_Aires")?;
+        let f = |instant: &str| local_time_type_at(&tzif, instant);
+        assert_eq!(f("1999-10-03T02:59:59Z")?, "-10800 false -03");
+        assert_eq!(f("1999-10-03T03:00:00Z")?, "-10800 true -03");
+        assert_eq!(f("2000-03-03T02:59:59Z")?, "-10800 true -03");
+        assert_eq!(f("2000-03-03T03:00:00Z")?, "-10800 false -03");
+        Ok(())
+    }
+
+    #[test]
+    fn compile_error_test() -> anyhow::Result<()> {
+        type E = CompileTzdataError;
+        let tzdata = Tzdata::from_str(NORTHAMERICA)?;
+        assert_eq!(tzdata.compile("Asia/Tokyo"), Err(E::UnknownZone));
+        let tzdata = Tzdata::from_str("Z Asia/Tokyo 9 JP J%sT")?;
+        assert_eq!(tzdata.compile("Asia/Tokyo"), Err(E::UnknownRule));
+        let tzdata = Tzdata::from_str("Z Etc/X 25 - X")?;
+        assert_eq!(tzdata.compile("Etc/X"), Err(E::InvalidLocalTimeType));
+        let tzdata = Tzdata::from_str("L Etc/A Etc/B\nL Etc/B Etc/A")?;
+        assert_eq!(tzdata.compile("Etc/A"), Err(E::UnknownZone));
+        Ok(())
+    }
+
+    #[test]
+    fn lookup_test() {
+        let months = ["January", "June", "July", "March", "May"];
+        assert_eq!(lookup("Ja", &months), Some(0));
+        assert_eq!(lookup("jan", &months), Some(0));
+        assert_eq!(lookup("Ju", &months), None);
+        assert_eq!(lookup("Jun", &months), Some(1));
+        assert_eq!(lookup("Ma", &months), None);
+        assert_eq!(lookup("Januaryx", &months), None);
+        assert_eq!(lookup("", &months), None);
+    }
+
+    #[test]
+    fn time_from_str_test() {
+        let f = time_from_str;
+        assert_eq!(f("2"), Some((7200, TimeKind::Wall)));
+        assert_eq!(f("2:00"), Some((7200, TimeKind::Wall)));
+        assert_eq!(f("2:00w"), Some((7200, TimeKind::Wall)));
+        assert_eq!(f("2:00s"), Some((7200, TimeKind::Standard)));
+        assert_eq!(f("1:00u"), Some((3600, TimeKind::Universal)));
+        assert_eq!(f("1:00g"), Some((3600, TimeKind::Universal)));
+        assert_eq!(f("1:00z"), Some((3600, TimeKind::Universal)));
+        assert_eq!(f("24:00"), Some((86400, TimeKind::Wall)));
+        assert_eq!(f("-0:25:21"), Some((-1521, TimeKind::Wall)));
+        assert_eq!(f("-"), Some((0, TimeKind::Wall)));
+        assert_eq!(f(""), None);
+        assert_eq!(f("2:0x"), None);
+        assert_eq!(f("2:60"), None);
+        assert_eq!(f("2:00:00:00"), None);
+    }
+
+    // the names whose compiled Tzif differs from the TZif file in dir
+    fn parity_failures(tzdata: &Tzdata, dir: &Path) -> anyhow::Result<Vec<String>> {
+        let max = i64::from(Instant::max());
+        let mut failures = vec![];
+        for name in tzdata.zone_names().chain(tzdata.link_names()) {
+            let bytes = match std::fs::read(dir.join(name)) {
+                Ok(bytes) => bytes,
+                Err(_) => continue,
+            };
+            let expected = Tzif::try_from(bytes.as_slice())?;
+            let actual = tzdata.compile(name)?;
+            if expected.footer() != actual.footer() {
+                failures.push(format!("{} {:?}", name, actual.footer()));
+            }
+            // around the transitions and every 97 years
+            let timestamps = expected
+                .transition_times()
+                .iter()
+                .chain(actual.transition_times())
+                .filter(|t| (1..=max).contains(*t))
+                .flat_map(|t| vec![*t - 1, *t])
+                .chain((0..=max).step_by(97 * 365 * 86400));
+            for timestamp in timestamps {
+                if expected.local_time_type_at(timestamp) != actual.local_time_type_at(timestamp) {
+                    failures.push(format!("{} {}", name, timestamp));
+                    break;
+                }
+            }
+        }
+        Ok(failures)
+    }
+
+    #[test]
+    fn parity_with_tzif_test() -> anyhow::Result<()> {
+        // tests/tzdata.zi and the zic-compiled tests/zoneinfo
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+        let tzdata = Tzdata::from_str(&std::fs::read_to_string(dir.join("tzdata.zi"))?)?;
+        assert_eq!(
+            tzdata.zone_names().collect::<Vec<_>>(),
+            vec!["America/New_York", "Europe/Berlin"]
+        );
+        for name in tzdata.zone_names() {
+            assert!(dir.join("zoneinfo").join(name).exists(), "{}", name);
+        }
+        assert_eq!(
+            parity_failures(&tzdata, &dir.join("zoneinfo"))?,
+            Vec::<String>::new()
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[ignore = "depends on the system tz database"]
+    fn parity_with_system_tzif_test() -> anyhow::Result<()> {
+        let dir = Path::new("/usr/share/zoneinfo");
+        let tzdata = Tzdata::from_str(&std::fs::read_to_string(dir.join("tzdata.zi"))?)?;
+        assert_eq!(parity_failures(&tzdata, dir)?, Vec::<String>::new());
+        Ok(())
+    }
+}
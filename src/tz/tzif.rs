@@ -0,0 +1,688 @@
+use std::convert::TryFrom;
+use std::str::FromStr;
+use thiserror::Error;
+
+use crate::Instant;
+
+use super::local_time_type::LocalTimeType;
+use super::offset_rule::OffsetRule;
+use super::posix_time_zone::{ParsePosixTimeZoneError, PosixTimeZone};
+use super::transition::Transition;
+
+// RFC 8536 Time Zone Information Format (TZif) version 1 - 3
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tzif {
+    version: u8,
+    transition_times: Vec<i64>,
+    transition_types: Vec<usize>,
+    local_time_types: Vec<LocalTimeType>,
+    leap_seconds: Vec<(i64, i32)>,
+    footer: Option<String>,
+    posix_time_zone: Option<PosixTimeZone>,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParseTzifError {
+    #[error("invalid designation")]
+    InvalidDesignation,
+    #[error("invalid footer")]
+    InvalidFooter,
+    #[error("invalid header")]
+    InvalidHeader,
+    #[error("invalid leap second")]
+    InvalidLeapSecond,
+    #[error("invalid local time type")]
+    InvalidLocalTimeType,
+    #[error("invalid transition")]
+    InvalidTransition,
+    #[error("unexpected eof")]
+    UnexpectedEof,
+    #[error("unsupported version")]
+    UnsupportedVersion,
+}
+
+impl Tzif {
+    // 1, 2 or 3
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    // seconds from unix epoch (ascending)
+    pub fn transition_times(&self) -> &[i64] {
+        &self.transition_times
+    }
+
+    // indices of local_time_types (same length as transition_times)
+    pub fn transition_types(&self) -> &[usize] {
+        &self.transition_types
+    }
+
+    pub fn local_time_types(&self) -> &[LocalTimeType] {
+        &self.local_time_types
+    }
+
+    // (occurrence, correction)
+    pub fn leap_seconds(&self) -> &[(i64, i32)] {
+        &self.leap_seconds
+    }
+
+    // POSIX TZ string (version 2+)
+    pub fn footer(&self) -> Option<&str> {
+        self.footer.as_deref()
+    }
+
+    // the footer as PosixTimeZone
+    pub fn posix_time_zone(&self) -> Option<&PosixTimeZone> {
+        self.posix_time_zone.as_ref()
+    }
+
+    // a version 2 TZif (e.g. compiled from tzdata)
+    pub(crate) fn new(
+        transition_times: Vec<i64>,
+        transition_types: Vec<usize>,
+        local_time_types: Vec<LocalTimeType>,
+        posix_time_zone: Option<PosixTimeZone>,
+    ) -> Self {
+        Self {
+            version: 2,
+            transition_times,
+            transition_types,
+            local_time_types,
+            leap_seconds: vec![],
+            footer: posix_time_zone.as_ref().map(|tz| tz.to_string()),
+            posix_time_zone,
+        }
+    }
+
+    // a version 2 TZif without transitions
+    pub(crate) fn from_posix_time_zone(s: &str) -> Result<Self, ParsePosixTimeZoneError> {
+        let posix_time_zone = PosixTimeZone::from_str(s)?;
+        let mut local_time_types = vec![posix_time_zone.std().clone()];
+        local_time_types.extend(posix_time_zone.dst().cloned());
+        Ok(Self {
+            version: 2,
+            transition_times: vec![],
+            transition_types: vec![],
+            local_time_types,
+            leap_seconds: vec![],
+            footer: Some(s.to_string()),
+            posix_time_zone: Some(posix_time_zone),
+        })
+    }
+
+    pub(crate) fn utc() -> Self {
+        Self::from_posix_time_zone("UTC0").expect("valid POSIX TZ string")
+    }
+
+    // local time type at seconds from unix epoch
+    pub(crate) fn local_time_type_at(&self, timestamp: i64) -> &LocalTimeType {
+        let index = self.transition_times.partition_point(|t| *t <= timestamp);
+        if index == self.transition_times.len() {
+            if let Some(posix_time_zone) = &self.posix_time_zone {
+                // the footer is only evaluated in the range of Instant
+                let timestamp =
+                    timestamp.clamp(i64::from(Instant::min()), i64::from(Instant::max()));
+                return posix_time_zone.local_time_type_at_timestamp(timestamp);
+            }
+        }
+        let type_index = match index {
+            0 => 0,
+            _ => self.transition_types[index - 1],
+        };
+        &self.local_time_types[type_index]
+    }
+
+    // the first offset change after seconds from unix epoch
+    // (transitions that only change the designation are skipped)
+    pub(crate) fn next_transition(&self, timestamp: i64) -> Option<Transition> {
+        let offset_at = |timestamp: i64| self.local_time_type_at(timestamp).offset();
+        let index = self.transition_times.partition_point(|t| *t <= timestamp);
+        for time in &self.transition_times[index..] {
+            let (before, after) = (offset_at(time - 1), offset_at(*time));
+            if before != after {
+                return Some(Transition::new(
+                    Instant::try_from(*time).ok()?,
+                    before,
+                    after,
+                ));
+            }
+        }
+        // the footer after the last transition
+        let timestamp = self
+            .transition_times
+            .last()
+            .map_or(timestamp, |last| timestamp.max(*last))
+            .max(i64::from(Instant::min()));
+        let instant = Instant::try_from(timestamp).ok()?;
+        self.posix_time_zone.as_ref()?.next_transition(instant)
+    }
+}
+
+impl std::convert::TryFrom<&[u8]> for Tzif {
+    type Error = ParseTzifError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let mut reader = Reader::new(bytes);
+        let header = Header::read(&mut reader)?;
+        if header.version == 1 {
+            let block = Block::read(&mut reader, &header, 4)?;
+            return Ok(block.into_tzif(1, None, None));
+        }
+
+        // skip the version 1 data block
+        reader.read_bytes(header.block_len(4))?;
+        let header = Header::read(&mut reader)?;
+        if header.version == 1 {
+            return Err(ParseTzifError::InvalidHeader);
+        }
+        let block = Block::read(&mut reader, &header, 8)?;
+        let footer = read_footer(&mut reader)?;
+        let posix_time_zone = footer
+            .as_deref()
+            .map(PosixTimeZone::from_str)
+            .transpose()
+            .map_err(|_| ParseTzifError::InvalidFooter)?;
+        Ok(block.into_tzif(header.version, footer, posix_time_zone))
+    }
+}
+
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    fn read(reader: &mut Reader) -> Result<Self, ParseTzifError> {
+        if reader.read_bytes(4)? != b"TZif" {
+            return Err(ParseTzifError::InvalidHeader);
+        }
+        let version = match reader.read_u8()? {
+            0 => 1,
+            b'2' => 2,
+            b'3' => 3,
+            _ => return Err(ParseTzifError::UnsupportedVersion),
+        };
+        reader.read_bytes(15)?;
+        Ok(Self {
+            version,
+            isutcnt: reader.read_count()?,
+            isstdcnt: reader.read_count()?,
+            leapcnt: reader.read_count()?,
+            timecnt: reader.read_count()?,
+            typecnt: reader.read_count()?,
+            charcnt: reader.read_count()?,
+        })
+    }
+
+    // the version 1 header of a version 2+ file is not validated (it may be empty)
+    fn validate(&self) -> Result<(), ParseTzifError> {
+        if (self.isutcnt != 0 && self.isutcnt != self.typecnt)
+            || (self.isstdcnt != 0 && self.isstdcnt != self.typecnt)
+            || self.typecnt == 0
+            || self.charcnt == 0
+        {
+            return Err(ParseTzifError::InvalidHeader);
+        }
+        Ok(())
+    }
+
+    // time_size: 4 (version 1) or 8 (version 2+)
+    fn block_len(&self, time_size: usize) -> usize {
+        self.timecnt * time_size
+            + self.timecnt
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+struct Block {
+    transition_times: Vec<i64>,
+    transition_types: Vec<usize>,
+    local_time_types: Vec<LocalTimeType>,
+    leap_seconds: Vec<(i64, i32)>,
+}
+
+impl Block {
+    fn read(
+        reader: &mut Reader,
+        header: &Header,
+        time_size: usize,
+    ) -> Result<Self, ParseTzifError> {
+        header.validate()?;
+        // check the length before allocating
+        if reader.rest().len() < header.block_len(time_size) {
+            return Err(ParseTzifError::UnexpectedEof);
+        }
+        let read_time = |reader: &mut Reader| match time_size {
+            4 => reader.read_i32().map(i64::from),
+            _ => reader.read_i64(),
+        };
+
+        let mut transition_times = Vec::with_capacity(header.timecnt);
+        for _ in 0..header.timecnt {
+            let time = read_time(reader)?;
+            if transition_times.last().is_some_and(|last| *last >= time) {
+                return Err(ParseTzifError::InvalidTransition);
+            }
+            transition_times.push(time);
+        }
+
+        let mut transition_types = Vec::with_capacity(header.timecnt);
+        for _ in 0..header.timecnt {
+            let index = usize::from(reader.read_u8()?);
+            if index >= header.typecnt {
+                return Err(ParseTzifError::InvalidTransition);
+            }
+            transition_types.push(index);
+        }
+
+        let mut records = Vec::with_capacity(header.typecnt);
+        for _ in 0..header.typecnt {
+            let utoff = reader.read_i32()?;
+            let is_dst = match reader.read_u8()? {
+                0 => false,
+                1 => true,
+                _ => return Err(ParseTzifError::InvalidLocalTimeType),
+            };
+            let idx = usize::from(reader.read_u8()?);
+            records.push((utoff, is_dst, idx));
+        }
+
+        let chars = reader.read_bytes(header.charcnt)?;
+        let mut local_time_types = Vec::with_capacity(header.typecnt);
+        for (utoff, is_dst, idx) in records {
+            let designation = read_designation(chars, idx)?;
+            let local_time_type = LocalTimeType::new(utoff, is_dst, designation)
+                .ok_or(ParseTzifError::InvalidLocalTimeType)?;
+            local_time_types.push(local_time_type);
+        }
+
+        let mut leap_seconds = Vec::with_capacity(header.leapcnt);
+        for _ in 0..header.leapcnt {
+            let occurrence = read_time(reader)?;
+            let correction = reader.read_i32()?;
+            if leap_seconds
+                .last()
+                .is_some_and(|(last, _)| *last >= occurrence)
+            {
+                return Err(ParseTzifError::InvalidLeapSecond);
+            }
+            leap_seconds.push((occurrence, correction));
+        }
+
+        // standard/wall and UT/local indicators are only used with POSIX TZ rules
+        for _ in 0..header.isstdcnt + header.isutcnt {
+            if reader.read_u8()? > 1 {
+                return Err(ParseTzifError::InvalidLocalTimeType);
+            }
+        }
+
+        Ok(Self {
+            transition_times,
+            transition_types,
+            local_time_types,
+            leap_seconds,
+        })
+    }
+
+    fn into_tzif(
+        self,
+        version: u8,
+        footer: Option<String>,
+        posix_time_zone: Option<PosixTimeZone>,
+    ) -> Tzif {
+        Tzif {
+            version,
+            transition_times: self.transition_times,
+            transition_types: self.transition_types,
+            local_time_types: self.local_time_types,
+            leap_seconds: self.leap_seconds,
+            footer,
+            posix_time_zone,
+        }
+    }
+}
+
+fn read_designation(chars: &[u8], idx: usize) -> Result<String, ParseTzifError> {
+    let bytes = chars.get(idx..).ok_or(ParseTzifError::InvalidDesignation)?;
+    let len = bytes
+        .iter()
+        .position(|b| *b == 0)
+        .ok_or(ParseTzifError::InvalidDesignation)?;
+    let designation = &bytes[..len];
+    if !designation.iter().all(|b| b.is_ascii_graphic()) {
+        return Err(ParseTzifError::InvalidDesignation);
+    }
+    String::from_utf8(designation.to_vec()).map_err(|_| ParseTzifError::InvalidDesignation)
+}
+
+fn read_footer(reader: &mut Reader) -> Result<Option<String>, ParseTzifError> {
+    if reader.read_u8()? != b'\n' {
+        return Err(ParseTzifError::InvalidFooter);
+    }
+    let rest = reader.rest();
+    let len = rest
+        .iter()
+        .position(|b| *b == b'\n')
+        .ok_or(ParseTzifError::InvalidFooter)?;
+    let footer = &rest[..len];
+    if !footer.iter().all(|b| b.is_ascii_graphic()) {
+        return Err(ParseTzifError::InvalidFooter);
+    }
+    let footer = String::from_utf8(footer.to_vec()).map_err(|_| ParseTzifError::InvalidFooter)?;
+    Ok(if footer.is_empty() {
+        None
+    } else {
+        Some(footer)
+    })
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], ParseTzifError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .ok_or(ParseTzifError::UnexpectedEof)?;
+        let bytes = self
+            .bytes
+            .get(self.pos..end)
+            .ok_or(ParseTzifError::UnexpectedEof)?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn read_count(&mut self) -> Result<usize, ParseTzifError> {
+        let count = u32::from_be_bytes(self.read_array()?);
+        usize::try_from(count).map_err(|_| ParseTzifError::InvalidHeader)
+    }
+
+    fn read_i32(&mut self) -> Result<i32, ParseTzifError> {
+        Ok(i32::from_be_bytes(self.read_array()?))
+    }
+
+    fn read_i64(&mut self) -> Result<i64, ParseTzifError> {
+        Ok(i64::from_be_bytes(self.read_array()?))
+    }
+
+    fn read_u8(&mut self) -> Result<u8, ParseTzifError> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], ParseTzifError> {
+        let mut array = [0_u8; N];
+        array.copy_from_slice(self.read_bytes(N)?);
+        Ok(array)
+    }
+
+    fn rest(&self) -> &'a [u8] {
+        &self.bytes[self.pos..]
+    }
+}
+
+// builds TZif bytes for tests
+#[cfg(test)]
+pub(crate) fn tzif_bytes(
+    version: u8,
+    transitions: &[(i64, u8)],
+    local_time_types: &[(i32, bool, &str)],
+    footer: &str,
+) -> Vec<u8> {
+    let mut chars = Vec::new();
+    let mut idxs = Vec::new();
+    for (_, _, designation) in local_time_types {
+        idxs.push(chars.len() as u8);
+        chars.extend_from_slice(designation.as_bytes());
+        chars.push(0);
+    }
+    let header = |bytes: &mut Vec<u8>, timecnt: usize| {
+        bytes.extend_from_slice(b"TZif");
+        bytes.push(match version {
+            1 => 0,
+            _ => b'0' + version,
+        });
+        bytes.extend_from_slice(&[0; 15]);
+        for count in &[0, 0, 0, timecnt, local_time_types.len(), chars.len()] {
+            bytes.extend_from_slice(&(*count as u32).to_be_bytes());
+        }
+    };
+    let block = |bytes: &mut Vec<u8>, transitions: &[(i64, u8)], time_size: usize| {
+        for (time, _) in transitions {
+            match time_size {
+                4 => bytes.extend_from_slice(&(*time as i32).to_be_bytes()),
+                _ => bytes.extend_from_slice(&time.to_be_bytes()),
+            }
+        }
+        for (_, index) in transitions {
+            bytes.push(*index);
+        }
+        for ((utoff, is_dst, _), idx) in local_time_types.iter().zip(idxs.iter()) {
+            bytes.extend_from_slice(&utoff.to_be_bytes());
+            bytes.push(u8::from(*is_dst));
+            bytes.push(*idx);
+        }
+        bytes.extend_from_slice(&chars);
+    };
+
+    let mut bytes = Vec::new();
+    // version 1 data block is limited to 32-bit transition times
+    let transitions32 = transitions
+        .iter()
+        .copied()
+        .filter(|(time, _)| i32::try_from(*time).is_ok())
+        .collect::<Vec<(i64, u8)>>();
+    header(&mut bytes, transitions32.len());
+    block(&mut bytes, &transitions32, 4);
+    if version == 1 {
+        return bytes;
+    }
+    header(&mut bytes, transitions.len());
+    block(&mut bytes, transitions, 8);
+    bytes.push(b'\n');
+    bytes.extend_from_slice(footer.as_bytes());
+    bytes.push(b'\n');
+    bytes
+}
+
+// America/New_York (2021 and the footer) for tests
+#[cfg(test)]
+pub(crate) fn new_york_tzif_bytes(version: u8) -> Vec<u8> {
+    tzif_bytes(
+        version,
+        &[(-2_717_650_800, 2), (1_615_705_200, 1), (1_636_264_800, 2)],
+        &[
+            (-17762, false, "LMT"),
+            (-14400, true, "EDT"),
+            (-18000, false, "EST"),
+        ],
+        "EST5EDT,M3.2.0,M11.1.0",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::transition::transition_string;
+    use super::*;
+
+    #[test]
+    fn try_from_test() -> anyhow::Result<()> {
+        let tzif = Tzif::try_from(new_york_tzif_bytes(2).as_slice())?;
+        assert_eq!(tzif.version(), 2);
+        assert_eq!(
+            tzif.transition_times(),
+            &[-2_717_650_800, 1_615_705_200, 1_636_264_800]
+        );
+        assert_eq!(tzif.transition_types(), &[2, 1, 2]);
+        assert_eq!(tzif.local_time_types().len(), 3);
+        assert_eq!(tzif.local_time_types()[0].designation(), "LMT");
+        assert_eq!(tzif.local_time_types()[1].utoff(), -14400);
+        assert!(tzif.local_time_types()[1].is_dst());
+        assert_eq!(tzif.local_time_types()[1].designation(), "EDT");
+        assert_eq!(tzif.leap_seconds(), &[]);
+        assert_eq!(tzif.footer(), Some("EST5EDT,M3.2.0,M11.1.0"));
+        assert_eq!(
+            tzif.posix_time_zone().map(|tz| tz.to_string()),
+            Some("EST5EDT,M3.2.0,M11.1.0".to_string())
+        );
+
+        let tzif = Tzif::try_from(new_york_tzif_bytes(3).as_slice())?;
+        assert_eq!(tzif.version(), 3);
+
+        // version 1 has no 64-bit data and no footer
+        let tzif = Tzif::try_from(new_york_tzif_bytes(1).as_slice())?;
+        assert_eq!(tzif.version(), 1);
+        assert_eq!(tzif.transition_times(), &[1_615_705_200, 1_636_264_800]);
+        assert_eq!(tzif.footer(), None);
+
+        // empty footer
+        let bytes = tzif_bytes(2, &[], &[(0, false, "UTC")], "");
+        assert_eq!(Tzif::try_from(bytes.as_slice())?.footer(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn try_from_error_test() {
+        type E = ParseTzifError;
+        let f = |bytes: Vec<u8>| Tzif::try_from(bytes.as_slice());
+        let utc = || tzif_bytes(2, &[], &[(0, false, "UTC")], "UTC0");
+
+        assert_eq!(f(vec![]), Err(E::UnexpectedEof));
+        assert_eq!(f(b"TZi".to_vec()), Err(E::UnexpectedEof));
+        assert_eq!(f(b"TZig2".to_vec()), Err(E::InvalidHeader));
+        let mut bytes = utc();
+        bytes[4] = b'5';
+        assert_eq!(f(bytes), Err(E::UnsupportedVersion));
+        let mut bytes = utc();
+        bytes.truncate(bytes.len() - 1);
+        assert_eq!(f(bytes), Err(E::InvalidFooter));
+        let mut bytes = utc();
+        bytes.truncate(bytes.len() - 10);
+        assert_eq!(f(bytes), Err(E::UnexpectedEof));
+        assert_eq!(f(tzif_bytes(2, &[], &[], "")), Err(E::InvalidHeader));
+        assert_eq!(
+            f(tzif_bytes(2, &[(1, 0), (1, 0)], &[(0, false, "UTC")], "")),
+            Err(E::InvalidTransition)
+        );
+        assert_eq!(
+            f(tzif_bytes(2, &[(1, 1)], &[(0, false, "UTC")], "")),
+            Err(E::InvalidTransition)
+        );
+        assert_eq!(
+            f(tzif_bytes(2, &[], &[(86400, false, "X")], "")),
+            Err(E::InvalidLocalTimeType)
+        );
+        assert_eq!(
+            f(tzif_bytes(2, &[], &[(0, false, "U\tC")], "")),
+            Err(E::InvalidDesignation)
+        );
+        assert_eq!(
+            f(tzif_bytes(2, &[], &[(0, false, "UTC")], "UTC 0")),
+            Err(E::InvalidFooter)
+        );
+        assert_eq!(
+            f(tzif_bytes(2, &[], &[(0, false, "UTC")], "UTC")),
+            Err(E::InvalidFooter)
+        );
+    }
+
+    #[test]
+    fn local_time_type_at_test() -> anyhow::Result<()> {
+        let tzif = Tzif::try_from(new_york_tzif_bytes(2).as_slice())?;
+        let f = |timestamp: i64| tzif.local_time_type_at(timestamp).designation();
+        // before the first transition
+        assert_eq!(f(i64::MIN), "LMT");
+        assert_eq!(f(-2_717_650_801), "LMT");
+        assert_eq!(f(-2_717_650_800), "EST");
+        assert_eq!(f(1_615_705_199), "EST");
+        assert_eq!(f(1_615_705_200), "EDT");
+        assert_eq!(f(1_636_264_799), "EDT");
+        assert_eq!(f(1_636_264_800), "EST");
+        // the footer after the last transition (2021-11-07T06:00:00Z)
+        assert_eq!(f(1_647_154_799), "EST");
+        assert_eq!(f(1_647_154_800), "EDT");
+        assert_eq!(f(1_667_714_400), "EST");
+        assert_eq!(f(i64::MAX), "EST");
+        // the footer wins over the type of the last transition
+        let bytes = tzif_bytes(
+            2,
+            &[(0, 1)],
+            &[(0, false, "LMT"), (3600, true, "X")],
+            "UTC0",
+        );
+        let tzif = Tzif::try_from(bytes.as_slice())?;
+        assert_eq!(tzif.local_time_type_at(0).designation(), "UTC");
+
+        // without footer
+        let tzif = Tzif::try_from(new_york_tzif_bytes(1).as_slice())?;
+        assert_eq!(tzif.local_time_type_at(i64::MAX).designation(), "EST");
+        let bytes = tzif_bytes(2, &[(0, 1)], &[(0, false, "LMT"), (3600, true, "X")], "");
+        let tzif = Tzif::try_from(bytes.as_slice())?;
+        assert_eq!(tzif.local_time_type_at(i64::MAX).designation(), "X");
+        Ok(())
+    }
+
+    #[test]
+    fn next_transition_test() -> anyhow::Result<()> {
+        let f = |tzif: &Tzif, timestamp: i64| {
+            tzif.next_transition(timestamp)
+                .as_ref()
+                .map(transition_string)
+        };
+        let tzif = Tzif::try_from(new_york_tzif_bytes(2).as_slice())?;
+        assert_eq!(
+            f(&tzif, 0),
+            Some("2021-03-14T07:00:00Z -05:00 -04:00".to_string())
+        );
+        assert_eq!(
+            f(&tzif, 1_615_705_200),
+            Some("2021-11-07T06:00:00Z -04:00 -05:00".to_string())
+        );
+        // the footer is in effect after the last transition
+        assert_eq!(
+            f(&tzif, 1_636_264_800),
+            Some("2022-03-13T07:00:00Z -05:00 -04:00".to_string())
+        );
+        assert_eq!(
+            f(&tzif, 1_647_154_800),
+            Some("2022-11-06T06:00:00Z -04:00 -05:00".to_string())
+        );
+
+        // without footer
+        let tzif = Tzif::try_from(new_york_tzif_bytes(1).as_slice())?;
+        assert_eq!(f(&tzif, 1_636_264_800), None);
+
+        // the designation only changes (e.g. "+09" -> "JST")
+        let bytes = tzif_bytes(
+            2,
+            &[(100, 1), (200, 2)],
+            &[
+                (32400, false, "+09"),
+                (32400, false, "JST"),
+                (36000, true, "JDT"),
+            ],
+            "",
+        );
+        let tzif = Tzif::try_from(bytes.as_slice())?;
+        assert_eq!(
+            f(&tzif, 0),
+            Some("1970-01-01T00:03:20Z +09:00 +10:00".to_string())
+        );
+        Ok(())
+    }
+}
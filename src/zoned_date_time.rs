@@ -0,0 +1,194 @@
+use thiserror::Error;
+
+use crate::{
+    DateTime, DisambiguationPolicy, Instant, OffsetDateTime, OffsetRule, ResolveLocalDateTimeError,
+    TimeZone, TimeZoneOffset,
+};
+
+// OffsetDateTime with a region-based time zone
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ZonedDateTime {
+    offset_date_time: OffsetDateTime,
+    time_zone: TimeZone,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum TryFromZonedDateTimeError {
+    #[error("out of range")]
+    OutOfRange,
+}
+
+impl ZonedDateTime {
+    pub fn from_date_time(
+        date_time: DateTime,
+        time_zone: TimeZone,
+        policy: DisambiguationPolicy,
+    ) -> Result<Self, ResolveLocalDateTimeError> {
+        let offset_date_time = time_zone.resolve_with_policy(date_time, policy)?;
+        Ok(Self {
+            offset_date_time,
+            time_zone,
+        })
+    }
+
+    pub fn from_instant(
+        instant: Instant,
+        time_zone: TimeZone,
+    ) -> Result<Self, TryFromZonedDateTimeError> {
+        let offset = time_zone.offset_at(instant);
+        let offset_date_time = OffsetDateTime::from_instant(instant, offset)
+            .map_err(|_| TryFromZonedDateTimeError::OutOfRange)?;
+        Ok(Self {
+            offset_date_time,
+            time_zone,
+        })
+    }
+
+    pub fn date_time(&self) -> DateTime {
+        self.offset_date_time.date_time()
+    }
+
+    pub fn instant(&self) -> Instant {
+        self.offset_date_time.instant()
+    }
+
+    pub fn offset(&self) -> TimeZoneOffset {
+        self.offset_date_time.offset()
+    }
+
+    pub fn offset_date_time(&self) -> OffsetDateTime {
+        self.offset_date_time
+    }
+
+    pub fn time_zone(&self) -> &TimeZone {
+        &self.time_zone
+    }
+
+    // the same instant in another time zone
+    pub fn with_time_zone(&self, time_zone: TimeZone) -> Result<Self, TryFromZonedDateTimeError> {
+        Self::from_instant(self.instant(), time_zone)
+    }
+}
+
+impl std::fmt::Display for ZonedDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}]", self.offset_date_time, self.time_zone.name())
+    }
+}
+
+impl From<ZonedDateTime> for Instant {
+    fn from(zoned_date_time: ZonedDateTime) -> Self {
+        zoned_date_time.instant()
+    }
+}
+
+impl From<ZonedDateTime> for OffsetDateTime {
+    fn from(zoned_date_time: ZonedDateTime) -> Self {
+        zoned_date_time.offset_date_time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::tz::new_york_tzif_bytes;
+
+    use super::*;
+
+    fn new_york() -> anyhow::Result<TimeZone> {
+        let bytes = new_york_tzif_bytes(2);
+        Ok(TimeZone::from_tzif_bytes("America/New_York", &bytes)?)
+    }
+
+    #[test]
+    fn from_instant_test() -> anyhow::Result<()> {
+        let f = |s: &str| -> anyhow::Result<String> {
+            let instant = Instant::from_str(s)?;
+            Ok(ZonedDateTime::from_instant(instant, new_york()?)?.to_string())
+        };
+        assert_eq!(
+            f("2021-03-14T06:59:59Z")?,
+            "2021-03-14T01:59:59-05:00[America/New_York]"
+        );
+        assert_eq!(
+            f("2021-03-14T07:00:00Z")?,
+            "2021-03-14T03:00:00-04:00[America/New_York]"
+        );
+        assert_eq!(
+            f("2021-11-07T05:59:59Z")?,
+            "2021-11-07T01:59:59-04:00[America/New_York]"
+        );
+        assert_eq!(
+            f("2021-11-07T06:00:00Z")?,
+            "2021-11-07T01:00:00-05:00[America/New_York]"
+        );
+        assert_eq!(
+            ZonedDateTime::from_instant(Instant::min(), new_york()?),
+            Err(TryFromZonedDateTimeError::OutOfRange)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_date_time_test() -> anyhow::Result<()> {
+        let f = |s: &str, policy: DisambiguationPolicy| -> anyhow::Result<String> {
+            let date_time = DateTime::from_str(s)?;
+            Ok(ZonedDateTime::from_date_time(date_time, new_york()?, policy)?.to_string())
+        };
+        assert_eq!(
+            f("2021-03-14T02:30:00", DisambiguationPolicy::ShiftForward)?,
+            "2021-03-14T03:30:00-04:00[America/New_York]"
+        );
+        assert_eq!(
+            f("2021-03-14T02:30:00.5", DisambiguationPolicy::ShiftForward)?,
+            "2021-03-14T03:30:00.500-04:00[America/New_York]"
+        );
+        assert_eq!(
+            f("2021-11-07T01:30:00", DisambiguationPolicy::Latest)?,
+            "2021-11-07T01:30:00-05:00[America/New_York]"
+        );
+        assert_eq!(
+            ZonedDateTime::from_date_time(
+                DateTime::from_str("2021-11-07T01:30:00")?,
+                new_york()?,
+                DisambiguationPolicy::Reject
+            ),
+            Err(ResolveLocalDateTimeError::Ambiguous)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn accessors_test() -> anyhow::Result<()> {
+        let instant = Instant::from_str("2021-03-14T07:00:00Z")?;
+        let zoned_date_time = ZonedDateTime::from_instant(instant, new_york()?)?;
+        assert_eq!(zoned_date_time.instant(), instant);
+        assert_eq!(
+            zoned_date_time.date_time(),
+            DateTime::from_str("2021-03-14T03:00:00")?
+        );
+        assert_eq!(zoned_date_time.offset(), TimeZoneOffset::from_h(-4)?);
+        assert_eq!(
+            zoned_date_time.offset_date_time(),
+            OffsetDateTime::from_str("2021-03-14T03:00:00-04:00")?
+        );
+        assert_eq!(zoned_date_time.time_zone().name(), "America/New_York");
+        assert_eq!(Instant::from(zoned_date_time.clone()), instant);
+        assert_eq!(
+            OffsetDateTime::from(zoned_date_time),
+            OffsetDateTime::from_str("2021-03-14T03:00:00-04:00")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn with_time_zone_test() -> anyhow::Result<()> {
+        let instant = Instant::from_str("2021-03-14T07:00:00Z")?;
+        let zoned_date_time = ZonedDateTime::from_instant(instant, new_york()?)?;
+        let utc = zoned_date_time.with_time_zone(TimeZone::utc())?;
+        assert_eq!(utc.instant(), instant);
+        assert_eq!(utc.to_string(), "2021-03-14T07:00:00Z[UTC]");
+        Ok(())
+    }
+}
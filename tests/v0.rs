@@ -1,6 +1,19 @@
-use std::{convert::TryFrom, str::FromStr};
+use std::{
+    convert::TryFrom,
+    str::FromStr,
+    time::{Duration, SystemTime},
+};
 
-use limited_date_time::{Date, DateTime, DayOfMonth, Days, OffsetDateTime, Year, YearMonth};
+use limited_date_time::{
+    date, datetime, odt, offset, time, year_month, CalendarDate, Clock, Date, DateTime,
+    DateTimeOfDay, DayOfMonth, Days, DisambiguationPolicy, EndOfMonthPolicy, ExtendedDateTime,
+    GpsTime, Instant, Interval, LeapSecondDateTime, LeapSecondPolicy, LocalOffset,
+    LocalTimeZoneConfig, ManualClock, Months, Nanosecond, OffsetDateTime, OffsetHistory,
+    OffsetRule, ParseExtendedDateTimeError, PosixTimeZone, PreciseInstant, RecurrenceRule,
+    RepeatingInterval, ResolveExtendedDateTimeError, Rfc3339DateTime, RoundingMode, Seconds,
+    SignedDays, SignedSeconds, SystemClock, TaiInstant, Time, TimeZone, TimeZoneAnnotation,
+    TimeZoneOffset, Tzdata, Year, YearMonth, ZonedDateTime,
+};
 
 #[test]
 fn use_case_offset_date_time_plus_days() -> anyhow::Result<()> {
@@ -24,6 +37,35 @@ fn use_case_offset_date_time_plus_days() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn use_case_offset_date_time_minus_days() -> anyhow::Result<()> {
+    let offset_date_time = OffsetDateTime::from_str("2021-02-03T04:05:06+09:00")?;
+    let updated_offset_date_time = OffsetDateTime::from_instant(
+        offset_date_time.instant() - Days::from(3_u8),
+        offset_date_time.offset(),
+    )?;
+    assert_eq!(
+        updated_offset_date_time.to_string(),
+        "2021-01-31T04:05:06+09:00"
+    );
+    Ok(())
+}
+
+#[test]
+fn use_case_offset_date_time_duration_between() -> anyhow::Result<()> {
+    let start = OffsetDateTime::from_str("2021-02-03T04:05:06+09:00")?;
+    let end = OffsetDateTime::from_str("2021-02-02T20:05:07Z")?;
+    assert_eq!(
+        end.instant() - start.instant(),
+        SignedSeconds::from(3_601_i64)
+    );
+    assert_eq!(
+        end.date_time().date() - start.date_time().date(),
+        SignedDays::from(-1_i8)
+    );
+    Ok(())
+}
+
 #[test]
 fn use_case_offset_date_time_with_day_of_month() -> anyhow::Result<()> {
     let with_day_of_month = |offset_date_time: OffsetDateTime,
@@ -108,6 +150,562 @@ fn use_case_offset_date_time_next_month() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn use_case_billing_anniversary() -> anyhow::Result<()> {
+    // the n-th billing date of a subscription started on the 31st
+    let billing_date = |start: Date, n: u32| -> anyhow::Result<Date> {
+        Ok(start.add_months(Months::from(n), EndOfMonthPolicy::Clamp)?)
+    };
+
+    let start = Date::from_str("2021-01-31")?;
+    assert_eq!(billing_date(start, 1)?.to_string(), "2021-02-28");
+    assert_eq!(billing_date(start, 2)?.to_string(), "2021-03-31");
+    assert_eq!(billing_date(start, 3)?.to_string(), "2021-04-30");
+    assert_eq!(billing_date(start, 13)?.to_string(), "2022-02-28");
+    assert_eq!(billing_date(start, 37)?.to_string(), "2024-02-29");
+
+    let offset_date_time = OffsetDateTime::from_str("2021-01-31T04:05:06+09:00")?;
+    assert!(offset_date_time
+        .add_months(Months::from(1_u32), EndOfMonthPolicy::Fail)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn use_case_daily_report() -> anyhow::Result<()> {
+    let start = Date::from_str("2021-02-26")?;
+    let end = Date::from_str("2021-03-02")?;
+    let dates = Date::range(start..=end)
+        .map(|d| d.to_string())
+        .collect::<Vec<String>>();
+    assert_eq!(
+        dates,
+        vec![
+            "2021-02-26",
+            "2021-02-27",
+            "2021-02-28",
+            "2021-03-01",
+            "2021-03-02"
+        ]
+    );
+    assert_eq!(Date::range(start..end).len(), 4);
+    assert_eq!(Date::range(start..=end).next_back(), Some(end));
+
+    let months = YearMonth::range(YearMonth::from_str("2021-11")?..)
+        .take(3)
+        .map(|ym| ym.to_string())
+        .collect::<Vec<String>>();
+    assert_eq!(months, vec!["2021-11", "2021-12", "2022-01"]);
+    Ok(())
+}
+
+#[test]
+fn use_case_batch_job_window() -> anyhow::Result<()> {
+    let window = Interval::from_str("2021-02-03T00:00:00+09:00/PT6H")?;
+    let now = OffsetDateTime::from_str("2021-02-02T20:00:00Z")?;
+    assert!(window.contains(now.instant()));
+    assert_eq!(
+        window.end().map(|end| end.to_string()),
+        Some("2021-02-03T06:00:00+09:00".to_string())
+    );
+
+    let runs = RepeatingInterval::from_str("R5/2021-02-03T00:00:00Z/P1D")?
+        .iter()
+        .map(|odt| odt.to_string())
+        .collect::<Vec<String>>();
+    assert_eq!(runs.len(), 5);
+    assert_eq!(
+        runs.last().map(String::as_str),
+        Some("2021-02-07T00:00:00Z")
+    );
+    Ok(())
+}
+
+#[test]
+fn use_case_recurring_meeting() -> anyhow::Result<()> {
+    // the last friday of every month
+    let rule = RecurrenceRule::from_str("RRULE:FREQ=MONTHLY;BYDAY=-1FR;COUNT=3")?;
+    let start = OffsetDateTime::from_str("2021-01-29T10:00:00+09:00")?;
+    let meetings = rule
+        .offset_occurrences(start)
+        .map(|o| o.map(|odt| odt.to_string()))
+        .collect::<Result<Vec<String>, _>>()?;
+    assert_eq!(
+        meetings,
+        vec![
+            "2021-01-29T10:00:00+09:00",
+            "2021-02-26T10:00:00+09:00",
+            "2021-03-26T10:00:00+09:00"
+        ]
+    );
+    assert_eq!(rule.to_string(), "FREQ=MONTHLY;COUNT=3;BYDAY=-1FR");
+    Ok(())
+}
+
+#[test]
+fn use_case_region_based_time_zone() -> anyhow::Result<()> {
+    // tests/zoneinfo is a stand-in for /usr/share/zoneinfo
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/zoneinfo");
+    let new_york = TimeZone::load(dir, "America/New_York")?;
+    let f = |s: &str| -> anyhow::Result<String> {
+        let instant = Instant::from_str(s)?;
+        Ok(ZonedDateTime::from_instant(instant, new_york.clone())?.to_string())
+    };
+    assert_eq!(
+        f("2021-01-01T12:00:00Z")?,
+        "2021-01-01T07:00:00-05:00[America/New_York]"
+    );
+    assert_eq!(
+        f("2021-07-01T12:00:00Z")?,
+        "2021-07-01T08:00:00-04:00[America/New_York]"
+    );
+    Ok(())
+}
+
+#[test]
+fn use_case_scheduler_at_dst_transitions() -> anyhow::Result<()> {
+    let rule = *PosixTimeZone::from_str("EST5EDT,M3.2.0,M11.1.0")?
+        .dst_rule()
+        .ok_or_else(|| anyhow::anyhow!("no DST"))?;
+
+    // a daily job at 02:30 local time
+    let run_at = |date: &str| -> anyhow::Result<String> {
+        let date_time = DateTime::from_str(&format!("{}T02:30:00", date))?;
+        Ok(rule
+            .resolve_with_policy(date_time, DisambiguationPolicy::ShiftForward)?
+            .to_string())
+    };
+    assert_eq!(run_at("2021-03-13")?, "2021-03-13T02:30:00-05:00");
+    // 02:30 is skipped
+    assert_eq!(run_at("2021-03-14")?, "2021-03-14T03:30:00-04:00");
+    assert_eq!(run_at("2021-03-15")?, "2021-03-15T02:30:00-04:00");
+
+    // 01:30 is repeated
+    let date_time = DateTime::from_str("2021-11-07T01:30:00")?;
+    assert!(rule.resolve(date_time).single().is_none());
+    assert_eq!(
+        rule.resolve(date_time).earliest().map(|t| t.to_string()),
+        Some("2021-11-07T01:30:00-04:00".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+fn use_case_print_in_local_time() -> anyhow::Result<()> {
+    // TZ=Asia/Tokyo (tests/zoneinfo is a stand-in for /usr/share/zoneinfo)
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/zoneinfo");
+    let config = LocalTimeZoneConfig::new(
+        Some("Asia/Tokyo".to_string()),
+        "/etc/localtime".into(),
+        dir.into(),
+    );
+    let local = config.load()?;
+    let instant = Instant::from_str("2021-02-03T04:05:06Z")?;
+    let offset_date_time = OffsetDateTime::from_instant(instant, local.offset_at(instant))?;
+    assert_eq!(offset_date_time.to_string(), "2021-02-03T13:05:06+09:00");
+    Ok(())
+}
+
+#[test]
+fn use_case_device_posix_tz() -> anyhow::Result<()> {
+    // the TZ config of an embedded device
+    let tz = PosixTimeZone::from_str("CET-1CEST,M3.5.0,M10.5.0/3")?;
+    let instant = Instant::from_str("2021-07-01T12:00:00Z")?;
+    assert_eq!(tz.offset_at(instant), TimeZoneOffset::from_h(2)?);
+    assert_eq!(tz.local_time_type_at(instant).designation(), "CEST");
+
+    let changes = tz
+        .transitions(Year::from_str("2022")?)
+        .iter()
+        .map(|t| OffsetDateTime::from_instant(t.instant(), t.offset_after()))
+        .map(|odt| odt.map(|odt| odt.to_string()))
+        .collect::<Result<Vec<String>, _>>()?;
+    assert_eq!(
+        changes,
+        vec!["2022-03-27T03:00:00+02:00", "2022-10-30T02:00:00+01:00"]
+    );
+    Ok(())
+}
+
+#[test]
+fn use_case_vendored_tzdata() -> anyhow::Result<()> {
+    // a pinned tzdata release instead of /usr/share/zoneinfo
+    let europe = "
+Rule EU 1981 max - Mar lastSun 1:00u 1:00 S
+Rule EU 1996 max - Oct lastSun 1:00u 0    -
+Zone Europe/Paris 0:09:21 - LMT 1891 Mar 16
+                  1:00    EU  CE%sT
+";
+    let tzdata = Tzdata::from_str(europe)?;
+    let paris = TimeZone::from_tzdata(&tzdata, "Europe/Paris")?;
+    let instant = Instant::from_str("2021-07-01T12:00:00Z")?;
+    assert_eq!(paris.offset_at(instant), TimeZoneOffset::from_h(2)?);
+    assert_eq!(paris.local_time_type_at(instant).designation(), "CEST");
+    assert_eq!(paris.tzif().footer(), Some("CET-1CEST,M3.5.0,M10.5.0/3"));
+    Ok(())
+}
+
+#[test]
+fn use_case_upcoming_clock_change() -> anyhow::Result<()> {
+    // change points supplied by the caller
+    let history = OffsetHistory::new(
+        TimeZoneOffset::from_h(1)?,
+        vec![
+            (
+                Instant::from_str("2022-03-27T01:00:00Z")?,
+                TimeZoneOffset::from_h(2)?,
+            ),
+            (
+                Instant::from_str("2022-10-30T01:00:00Z")?,
+                TimeZoneOffset::from_h(1)?,
+            ),
+        ],
+    )?;
+
+    // warn a week before clocks change
+    let now = Instant::from_str("2022-03-20T09:00:00Z")?;
+    let transition = history
+        .next_transition(now)
+        .ok_or_else(|| anyhow::anyhow!("no transition"))?;
+    let seconds_until_change = i64::from(transition.instant()) - i64::from(now);
+    assert!(seconds_until_change <= 7 * 86400);
+
+    // 23- and 25-hour days
+    let hours_of_day = |year_month: &str| -> anyhow::Result<Vec<String>> {
+        history
+            .transitions_in(YearMonth::from_str(year_month)?)
+            .iter()
+            .map(|t| {
+                let delta =
+                    t.offset_after().offset_in_minutes() - t.offset_before().offset_in_minutes();
+                let date = OffsetDateTime::from_instant(t.instant(), t.offset_after())?
+                    .date_time()
+                    .date();
+                Ok(format!("{} {}h", date, 24 - delta / 60))
+            })
+            .collect()
+    };
+    assert_eq!(hours_of_day("2022-03")?, vec!["2022-03-27 23h"]);
+    assert_eq!(hours_of_day("2022-07")?, Vec::<String>::new());
+    assert_eq!(hours_of_day("2022-10")?, vec!["2022-10-30 25h"]);
+    Ok(())
+}
+
+#[test]
+fn use_case_event_store_with_intended_zone() -> anyhow::Result<()> {
+    // the offset and the intended zone are stored together
+    let stored = "2021-02-03T04:05:06+09:00[Asia/Tokyo][u-ca=japanese]";
+    let event = ExtendedDateTime::from_str(stored)?;
+    assert_eq!(
+        event.offset_date_time().instant(),
+        Instant::from_str("2021-02-02T19:05:06Z")?
+    );
+    assert_eq!(
+        event.time_zone(),
+        Some(&TimeZoneAnnotation::Name("Asia/Tokyo".to_string()))
+    );
+    // the elective calendar annotation is kept as is
+    assert_eq!(event.calendar(), Some("japanese"));
+    assert_eq!(event.to_string(), stored);
+
+    // the stored offset is checked against the zone
+    let tokyo = TimeZone::from_posix_tz("JST-9")?;
+    assert_eq!(
+        ExtendedDateTime::from_str("2021-02-03T04:05:06+09:00[JST-9]")?
+            .to_zoned_date_time(tokyo.clone())?
+            .offset(),
+        TimeZoneOffset::from_h(9)?
+    );
+    assert_eq!(
+        ExtendedDateTime::from_str("2021-02-03T04:05:06+08:00[JST-9]")?.to_zoned_date_time(tokyo),
+        Err(ResolveExtendedDateTimeError::OffsetMismatch)
+    );
+
+    // a critical annotation that cannot be honored is rejected
+    assert_eq!(
+        ExtendedDateTime::from_str("2021-02-03T04:05:06+09:00[Asia/Tokyo][!u-ca=japanese]"),
+        Err(ParseExtendedDateTimeError::UnsupportedCriticalAnnotation(
+            "u-ca".to_string()
+        ))
+    );
+    Ok(())
+}
+
+#[test]
+fn use_case_mail_gateway_unknown_offset() -> anyhow::Result<()> {
+    // "-00:00": the time in UTC is known, the local offset is not
+    let received = Rfc3339DateTime::from_str("2021-02-03T04:05:06-00:00")?;
+    assert_eq!(received.local_offset(), LocalOffset::Unknown);
+    assert_eq!(
+        received.instant(),
+        Instant::from_str("2021-02-03T04:05:06Z")?
+    );
+    assert_eq!(received.to_string(), "2021-02-03T04:05:06-00:00");
+
+    // OffsetDateTime does not silently treat it as UTC
+    assert!(OffsetDateTime::from_str("2021-02-03T04:05:06-00:00").is_err());
+    Ok(())
+}
+
+#[test]
+fn use_case_fractional_seconds_from_clients() -> anyhow::Result<()> {
+    // JavaScript (Date#toISOString) and Postgres (timestamptz) style
+    let js = OffsetDateTime::from_str("2021-02-03T04:05:06.789Z")?;
+    let pg = OffsetDateTime::from_str("2021-02-03T13:05:06.789012+09:00")?;
+    assert_eq!(js.to_string(), "2021-02-03T04:05:06.789Z");
+    assert_eq!(pg.to_string(), "2021-02-03T13:05:06.789012+09:00");
+    assert_eq!(u32::from(pg.date_time().time().nanosecond()), 789_012_000);
+    // the same second, ordered by the fraction
+    assert_eq!(js.instant(), pg.instant());
+    assert!(js.date_time() < OffsetDateTime::from_str("2021-02-03T04:05:06.790Z")?.date_time());
+    Ok(())
+}
+
+#[test]
+fn use_case_kafka_record_timestamp() -> anyhow::Result<()> {
+    // epoch milliseconds (e.g. Kafka record timestamps, JavaScript Date#getTime)
+    let produced = PreciseInstant::from_unix_millis(1_612_325_106_789)?;
+    let consumed = PreciseInstant::from_unix_millis(1_612_325_107_012)?;
+    assert!(produced < consumed);
+    assert_eq!(consumed.nanos_since(produced) / 1_000_000, 223);
+    assert_eq!(produced.to_string(), "2021-02-03T04:05:06.789Z");
+    assert_eq!(
+        OffsetDateTime::from_precise_instant(produced, TimeZoneOffset::from_h(9)?)?.to_string(),
+        "2021-02-03T13:05:06.789+09:00"
+    );
+    // whole seconds only on request
+    assert_eq!(
+        produced.truncate(),
+        Instant::from_str("2021-02-03T04:05:06Z")?
+    );
+    Ok(())
+}
+
+#[test]
+fn use_case_gnss_feed_with_leap_second() -> anyhow::Result<()> {
+    let records = [
+        "2016-12-31T23:59:59.500Z",
+        "2016-12-31T23:59:60Z",
+        "2016-12-31T23:59:60.500Z",
+        "2017-01-01T00:00:00Z",
+    ];
+    let instants = records
+        .iter()
+        .map(|s| {
+            Ok(LeapSecondDateTime::from_str(s)?
+                .to_precise_instant(LeapSecondPolicy::Smear)
+                .to_string())
+        })
+        .collect::<anyhow::Result<Vec<String>>>()?;
+    // strictly increasing
+    assert_eq!(
+        instants,
+        vec![
+            "2016-12-31T23:59:59.000011573Z",
+            "2016-12-31T23:59:59.500005786Z",
+            "2017-01-01T00:00:00Z",
+            "2017-01-01T00:00:00.499994213Z",
+        ]
+    );
+
+    // 23:59:60 is valid only at a real leap second
+    assert!(LeapSecondDateTime::from_str("2021-12-31T23:59:60Z").is_err());
+    Ok(())
+}
+
+#[test]
+fn use_case_telemetry_gps_timestamp() -> anyhow::Result<()> {
+    // (GPS week, seconds of week, nanoseconds) from a sensor packet
+    let (week, seconds_of_week, nanos) = (2143_u32, 273_924_u32, 250_000_000_u32);
+    let gps_time = GpsTime::new(week, seconds_of_week, Nanosecond::try_from(nanos)?)?;
+    let offset_date_time = OffsetDateTime::from_precise_instant(
+        gps_time.to_precise_instant(LeapSecondPolicy::CollapseToPrevious),
+        TimeZoneOffset::from_h(9)?,
+    )?;
+    // GPS - UTC = 18 seconds
+    assert_eq!(
+        offset_date_time.to_string(),
+        "2021-02-03T13:05:06.250+09:00"
+    );
+
+    // TAI - UTC = 37 seconds
+    let tai_instant = TaiInstant::from(gps_time);
+    assert_eq!(
+        tai_instant.seconds() - u64::from(offset_date_time.instant()),
+        37
+    );
+    assert_eq!(
+        GpsTime::try_from(offset_date_time.precise_instant())?,
+        gps_time
+    );
+    Ok(())
+}
+
+#[test]
+fn use_case_opening_hours_until_end_of_day() -> anyhow::Result<()> {
+    // imported opening hours: [open, close)
+    let (open, close) = ("2021-02-03T18:00:00", "2021-02-03T24:00:00");
+    let hours = DateTimeOfDay::from_str(open)?..DateTimeOfDay::from_str(close)?;
+    assert!(hours.contains(&DateTimeOfDay::from_str("2021-02-03T23:59:59")?));
+    assert!(!hours.contains(&DateTimeOfDay::from_str("2021-02-04T00:00:00")?));
+    // exported without data loss
+    assert_eq!(hours.end.to_string(), close);
+    assert!(hours.end.is_end_of_day());
+
+    let close_at = OffsetDateTime::new(DateTime::try_from(hours.end)?, TimeZoneOffset::from_h(9)?);
+    assert_eq!(close_at.to_string(), "2021-02-04T00:00:00+09:00");
+    assert_eq!(close_at.instant().to_string(), "2021-02-03T15:00:00Z");
+    Ok(())
+}
+
+#[test]
+fn use_case_session_expiry_with_injected_clock() -> anyhow::Result<()> {
+    struct SessionStore<C: Clock> {
+        clock: C,
+        expires_at: Option<Instant>,
+    }
+
+    impl<C: Clock> SessionStore<C> {
+        fn login(&mut self) -> anyhow::Result<()> {
+            self.expires_at = Some(Instant::now_in(&self.clock)? + Days::from(1_u8));
+            Ok(())
+        }
+
+        fn is_active(&self) -> anyhow::Result<bool> {
+            let now = Instant::now_in(&self.clock)?;
+            Ok(self.expires_at.is_some_and(|t| now < t))
+        }
+    }
+
+    let clock = ManualClock::from(Instant::from_str("2021-02-03T04:05:06Z")?);
+    let mut store = SessionStore {
+        clock,
+        expires_at: None,
+    };
+    store.login()?;
+    assert!(store.is_active()?);
+    store.clock.advance(SignedSeconds::from(86_399_i64))?;
+    assert!(store.is_active()?);
+    store.clock.advance(SignedSeconds::from(1_i64))?;
+    assert!(!store.is_active()?);
+    assert_eq!(
+        CalendarDate::now_in(&store.clock, TimeZoneOffset::from_h(9)?)?.to_string(),
+        "2021-02-04"
+    );
+
+    // production code uses the system clock
+    let mut store = SessionStore {
+        clock: SystemClock,
+        expires_at: None,
+    };
+    store.login()?;
+    assert!(store.is_active()?);
+    Ok(())
+}
+
+#[test]
+fn use_case_file_mtime_and_timeout() -> anyhow::Result<()> {
+    let metadata = std::fs::metadata("Cargo.toml")?;
+    let modified = Instant::try_from(metadata.modified()?)?;
+    assert!(modified <= Instant::now());
+    let modified = PreciseInstant::try_from(metadata.modified()?)?;
+    assert_eq!(SystemTime::from(modified), metadata.modified()?);
+
+    // a timeout for std (e.g. TcpStream::set_read_timeout)
+    let timeout = Duration::from(Seconds::from(30_u64));
+    assert_eq!(timeout, Duration::from_secs(30));
+    // retention in whole days (at least the elapsed time)
+    let elapsed = Duration::from_secs(36 * 3_600);
+    assert_eq!(
+        Days::from_duration(elapsed, RoundingMode::Ceil)?,
+        Days::from(2_u8)
+    );
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn use_case_json_api_payload() -> anyhow::Result<()> {
+    use serde::{Deserialize, Serialize};
+    use serde_test::{assert_tokens, Token};
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Booking {
+        starts_at: OffsetDateTime,
+        nights: Days,
+        #[serde(with = "limited_date_time::serde::instant_as_seconds")]
+        created_at: Instant,
+    }
+
+    let booking = Booking {
+        starts_at: OffsetDateTime::from_str("2021-02-03T15:00:00+09:00")?,
+        nights: Days::from(2_u8),
+        created_at: Instant::from_str("2021-01-02T03:04:05Z")?,
+    };
+    assert_tokens(
+        &booking,
+        &[
+            Token::Struct {
+                name: "Booking",
+                len: 3,
+            },
+            Token::Str("starts_at"),
+            Token::Str("2021-02-03T15:00:00+09:00"),
+            Token::Str("nights"),
+            Token::U32(2),
+            Token::Str("created_at"),
+            Token::U64(1_609_556_645),
+            Token::StructEnd,
+        ],
+    );
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn use_case_chrono_library_boundary() -> anyhow::Result<()> {
+    use chrono::{FixedOffset, NaiveDate};
+
+    // a library that speaks chrono
+    let next_business_day = |date: NaiveDate| date.succ_opt().expect("not the max date");
+
+    let date = CalendarDate::from_str("2021-02-03")?;
+    let next = CalendarDate::try_from(next_business_day(NaiveDate::from(date)))?;
+    assert_eq!(next.to_string(), "2021-02-04");
+
+    let received = chrono::DateTime::parse_from_rfc3339("2021-02-03T04:05:06+09:00")?;
+    let offset_date_time = OffsetDateTime::try_from(received)?;
+    assert_eq!(offset_date_time.to_string(), "2021-02-03T04:05:06+09:00");
+    assert_eq!(
+        chrono::DateTime::<FixedOffset>::from(offset_date_time),
+        received
+    );
+
+    // out of range
+    let received = chrono::DateTime::parse_from_rfc3339("1969-12-31T23:59:59Z")?;
+    assert!(OffsetDateTime::try_from(received).is_err());
+    Ok(())
+}
+
+#[test]
+fn use_case_literal_constants() -> anyhow::Result<()> {
+    const RELEASE_DATE: CalendarDate = date!("2021-02-03");
+    const OPENING_TIME: Time = time!(09:00:00);
+    const JST: TimeZoneOffset = offset!(+09:00);
+
+    assert_eq!(RELEASE_DATE, CalendarDate::from_str("2021-02-03")?);
+    assert_eq!(
+        OffsetDateTime::new(DateTime::new(RELEASE_DATE, OPENING_TIME), JST),
+        odt!(2021-02-03T09:00:00+09:00)
+    );
+    assert_eq!(
+        datetime!(2021-02-03T09:00:00.5).to_string(),
+        "2021-02-03T09:00:00.500"
+    );
+    assert_eq!(year_month!("2021-02").days(), Days::from(28_u8));
+    Ok(())
+}
+
 #[test]
 fn use_case_get_days() -> anyhow::Result<()> {
     assert_eq!(DayOfMonth::from_str("03")?.days(), Days::from(1_u16));